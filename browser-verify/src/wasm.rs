@@ -1,53 +1,23 @@
-use bytemuck::Pod;
-use bytemuck::Zeroable;
 use js_sys::Promise;
 use risc0_zkvm::serde::to_vec;
 use risc0_zkvm::sha::Digest;
 use risc0_zkvm::{Journal, Receipt};
-use serde::{Deserialize, Serialize};
+use rpgf_ballots_core::merkle::InclusionProof;
+use rpgf_ballots_core::poseidon::hash_vote_amounts;
+use rpgf_ballots_core::{
+    Felt, IncrementalJournal, JournalHeader, PoseidonConfig, PoseidonConfigError, ProjectJournal,
+    ProjectsJournal, TreeHashMode,
+};
+use rpgf_ballots_methods::{INCREMENT_ID, SHARDS_ID, SHARD_ID};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 // import felt from halo2
 use halo2curves::bn256::Fr as Fp;
 use halo2curves::ff::PrimeField;
 
-#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, PartialEq)]
-#[repr(C)]
-struct Felt([u64; 4]);
-
-fn to_hex_string(values: &Felt) -> String {
-    // Map each u64 value to its hexadecimal string representation and concatenate them.
-    let bytes: [u8; 32] = values
-        .0
-        .iter()
-        .flat_map(|&value| value.to_le_bytes().to_vec())
-        .collect::<Vec<u8>>()
-        .try_into()
-        .unwrap();
-
-    let hex_str = hex::encode(bytes);
-
-    // Prepend the '0x' prefix to the concatenated string.
-    format!("0x{}", hex_str)
-}
-
-fn from_hex_string(hex_str: &str) -> Felt {
-    // Remove the '0x' prefix from the input string.
-    let hex_str = hex_str.trim_start_matches("0x");
-
-    // Split the input string into 16-character chunks, then hex decode each chunk, convert them to u64 from le bytes and collect them into an array.
-    let chunks: [u64; 4] = hex_str
-        .as_bytes()
-        .chunks(16)
-        .map(|chunk| u64::from_le_bytes(hex::decode(chunk).unwrap().try_into().unwrap()))
-        .collect::<Vec<u64>>()
-        .try_into()
-        .unwrap();
-
-    // Parse each chunk as a u64 value and collect them into an array.
-    Felt(chunks)
-}
-
 #[wasm_bindgen]
 pub fn big_endian_to_little_endian(hex_str: &str) -> String {
     // Remove the '0x' prefix from the input string.
@@ -88,14 +58,11 @@ pub fn extract_hashes(
     let receipts: Vec<Receipt> = bincode::deserialize(&receipts[..]).unwrap();
     let hashes = receipts
         .iter()
-        .map(|receipt| receipt.journal.decode::<Felt>().unwrap())
-        .collect::<Vec<Felt>>();
+        .map(|receipt| Ok(decode_project_journal(receipt)?.hash))
+        .collect::<Result<Vec<Felt>, JsError>>()?;
 
     // get the string hashes to get Vec<String>
-    let hash_strings = hashes
-        .iter()
-        .map(|felt| to_hex_string(felt))
-        .collect::<Vec<String>>();
+    let hash_strings = hashes.iter().map(Felt::to_hex).collect::<Vec<String>>();
 
     Ok(wasm_bindgen::Clamped(
         serde_json::to_vec(&hash_strings).unwrap(),
@@ -117,13 +84,13 @@ pub fn modify_hashes(
     }
     let hashes: Vec<Felt> = hashes_string
         .iter()
-        .map(|felt| from_hex_string(felt))
-        .collect::<Vec<Felt>>();
+        .map(|felt| Felt::from_hex(felt))
+        .collect::<Result<Vec<Felt>, _>>()?;
     // modify the hashes in the receipts
     for (receipt, felt) in receipts.iter_mut().zip(hashes.iter()) {
-        let data = bytemuck::bytes_of(felt);
-        let journal = Journal::new(data.to_vec());
-        receipt.journal = journal;
+        let mut journal = decode_project_journal(receipt)?;
+        journal.hash = *felt;
+        receipt.journal = encode_journal(&journal);
     }
 
     Ok(wasm_bindgen::Clamped(
@@ -140,9 +107,16 @@ pub async fn verify_aggr_async(
     let receipt_result = async move {
         let receipt: Receipt = bincode::deserialize(&receipt[..]).unwrap();
         let image_id: Digest = bincode::deserialize(&image_id[..]).unwrap();
-        match receipt.verify(image_id) {
-            Ok(_) => Ok(JsValue::from_bool(true)),
-            Err(_) => Err(JsValue::from_str("Verification failed")),
+        if receipt.verify(image_id).is_err() {
+            return Err(JsValue::from_str("Verification failed"));
+        }
+        // a receipt of the shards guest (shards.rs) only holds if its shards were proven by
+        // the published shard guest (shard.rs)
+        let journal = decode_projects_journal(&receipt).map_err(JsValue::from)?;
+        let shard_image_id = (image_id == Digest::from(SHARDS_ID)).then_some(&SHARD_ID);
+        match journal.check_shard_image_id(shard_image_id) {
+            Ok(()) => Ok(JsValue::from_bool(true)),
+            Err(e) => Err(JsValue::from_str(&format!("Verification failed: {}", e))),
         }
     };
 
@@ -158,17 +132,50 @@ pub fn verify_aggr(
     future_to_promise(verify_aggr_async(receipt, image_id))
 }
 
+#[wasm_bindgen]
+pub async fn verify_incremental_async(
+    receipt: wasm_bindgen::Clamped<Vec<u8>>,
+    image_id: wasm_bindgen::Clamped<Vec<u8>>,
+) -> Result<JsValue, JsValue> {
+    let receipt_result = async move {
+        let receipt: Receipt = bincode::deserialize(&receipt[..]).unwrap();
+        let image_id: Digest = bincode::deserialize(&image_id[..]).unwrap();
+        if image_id != Digest::from(INCREMENT_ID) || receipt.verify(image_id).is_err() {
+            return Err(JsValue::from_str("Verification failed"));
+        }
+        // the previous steps must have been verified against the same guest
+        let journal = decode_incremental_journal(&receipt).map_err(JsValue::from)?;
+        match journal.check_image_id(&INCREMENT_ID) {
+            Ok(()) => Ok(JsValue::from_bool(true)),
+            Err(e) => Err(JsValue::from_str(&format!("Verification failed: {}", e))),
+        }
+    };
+
+    receipt_result.await
+}
+
+// Verifies a receipt of the incremental guest code (increment.rs) against the published image id
+// of the guest, which every previous step of the chain must have been verified against too
+#[wasm_bindgen]
+pub fn verify_incremental(
+    receipt: wasm_bindgen::Clamped<Vec<u8>>,
+    image_id: wasm_bindgen::Clamped<Vec<u8>>,
+) -> Promise {
+    future_to_promise(verify_incremental_async(receipt, image_id))
+}
+
 // Extracts the hashes from the receipt generated from the aggregate guest code (projects.rs)
 #[wasm_bindgen]
 pub fn extract_hashes_aggr(
     receipt: wasm_bindgen::Clamped<Vec<u8>>,
 ) -> Result<wasm_bindgen::Clamped<Vec<u8>>, JsError> {
     let receipt: Receipt = bincode::deserialize(&receipt[..]).unwrap();
-    let hashes = receipt.journal.decode::<Vec<Felt>>().unwrap();
-    // get the string hashes to get Vec<String>
-    let hash_strings = hashes
+    let journal = decode_projects_journal(&receipt)?;
+    // get the string hashes to get Vec<String>, in the order of the journal's projects
+    let hash_strings = journal
+        .projects
         .iter()
-        .map(|felt| to_hex_string(felt))
+        .map(|project| project.hash.to_hex())
         .collect::<Vec<String>>();
 
     Ok(wasm_bindgen::Clamped(
@@ -186,13 +193,173 @@ pub fn modify_hashes_aggr(
     let hashes_string: Vec<String> = serde_json::from_slice(&hashes_string[..]).unwrap();
     let hashes: Vec<Felt> = hashes_string
         .iter()
-        .map(|felt| from_hex_string(felt))
-        .collect::<Vec<Felt>>();
-    let data_u32_words = to_vec(&hashes).unwrap();
-    let bytes = bytemuck::try_cast_slice(data_u32_words.as_slice()).unwrap();
+        .map(|felt| Felt::from_hex(felt))
+        .collect::<Result<Vec<Felt>, _>>()?;
     // modify the hashes in the receipt
-    let journal = Journal::new(bytes.to_vec());
-    receipt.journal = journal;
+    let mut journal = decode_projects_journal(&receipt)?;
+    if journal.projects.len() != hashes.len() {
+        return Err(JsError::new("Projects and hashes length mismatch"));
+    }
+    for (project, hash) in journal.projects.iter_mut().zip(hashes) {
+        project.hash = hash;
+    }
+    receipt.journal = encode_journal(&journal);
 
     Ok(wasm_bindgen::Clamped(bincode::serialize(&receipt).unwrap()))
 }
+
+// Recomputes the hash of every project of a votes table (json of project id -> vote amounts, as
+// written by private_processing), bit for bit as the guests commit them with the Poseidon spec
+// (`w2r1` or `w3r2`), chunk length and tree hash mode of the journal header. Returns a json
+// object of project id -> hash, in the format of `extract_hashes`
+#[wasm_bindgen]
+pub fn recompute_hashes(
+    votes_table: wasm_bindgen::Clamped<Vec<u8>>,
+    poseidon_spec: &str,
+    chunk_len: u32,
+    legacy_tree_hash: bool,
+) -> Result<wasm_bindgen::Clamped<Vec<u8>>, JsError> {
+    let votes_table: BTreeMap<String, Vec<i128>> = serde_json::from_slice(&votes_table[..])?;
+    let config = PoseidonConfig {
+        spec: poseidon_spec.parse()?,
+        chunk_len,
+        tree_hash: if legacy_tree_hash {
+            TreeHashMode::Legacy
+        } else {
+            TreeHashMode::LengthBinding
+        },
+    };
+    let hashes = votes_table
+        .iter()
+        .map(|(project_id, amounts)| {
+            Ok((
+                project_id.clone(),
+                hash_vote_amounts(amounts, None, &config)?.to_hex(),
+            ))
+        })
+        .collect::<Result<BTreeMap<String, String>, PoseidonConfigError>>()?;
+
+    Ok(wasm_bindgen::Clamped(serde_json::to_vec(&hashes).unwrap()))
+}
+
+// Checks a badgeholder's inclusion proof (json, as written by the inclusion_proofs command)
+// against the votes roots committed by the receipts of single hash guest code (project.rs), or
+// by the receipt of the aggregate guest code (projects.rs) if `aggregate` is set, once the
+// receipts are verified against `image_id`
+#[wasm_bindgen]
+pub fn verify_inclusion_proof(
+    receipts: wasm_bindgen::Clamped<Vec<u8>>,
+    image_id: wasm_bindgen::Clamped<Vec<u8>>,
+    proof: wasm_bindgen::Clamped<Vec<u8>>,
+    aggregate: bool,
+) -> Result<bool, JsError> {
+    let image_id: Digest = bincode::deserialize(&image_id[..]).unwrap();
+    let proof: InclusionProof = serde_json::from_slice(&proof[..])?;
+    let root = match proof.root() {
+        Some(root) => root,
+        None => return Ok(false),
+    };
+    if aggregate {
+        let receipt: Receipt = bincode::deserialize(&receipts[..]).unwrap();
+        if receipt.verify(image_id).is_err() {
+            return Ok(false);
+        }
+        let journal = decode_projects_journal(&receipt)?;
+        let shard_image_id = (image_id == Digest::from(SHARDS_ID)).then_some(&SHARD_ID);
+        if journal.check_shard_image_id(shard_image_id).is_err() {
+            return Ok(false);
+        }
+        return Ok(journal
+            .projects
+            .iter()
+            .any(|project| project.project_id == proof.project_id && project.votes_root == root));
+    }
+    let receipts: Vec<Receipt> = bincode::deserialize(&receipts[..]).unwrap();
+    for receipt in &receipts {
+        let journal = decode_project_journal(receipt)?;
+        if journal.project_id == proof.project_id {
+            if receipt.verify(image_id).is_err() {
+                return Ok(false);
+            }
+            return Ok(journal.votes_root == root);
+        }
+    }
+    Ok(false)
+}
+
+// Extracts the journal headers (round id, input hashes, ballot counts, ..) as json from receipts
+// generated from single hash guest code (project.rs)
+#[wasm_bindgen]
+pub fn extract_headers(
+    receipts: wasm_bindgen::Clamped<Vec<u8>>,
+) -> Result<wasm_bindgen::Clamped<Vec<u8>>, JsError> {
+    let receipts: Vec<Receipt> = bincode::deserialize(&receipts[..]).unwrap();
+    let headers = receipts
+        .iter()
+        .map(|receipt| Ok(decode_project_journal(receipt)?.header))
+        .collect::<Result<Vec<JournalHeader>, JsError>>()?;
+
+    Ok(wasm_bindgen::Clamped(serde_json::to_vec(&headers).unwrap()))
+}
+
+// Extracts the journal header as json from the receipt generated from the aggregate guest code
+// (projects.rs)
+#[wasm_bindgen]
+pub fn extract_header_aggr(
+    receipt: wasm_bindgen::Clamped<Vec<u8>>,
+) -> Result<wasm_bindgen::Clamped<Vec<u8>>, JsError> {
+    let receipt: Receipt = bincode::deserialize(&receipt[..]).unwrap();
+    let header = decode_projects_journal(&receipt)?.header;
+
+    Ok(wasm_bindgen::Clamped(serde_json::to_vec(&header).unwrap()))
+}
+
+// Extracts the project filter and the (project id, vote count, hash, votes root) of every project
+// it selects as json from the receipt generated from the aggregate guest code (projects.rs)
+#[wasm_bindgen]
+pub fn extract_projects_aggr(
+    receipt: wasm_bindgen::Clamped<Vec<u8>>,
+) -> Result<wasm_bindgen::Clamped<Vec<u8>>, JsError> {
+    let receipt: Receipt = bincode::deserialize(&receipt[..]).unwrap();
+    let journal = decode_projects_journal(&receipt)?;
+    let projects = serde_json::json!({
+        "filter": journal.filter,
+        "projects": journal.projects,
+    });
+
+    Ok(wasm_bindgen::Clamped(
+        serde_json::to_vec(&projects).unwrap(),
+    ))
+}
+
+fn decode_project_journal(receipt: &Receipt) -> Result<ProjectJournal, JsError> {
+    let journal: ProjectJournal = decode_journal(receipt)?;
+    journal.header.check_version()?;
+    Ok(journal)
+}
+
+fn decode_projects_journal(receipt: &Receipt) -> Result<ProjectsJournal, JsError> {
+    let journal: ProjectsJournal = decode_journal(receipt)?;
+    journal.header.check_version()?;
+    Ok(journal)
+}
+
+fn decode_incremental_journal(receipt: &Receipt) -> Result<IncrementalJournal, JsError> {
+    let journal: IncrementalJournal = decode_journal(receipt)?;
+    journal.journal.header.check_version()?;
+    Ok(journal)
+}
+
+fn decode_journal<T: DeserializeOwned>(receipt: &Receipt) -> Result<T, JsError> {
+    receipt
+        .journal
+        .decode()
+        .map_err(|e| JsError::new(&format!("Failed to decode journal: {}", e)))
+}
+
+// Encodes a journal the same way `env::commit` does in the guest
+fn encode_journal<T: Serialize>(journal: &T) -> Journal {
+    let data_u32_words = to_vec(journal).unwrap();
+    let bytes = bytemuck::try_cast_slice(data_u32_words.as_slice()).unwrap();
+    Journal::new(bytes.to_vec())
+}
@@ -0,0 +1,368 @@
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A 32 byte project id, written as a `0x` prefixed hex string in ballots.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProjectId(pub [u8; 32]);
+
+impl FromStr for ProjectId {
+    type Err = BallotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BallotError::InvalidProjectId {
+            index: None,
+            value: s.to_string(),
+        };
+        let hex_str = s.strip_prefix("0x").ok_or_else(invalid)?;
+        if hex_str.len() != 64 {
+            return Err(invalid());
+        }
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(hex_str, &mut bytes).map_err(|_| invalid())?;
+        Ok(ProjectId(bytes))
+    }
+}
+
+impl fmt::Display for ProjectId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+/// Number of decimals vote amounts are scaled by by default, matching the 18 decimals of OP.
+pub const DEFAULT_AMOUNT_SCALE: u32 = 18;
+
+/// The largest scale for which `10^scale` fits in an `i128`.
+pub const MAX_AMOUNT_SCALE: u32 = 38;
+
+/// A non negative decimal vote amount, kept as the text it was written as in the ballot.
+///
+/// Ballots write amounts either as json strings (`"12345.67"`) or json numbers (`12345`). Json
+/// numbers keep every digit they were written with, numbers in exponent notation are rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Amount(String);
+
+impl Amount {
+    /// The decimal text of the amount.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The amount as a fixed point integer with `scale` decimals, i.e. `amount * 10^scale`.
+    ///
+    /// The conversion is exact: amounts with more significant decimals than `scale`, or that
+    /// don't fit in an `i128` once scaled, are rejected rather than truncated.
+    pub fn to_fixed(&self, scale: u32) -> Result<i128, BallotError> {
+        let out_of_range = || BallotError::AmountOutOfRange {
+            value: self.0.clone(),
+            scale,
+        };
+        let (whole, fraction) = self.0.split_once('.').unwrap_or((&self.0, ""));
+        let fraction = fraction.trim_end_matches('0');
+        let padding = (scale as usize)
+            .checked_sub(fraction.len())
+            .ok_or_else(out_of_range)?;
+
+        let mut units: i128 = 0;
+        for digit in whole.bytes().chain(fraction.bytes()) {
+            units = units
+                .checked_mul(10)
+                .and_then(|units| units.checked_add((digit - b'0') as i128))
+                .ok_or_else(out_of_range)?;
+        }
+        let units = 10i128
+            .checked_pow(padding as u32)
+            .and_then(|shift| units.checked_mul(shift))
+            .ok_or_else(out_of_range)?;
+        Ok(units)
+    }
+}
+
+impl FromStr for Amount {
+    type Err = BallotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (whole, fraction) = match s.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (s, None),
+        };
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        let valid = match fraction {
+            Some(fraction) => is_digits(whole) && is_digits(fraction),
+            None => is_digits(whole),
+        };
+        if !valid {
+            return Err(BallotError::InvalidAmount {
+                index: None,
+                value: s.to_string(),
+            });
+        }
+        Ok(Amount(s.to_string()))
+    }
+}
+
+/// A single `{"projectId": .., "amount": ..}` entry of a ballot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vote {
+    pub project_id: ProjectId,
+    pub amount: Amount,
+}
+
+impl Vote {
+    fn from_value(index: usize, value: &Value) -> Result<Self, BallotError> {
+        let object = value
+            .as_object()
+            .ok_or(BallotError::VoteNotAnObject { index })?;
+        let mut project_id = None;
+        let mut amount = None;
+        for (field, value) in object {
+            match field.as_str() {
+                "projectId" => {
+                    let id = value
+                        .as_str()
+                        .ok_or_else(|| BallotError::InvalidProjectId {
+                            index: Some(index),
+                            value: value.to_string(),
+                        })?;
+                    project_id = Some(id.parse::<ProjectId>().map_err(|e| e.at(index))?);
+                }
+                "amount" => {
+                    let text = match value {
+                        Value::String(text) => text.clone(),
+                        // the digits of the json number as written, never rounded through an f64
+                        Value::Number(number) => number.to_string(),
+                        _ => {
+                            return Err(BallotError::InvalidAmount {
+                                index: Some(index),
+                                value: value.to_string(),
+                            })
+                        }
+                    };
+                    amount = Some(text.parse::<Amount>().map_err(|e| e.at(index))?);
+                }
+                _ => {
+                    return Err(BallotError::UnknownField {
+                        index,
+                        field: field.clone(),
+                    })
+                }
+            }
+        }
+        Ok(Vote {
+            project_id: project_id.ok_or(BallotError::MissingField {
+                index,
+                field: "projectId",
+            })?,
+            amount: amount.ok_or(BallotError::MissingField {
+                index,
+                field: "amount",
+            })?,
+        })
+    }
+}
+
+/// A validated ballot: either a json array of votes, one per project, or a
+/// `{"nonce": .., "votes": [..]}` object whose nonce orders the ballots of a badgeholder.
+///
+/// The original bytes are kept since they are what the badgeholder signed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ballot {
+    raw: Vec<u8>,
+    nonce: u64,
+    votes: Vec<Vote>,
+}
+
+impl Ballot {
+    /// Parses and validates the json ballot `raw`. Plain arrays of votes have a nonce of 0.
+    pub fn parse(raw: Vec<u8>) -> Result<Self, BallotError> {
+        let value: Value =
+            serde_json::from_slice(&raw).map_err(|e| BallotError::Json(e.to_string()))?;
+        let (nonce, array) = match &value {
+            Value::Array(array) => (0, array),
+            Value::Object(object) => {
+                let mut nonce = None;
+                let mut votes = None;
+                for (field, value) in object {
+                    match field.as_str() {
+                        "nonce" => nonce = Some(parse_nonce(value)?),
+                        "votes" => votes = Some(value.as_array().ok_or(BallotError::NotAnArray)?),
+                        _ => return Err(BallotError::UnknownBallotField(field.clone())),
+                    }
+                }
+                // an object without votes isn't a ballot at all
+                let votes = votes.ok_or(BallotError::NotAnArray)?;
+                (nonce.ok_or(BallotError::MissingNonce)?, votes)
+            }
+            _ => return Err(BallotError::NotAnArray),
+        };
+
+        let mut seen = BTreeSet::new();
+        let mut votes = Vec::with_capacity(array.len());
+        for (index, value) in array.iter().enumerate() {
+            let vote = Vote::from_value(index, value)?;
+            if !seen.insert(vote.project_id) {
+                return Err(BallotError::DuplicateProject {
+                    index,
+                    project_id: vote.project_id,
+                });
+            }
+            votes.push(vote);
+        }
+        Ok(Ballot { raw, nonce, votes })
+    }
+
+    /// The signed bytes of the ballot.
+    pub fn raw(&self) -> &[u8] {
+        &self.raw
+    }
+
+    /// The nonce (or timestamp) of the ballot, a later ballot of the same badgeholder
+    /// supersedes ballots with a lower nonce.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    pub fn votes(&self) -> &[Vote] {
+        &self.votes
+    }
+
+    /// The vote for `project_id`, if the ballot has one.
+    pub fn vote_for(&self, project_id: &ProjectId) -> Option<&Vote> {
+        self.votes
+            .iter()
+            .find(|vote| vote.project_id == *project_id)
+    }
+}
+
+/// Nonces are written as json numbers or, for values beyond the safe integers of javascript,
+/// decimal strings.
+fn parse_nonce(value: &Value) -> Result<u64, BallotError> {
+    let nonce = match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(text) if text.bytes().all(|b| b.is_ascii_digit()) => text.parse().ok(),
+        _ => None,
+    };
+    nonce.ok_or_else(|| BallotError::InvalidNonce(value.to_string()))
+}
+
+/// Reasons a ballot is rejected. `index` is the position of the offending vote in the ballot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BallotError {
+    /// The ballot isn't valid json.
+    Json(String),
+    /// The ballot (or its `votes` field) isn't a json array of votes.
+    NotAnArray,
+    /// The ballot object has a field other than `nonce` and `votes`.
+    UnknownBallotField(String),
+    /// The ballot object has votes but no nonce.
+    MissingNonce,
+    /// The nonce isn't a non negative integer that fits in a `u64`.
+    InvalidNonce(String),
+    VoteNotAnObject {
+        index: usize,
+    },
+    MissingField {
+        index: usize,
+        field: &'static str,
+    },
+    UnknownField {
+        index: usize,
+        field: String,
+    },
+    /// The project id isn't a `0x` prefixed 32 byte hex string.
+    InvalidProjectId {
+        index: Option<usize>,
+        value: String,
+    },
+    /// The amount isn't a non negative decimal number.
+    InvalidAmount {
+        index: Option<usize>,
+        value: String,
+    },
+    DuplicateProject {
+        index: usize,
+        project_id: ProjectId,
+    },
+    /// The amount can't be represented exactly as a fixed point integer with `scale` decimals.
+    AmountOutOfRange {
+        value: String,
+        scale: u32,
+    },
+}
+
+impl BallotError {
+    /// Attaches the index of the vote the error was found in.
+    fn at(self, vote_index: usize) -> Self {
+        match self {
+            BallotError::InvalidProjectId { value, .. } => BallotError::InvalidProjectId {
+                index: Some(vote_index),
+                value,
+            },
+            BallotError::InvalidAmount { value, .. } => BallotError::InvalidAmount {
+                index: Some(vote_index),
+                value,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for BallotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let at = |index: &Option<usize>| match index {
+            Some(index) => format!(" in vote {}", index),
+            None => String::new(),
+        };
+        match self {
+            BallotError::Json(e) => write!(f, "ballot is not valid json: {}", e),
+            BallotError::NotAnArray => write!(f, "ballot is not a json array of votes"),
+            BallotError::UnknownBallotField(field) => {
+                write!(f, "ballot has an unknown field `{}`", field)
+            }
+            BallotError::MissingNonce => write!(f, "ballot object is missing the `nonce` field"),
+            BallotError::InvalidNonce(value) => write!(
+                f,
+                "invalid nonce {}, expected a non negative 64 bit integer",
+                value
+            ),
+            BallotError::VoteNotAnObject { index } => write!(f, "vote {} is not an object", index),
+            BallotError::MissingField { index, field } => {
+                write!(f, "vote {} is missing the `{}` field", index, field)
+            }
+            BallotError::UnknownField { index, field } => {
+                write!(f, "vote {} has an unknown field `{}`", index, field)
+            }
+            BallotError::InvalidProjectId { index, value } => write!(
+                f,
+                "invalid project id {}{}, expected a 0x prefixed 32 byte hex string",
+                value,
+                at(index)
+            ),
+            BallotError::InvalidAmount { index, value } => write!(
+                f,
+                "invalid amount {}{}, expected a non negative decimal number",
+                value,
+                at(index)
+            ),
+            BallotError::DuplicateProject { index, project_id } => write!(
+                f,
+                "vote {} is a second vote for project {}",
+                index, project_id
+            ),
+            BallotError::AmountOutOfRange { value, scale } => write!(
+                f,
+                "amount {} can't be represented exactly with {} decimals",
+                value, scale
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BallotError {}
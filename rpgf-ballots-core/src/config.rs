@@ -0,0 +1,173 @@
+use crate::ballot::DEFAULT_AMOUNT_SCALE;
+use crate::eip712::Eip712Domain;
+use crate::eligibility::Allowlist;
+use crate::rejection::RejectionPolicy;
+use crate::tally::TallyConfig;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Options passed by the host to both guests.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuestConfig {
+    /// Identifier of the RetroPGF round, committed to the journal.
+    pub round_id: u32,
+    /// Number of decimals vote amounts are scaled by before hashing.
+    pub amount_scale: u32,
+    /// What to do with ballots that fail validation.
+    pub rejection_policy: RejectionPolicy,
+    /// Badgeholder addresses allowed to vote. Every signer is eligible if it isn't set.
+    pub eligibility: Option<Allowlist>,
+    /// Domain of EIP-712 typed data ballot signatures, which are only accepted if it is set.
+    pub eip712: Option<Eip712Domain>,
+    /// Parameters of the allocations the aggregate guest computes, if it should.
+    pub tally: Option<TallyConfig>,
+    /// How the vote amounts are hashed.
+    pub poseidon: PoseidonConfig,
+    /// Secret the blinding salt of each project hash is derived from, if the hashes are salted.
+    /// Only its [`salt_commitment`](crate::salt::salt_commitment) is committed.
+    pub salt_secret: Option<[u8; 32]>,
+}
+
+impl GuestConfig {
+    /// The EIP-712 domain separator of the round, if typed data signatures are accepted.
+    pub fn eip712_domain_separator(&self) -> Option<[u8; 32]> {
+        self.eip712
+            .as_ref()
+            .map(|domain| domain.separator(self.round_id))
+    }
+}
+
+impl Default for GuestConfig {
+    fn default() -> Self {
+        GuestConfig {
+            round_id: 0,
+            amount_scale: DEFAULT_AMOUNT_SCALE,
+            rejection_policy: RejectionPolicy::default(),
+            eligibility: None,
+            eip712: None,
+            tally: None,
+            poseidon: PoseidonConfig::default(),
+            salt_secret: None,
+        }
+    }
+}
+
+/// The Poseidon permutations the vote amounts can be hashed with.
+///
+/// Written as `w2r1` or `w3r2`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoseidonSpecId {
+    /// Width 2 and rate 1, with 8 full and 56 partial rounds, as in ezkl's Poseidon chip.
+    #[default]
+    W2R1,
+    /// Width 3 and rate 2, with 8 full and 57 partial rounds, absorbing two amounts per
+    /// permutation.
+    W3R2,
+}
+
+impl FromStr for PoseidonSpecId {
+    type Err = PoseidonConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w2r1" => Ok(PoseidonSpecId::W2R1),
+            "w3r2" => Ok(PoseidonSpecId::W3R2),
+            _ => Err(PoseidonConfigError::UnknownSpec(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for PoseidonSpecId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoseidonSpecId::W2R1 => write!(f, "w2r1"),
+            PoseidonSpecId::W3R2 => write!(f, "w3r2"),
+        }
+    }
+}
+
+/// How the tree hash of the vote amounts pads its chunks.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TreeHashMode {
+    /// Chunks are zero padded and hashed as constant length messages, so trailing zero amounts
+    /// don't change the hash. Kept to recompute the hashes of earlier receipts.
+    Legacy,
+    /// The capacity element of each chunk's hash also encodes its tree level and the number of
+    /// its elements that aren't padding, binding the number of amounts.
+    #[default]
+    LengthBinding,
+}
+
+impl fmt::Display for TreeHashMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeHashMode::Legacy => write!(f, "legacy"),
+            TreeHashMode::LengthBinding => write!(f, "length binding"),
+        }
+    }
+}
+
+/// Number of vote amounts hashed together at each level of the tree hash, unless configured
+/// otherwise.
+pub const DEFAULT_POSEIDON_CHUNK_LEN: u32 = 32;
+
+/// The chunk lengths the guests can hash vote amounts with.
+pub const POSEIDON_CHUNK_LENS: [u32; 4] = [8, 16, 32, 64];
+
+/// The Poseidon tree hash of the vote amounts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoseidonConfig {
+    pub spec: PoseidonSpecId,
+    /// Number of vote amounts hashed together at each level of the tree hash, one of
+    /// [`POSEIDON_CHUNK_LENS`].
+    pub chunk_len: u32,
+    pub tree_hash: TreeHashMode,
+}
+
+impl PoseidonConfig {
+    /// Checks that the guests support the chunk length.
+    pub fn check(&self) -> Result<(), PoseidonConfigError> {
+        if POSEIDON_CHUNK_LENS.contains(&self.chunk_len) {
+            Ok(())
+        } else {
+            Err(PoseidonConfigError::UnsupportedChunkLen(self.chunk_len))
+        }
+    }
+}
+
+impl Default for PoseidonConfig {
+    fn default() -> Self {
+        PoseidonConfig {
+            spec: PoseidonSpecId::default(),
+            chunk_len: DEFAULT_POSEIDON_CHUNK_LEN,
+            tree_hash: TreeHashMode::default(),
+        }
+    }
+}
+
+/// A Poseidon configuration the guests don't support.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoseidonConfigError {
+    UnknownSpec(String),
+    UnsupportedChunkLen(u32),
+}
+
+impl fmt::Display for PoseidonConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoseidonConfigError::UnknownSpec(spec) => {
+                write!(f, "unknown Poseidon spec {}, expected w2r1 or w3r2", spec)
+            }
+            PoseidonConfigError::UnsupportedChunkLen(chunk_len) => write!(
+                f,
+                "unsupported Poseidon chunk length {}, expected one of {:?}",
+                chunk_len, POSEIDON_CHUNK_LENS
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PoseidonConfigError {}
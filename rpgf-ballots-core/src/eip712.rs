@@ -0,0 +1,94 @@
+use crate::ballot::{Ballot, Vote};
+use crate::eligibility::Address;
+use crate::signature::keccak256;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+const DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)";
+const VOTE_TYPE: &str = "Vote(bytes32 projectId,string amount)";
+// referenced struct types are appended to the primary type, sorted by name
+const BALLOT_TYPE: &str = "Ballot(uint256 nonce,Vote[] votes)Vote(bytes32 projectId,string amount)";
+
+/// The EIP-712 domain ballots are signed in when they are signed as typed data.
+///
+/// Ballots are signed as the `Ballot(uint256 nonce,Vote[] votes)` struct, with
+/// `Vote(bytes32 projectId,string amount)` votes whose amounts are the decimal strings of the
+/// ballot json. The round id is the domain's `salt`, as a big endian `bytes32`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: Address,
+}
+
+impl Default for Eip712Domain {
+    /// The `RetroPGF` version `1` domain on OP mainnet, without a verifying contract.
+    fn default() -> Self {
+        Eip712Domain {
+            name: "RetroPGF".to_string(),
+            version: "1".to_string(),
+            chain_id: 10,
+            verifying_contract: [0; 20],
+        }
+    }
+}
+
+impl Eip712Domain {
+    /// The domain separator of the domain for the round `round_id`.
+    pub fn separator(&self, round_id: u32) -> [u8; 32] {
+        let mut salt = [0; 32];
+        salt[28..].copy_from_slice(&round_id.to_be_bytes());
+        let mut hasher = Keccak256::new();
+        hasher.update(keccak256(DOMAIN_TYPE.as_bytes()));
+        hasher.update(keccak256(self.name.as_bytes()));
+        hasher.update(keccak256(self.version.as_bytes()));
+        hasher.update(uint256(self.chain_id));
+        hasher.update(address(&self.verifying_contract));
+        hasher.update(salt);
+        hasher.finalize().into()
+    }
+}
+
+/// The EIP-712 digest a typed data signature of `ballot` signs, given the domain separator.
+pub fn ballot_digest(domain_separator: &[u8; 32], ballot: &Ballot) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update([0x19, 0x01]);
+    hasher.update(domain_separator);
+    hasher.update(hash_ballot(ballot));
+    hasher.finalize().into()
+}
+
+fn hash_ballot(ballot: &Ballot) -> [u8; 32] {
+    let mut votes = Keccak256::new();
+    for vote in ballot.votes() {
+        votes.update(hash_vote(vote));
+    }
+    let mut hasher = Keccak256::new();
+    hasher.update(keccak256(BALLOT_TYPE.as_bytes()));
+    hasher.update(uint256(ballot.nonce()));
+    hasher.update(votes.finalize());
+    hasher.finalize().into()
+}
+
+fn hash_vote(vote: &Vote) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(keccak256(VOTE_TYPE.as_bytes()));
+    hasher.update(vote.project_id.0);
+    hasher.update(keccak256(vote.amount.as_str().as_bytes()));
+    hasher.finalize().into()
+}
+
+fn uint256(value: u64) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn address(address: &Address) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
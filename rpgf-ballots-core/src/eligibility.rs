@@ -0,0 +1,82 @@
+use crate::signature::keccak256;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// A 20 byte Ethereum address.
+pub type Address = [u8; 20];
+
+/// The set of badgeholder addresses allowed to vote.
+///
+/// Addresses are kept sorted and deduplicated, so that the same set always produces the same
+/// [`Allowlist::root`] whatever order the addresses were listed in.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<Address>", into = "Vec<Address>")]
+pub struct Allowlist(Vec<Address>);
+
+impl Allowlist {
+    pub fn new(mut addresses: Vec<Address>) -> Self {
+        addresses.sort_unstable();
+        addresses.dedup();
+        Allowlist(addresses)
+    }
+
+    pub fn addresses(&self) -> &[Address] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, address: &Address) -> bool {
+        self.0.binary_search(address).is_ok()
+    }
+
+    /// Keccak Merkle root of the set, zero if it is empty.
+    ///
+    /// The leaves are the keccak256 hashes of the addresses in ascending order, and each parent
+    /// is the keccak256 hash of its two children in ascending order (the OpenZeppelin
+    /// `MerkleProof` convention). An odd node is promoted to the next level as is.
+    pub fn root(&self) -> [u8; 32] {
+        let mut level: Vec<[u8; 32]> = self.0.iter().map(|address| keccak256(address)).collect();
+        if level.is_empty() {
+            return [0; 32];
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        level[0]
+    }
+}
+
+impl From<Vec<Address>> for Allowlist {
+    fn from(addresses: Vec<Address>) -> Self {
+        Allowlist::new(addresses)
+    }
+}
+
+impl From<Allowlist> for Vec<Address> {
+    fn from(allowlist: Allowlist) -> Self {
+        allowlist.0
+    }
+}
+
+fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Keccak256::new();
+    hasher.update(first);
+    hasher.update(second);
+    hasher.finalize().into()
+}
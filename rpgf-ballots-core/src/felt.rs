@@ -0,0 +1,76 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use bytemuck::{Pod, Zeroable};
+use core::fmt;
+#[cfg(feature = "poseidon")]
+use ff::PrimeField;
+#[cfg(feature = "poseidon")]
+use halo2curves::bn256::Fr;
+use serde::{Deserialize, Serialize};
+
+/// A BN254 field element as committed to the journal, in little endian u64 limbs.
+///
+/// Needs to be a custom type with the `Pod` and `Zeroable` traits for wasm serialization of
+/// the journal instances.
+#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Felt(pub [u64; 4]);
+
+impl Felt {
+    /// The `0x` prefixed hex string of the little endian bytes of the limbs, the format hashes
+    /// are shown and edited in by `browser-verify`.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(bytemuck::bytes_of(self)))
+    }
+
+    /// Parses a [`Felt::to_hex`] string, with or without the `0x` prefix.
+    pub fn from_hex(hex_str: &str) -> Result<Self, FeltError> {
+        let invalid = || FeltError::InvalidHex(hex_str.to_string());
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(hex_str.trim_start_matches("0x"), &mut bytes)
+            .map_err(|_| invalid())?;
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Ok(Felt(limbs))
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl From<Fr> for Felt {
+    fn from(value: Fr) -> Self {
+        let repr = value.to_repr();
+        let mut limbs = [0u64; 4];
+        for (limb, bytes) in limbs.iter_mut().zip(repr.as_ref().chunks(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Felt(limbs)
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl From<Felt> for Fr {
+    fn from(value: Felt) -> Self {
+        Fr::from_raw(value.0)
+    }
+}
+
+/// Why a string isn't a [`Felt`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FeltError {
+    InvalidHex(String),
+}
+
+impl fmt::Display for FeltError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeltError::InvalidHex(value) => {
+                write!(f, "invalid felt {}, expected 32 hex bytes", value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeltError {}
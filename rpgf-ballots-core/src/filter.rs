@@ -0,0 +1,416 @@
+use crate::ballot::{Amount, ProjectId};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// The criteria selecting which projects the aggregate guests commit the hashes of, committed
+/// to the journal next to them.
+///
+/// The default filter selects every project of the registry.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectFilter {
+    pub expr: FilterExpr,
+    /// The projects of each category the expression refers to.
+    pub categories: BTreeMap<String, Vec<ProjectId>>,
+}
+
+impl ProjectFilter {
+    /// Whether a project with the fixed point vote `amounts` is selected.
+    pub fn matches(&self, project_id: &ProjectId, amounts: &[i128]) -> bool {
+        self.expr.matches(project_id, amounts, &self.categories)
+    }
+}
+
+/// A filter expression over the projects of the registry, parsed from
+///
+/// ```text
+/// expr      := term ("or" term)*
+/// term      := factor ("and" factor)*
+/// factor    := "not" factor | "(" expr ")" | predicate
+/// predicate := "all"
+///            | "votes" ("<" | "<=" | ">" | ">=" | "==") count
+///            | "votes" "in" min ".." max
+///            | ("total" | "median") ("<" | "<=" | ">" | ">=" | "==") amount
+///            | "project" "in" "[" project_id ("," project_id)* "]"
+///            | "category" "in" "[" name ("," name)* "]"
+/// ```
+///
+/// `votes` is the number of counted votes of a project, `total` and `median` the sum and median
+/// of their decimal amounts, the mean of the two middle amounts for an even number of votes.
+/// Projects without votes have a total of zero and no median, so median predicates don't select
+/// them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterExpr {
+    #[default]
+    All,
+    VoteCount {
+        comparison: Comparison,
+        count: u32,
+    },
+    /// An inclusive range of vote counts.
+    VoteCountRange {
+        min: u32,
+        max: u32,
+    },
+    /// The sum of the fixed point vote amounts.
+    Total {
+        comparison: Comparison,
+        amount: i128,
+    },
+    /// The median of the fixed point vote amounts.
+    Median {
+        comparison: Comparison,
+        amount: i128,
+    },
+    Projects(Vec<ProjectId>),
+    Categories(Vec<String>),
+    Not(Box<FilterExpr>),
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+}
+
+impl Comparison {
+    /// Whether `value` compares to `threshold` this way.
+    pub fn holds<T: Ord>(&self, value: T, threshold: T) -> bool {
+        match self {
+            Comparison::Lt => value < threshold,
+            Comparison::Le => value <= threshold,
+            Comparison::Gt => value > threshold,
+            Comparison::Ge => value >= threshold,
+            Comparison::Eq => value == threshold,
+        }
+    }
+}
+
+impl FilterExpr {
+    /// Parses a filter expression, scaling its amounts by `amount_scale` decimals like the vote
+    /// amounts.
+    pub fn parse(expr: &str, amount_scale: u32) -> Result<Self, FilterError> {
+        let mut parser = Parser {
+            tokens: tokenize(expr)?,
+            position: 0,
+            amount_scale,
+        };
+        let expr = parser.expr()?;
+        match parser.next() {
+            None => Ok(expr),
+            Some(token) => Err(FilterError::UnexpectedToken(token)),
+        }
+    }
+
+    /// Whether a project with the fixed point vote `amounts` is selected, given the projects of
+    /// each category.
+    pub fn matches(
+        &self,
+        project_id: &ProjectId,
+        amounts: &[i128],
+        categories: &BTreeMap<String, Vec<ProjectId>>,
+    ) -> bool {
+        match self {
+            FilterExpr::All => true,
+            FilterExpr::VoteCount { comparison, count } => {
+                comparison.holds(amounts.len() as u64, *count as u64)
+            }
+            FilterExpr::VoteCountRange { min, max } => {
+                (*min as usize..=*max as usize).contains(&amounts.len())
+            }
+            FilterExpr::Total { comparison, amount } => comparison.holds(
+                amounts
+                    .iter()
+                    .fold(0i128, |total, amount| total.saturating_add(*amount)),
+                *amount,
+            ),
+            FilterExpr::Median { comparison, amount } => {
+                if amounts.is_empty() {
+                    return false;
+                }
+                let mut sorted = amounts.to_vec();
+                sorted.sort_unstable();
+                // twice the median against twice the threshold, to stay exact
+                let (low, high) = ((sorted.len() - 1) / 2, sorted.len() / 2);
+                comparison.holds(
+                    sorted[low].saturating_add(sorted[high]),
+                    amount.saturating_mul(2),
+                )
+            }
+            FilterExpr::Projects(project_ids) => project_ids.contains(project_id),
+            FilterExpr::Categories(names) => names.iter().any(|name| {
+                categories
+                    .get(name)
+                    .into_iter()
+                    .any(|project_ids| project_ids.contains(project_id))
+            }),
+            FilterExpr::Not(expr) => !expr.matches(project_id, amounts, categories),
+            FilterExpr::And(left, right) => {
+                left.matches(project_id, amounts, categories)
+                    && right.matches(project_id, amounts, categories)
+            }
+            FilterExpr::Or(left, right) => {
+                left.matches(project_id, amounts, categories)
+                    || right.matches(project_id, amounts, categories)
+            }
+        }
+    }
+
+    /// The names of the categories the expression refers to.
+    pub fn categories(&self) -> Vec<&str> {
+        match self {
+            FilterExpr::Categories(names) => names.iter().map(String::as_str).collect(),
+            FilterExpr::Not(expr) => expr.categories(),
+            FilterExpr::And(left, right) | FilterExpr::Or(left, right) => {
+                let mut names = left.categories();
+                names.extend(right.categories());
+                names
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Both expressions, `self` alone if `other` selects every project.
+    pub fn and(self, other: FilterExpr) -> FilterExpr {
+        match (self, other) {
+            (expr, FilterExpr::All) | (FilterExpr::All, expr) => expr,
+            (left, right) => FilterExpr::And(Box::new(left), Box::new(right)),
+        }
+    }
+}
+
+/// Reasons a filter expression doesn't parse.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FilterError {
+    UnexpectedEnd,
+    UnexpectedToken(String),
+    UnexpectedCharacter(char),
+    InvalidCount(String),
+    InvalidAmount(String),
+    InvalidProjectId(String),
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterError::UnexpectedEnd => write!(f, "unexpected end of the filter"),
+            FilterError::UnexpectedToken(token) => {
+                write!(f, "unexpected `{}` in the filter", token)
+            }
+            FilterError::UnexpectedCharacter(c) => {
+                write!(f, "unexpected character `{}` in the filter", c)
+            }
+            FilterError::InvalidCount(value) => write!(
+                f,
+                "invalid vote count {}, expected a 32 bit unsigned integer",
+                value
+            ),
+            FilterError::InvalidAmount(value) => write!(
+                f,
+                "invalid amount {}, expected a non negative decimal number",
+                value
+            ),
+            FilterError::InvalidProjectId(value) => write!(
+                f,
+                "invalid project id {}, expected a 0x prefixed 32 byte hex string",
+                value
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FilterError {}
+
+/// Splits an expression into words (keywords, numbers, project ids and category names) and
+/// punctuation.
+fn tokenize(expr: &str) -> Result<Vec<String>, FilterError> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let length = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' | ')' | '[' | ']' | ',' => 1,
+            '<' | '>' if next == Some('=') => 2,
+            '<' | '>' => 1,
+            '=' if next == Some('=') => 2,
+            '.' if next == Some('.') => 2,
+            c if is_word_char(c) => {
+                let mut end = i;
+                // a word ends before a `..` range
+                while end < chars.len()
+                    && is_word_char(chars[end])
+                    && !(chars[end] == '.' && chars.get(end + 1) == Some(&'.'))
+                {
+                    end += 1;
+                }
+                end - i
+            }
+            c => return Err(FilterError::UnexpectedCharacter(c)),
+        };
+        tokens.push(chars[i..i + length].iter().collect());
+        i += length;
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    position: usize,
+    amount_scale: u32,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.position).map(String::as_str)
+    }
+
+    fn word(&mut self) -> Result<String, FilterError> {
+        self.next().ok_or(FilterError::UnexpectedEnd)
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), FilterError> {
+        let token = self.word()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(FilterError::UnexpectedToken(token))
+        }
+    }
+
+    fn expr(&mut self) -> Result<FilterExpr, FilterError> {
+        let mut expr = self.term()?;
+        while self.peek() == Some("or") {
+            self.position += 1;
+            expr = FilterExpr::Or(Box::new(expr), Box::new(self.term()?));
+        }
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<FilterExpr, FilterError> {
+        let mut expr = self.factor()?;
+        while self.peek() == Some("and") {
+            self.position += 1;
+            expr = FilterExpr::And(Box::new(expr), Box::new(self.factor()?));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<FilterExpr, FilterError> {
+        let token = self.word()?;
+        match token.as_str() {
+            "not" => Ok(FilterExpr::Not(Box::new(self.factor()?))),
+            "(" => {
+                let expr = self.expr()?;
+                self.expect(")")?;
+                Ok(expr)
+            }
+            "all" => Ok(FilterExpr::All),
+            "votes" if self.peek() == Some("in") => {
+                self.position += 1;
+                let min = self.count()?;
+                self.expect("..")?;
+                let max = self.count()?;
+                Ok(FilterExpr::VoteCountRange { min, max })
+            }
+            "votes" => Ok(FilterExpr::VoteCount {
+                comparison: self.comparison()?,
+                count: self.count()?,
+            }),
+            "total" => Ok(FilterExpr::Total {
+                comparison: self.comparison()?,
+                amount: self.amount()?,
+            }),
+            "median" => Ok(FilterExpr::Median {
+                comparison: self.comparison()?,
+                amount: self.amount()?,
+            }),
+            "project" => {
+                self.expect("in")?;
+                let project_ids = self
+                    .list()?
+                    .into_iter()
+                    .map(|project_id| {
+                        project_id
+                            .parse()
+                            .map_err(|_| FilterError::InvalidProjectId(project_id))
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(FilterExpr::Projects(project_ids))
+            }
+            "category" => {
+                self.expect("in")?;
+                Ok(FilterExpr::Categories(self.list()?))
+            }
+            _ => Err(FilterError::UnexpectedToken(token)),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, FilterError> {
+        let token = self.word()?;
+        Ok(match token.as_str() {
+            "<" => Comparison::Lt,
+            "<=" => Comparison::Le,
+            ">" => Comparison::Gt,
+            ">=" => Comparison::Ge,
+            "==" => Comparison::Eq,
+            _ => return Err(FilterError::UnexpectedToken(token)),
+        })
+    }
+
+    fn count(&mut self) -> Result<u32, FilterError> {
+        let token = self.word()?;
+        token.parse().map_err(|_| FilterError::InvalidCount(token))
+    }
+
+    fn amount(&mut self) -> Result<i128, FilterError> {
+        let token = self.word()?;
+        token
+            .parse::<Amount>()
+            .and_then(|amount| amount.to_fixed(self.amount_scale))
+            .map_err(|_| FilterError::InvalidAmount(token))
+    }
+
+    /// A `[word, ..]` list of at least one word.
+    fn list(&mut self) -> Result<Vec<String>, FilterError> {
+        self.expect("[")?;
+        let mut words = vec![];
+        loop {
+            let word = self.word()?;
+            if !word.chars().all(is_word_char) {
+                return Err(FilterError::UnexpectedToken(word));
+            }
+            words.push(word);
+            match self.word()?.as_str() {
+                "," => continue,
+                "]" => return Ok(words),
+                token => return Err(FilterError::UnexpectedToken(token.to_string())),
+            }
+        }
+    }
+}
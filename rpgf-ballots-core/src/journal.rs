@@ -0,0 +1,265 @@
+use crate::ballot::ProjectId;
+use crate::config::{PoseidonConfig, PoseidonSpecId, TreeHashMode};
+use crate::eligibility::Address;
+use crate::felt::Felt;
+use crate::filter::ProjectFilter;
+use crate::rejection::Rejection;
+use crate::sha256::HashSpan;
+use crate::tally::Tally;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Version of the journal layout committed by the guests, bumped on every breaking change.
+pub const JOURNAL_VERSION: u32 = 1;
+
+/// Parameters of the Poseidon tree hash the vote amounts were hashed with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoseidonParams {
+    /// The permutation, which the other parameters describe.
+    pub spec: PoseidonSpecId,
+    /// Width of the permutation state.
+    pub width: u32,
+    /// Number of field elements absorbed per permutation.
+    pub rate: u32,
+    pub full_rounds: u32,
+    pub partial_rounds: u32,
+    /// Number of vote amounts hashed together at each level of the tree hash.
+    pub chunk_len: u32,
+    pub tree_hash: TreeHashMode,
+}
+
+impl PoseidonParams {
+    /// The configuration to recompute the committed hashes with.
+    pub fn config(&self) -> PoseidonConfig {
+        PoseidonConfig {
+            spec: self.spec,
+            chunk_len: self.chunk_len,
+            tree_hash: self.tree_hash,
+        }
+    }
+}
+
+/// Metadata committed by both guests ahead of their results, identifying what a receipt covers.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JournalHeader {
+    /// Always [`JOURNAL_VERSION`] for journals produced by the current guests.
+    pub version: u32,
+    /// Identifier of the RetroPGF round the ballots were cast in.
+    pub round_id: u32,
+    /// Sha256 hash of the processed inputs file.
+    pub inputs_hash: [u8; 32],
+    /// Sha256 hash of the verifying keys file the ballots were validated against.
+    pub verifying_keys_hash: [u8; 32],
+    /// Sha256 hash of the project registry file votes were checked against.
+    pub project_registry_hash: [u8; 32],
+    /// Sha256 hash of the Safe owner sets file multisig ballots were checked against, if any.
+    pub safe_owners_hash: Option<[u8; 32]>,
+    /// Merkle root of the badgeholder allowlist every counted signer belongs to, if one was
+    /// enforced.
+    pub eligibility_root: Option<[u8; 32]>,
+    /// Domain separator of the EIP-712 typed data signatures accepted, if any.
+    pub eip712_domain_separator: Option<[u8; 32]>,
+    /// Number of decimals the vote amounts were scaled by before hashing.
+    pub amount_scale: u32,
+    pub poseidon: PoseidonParams,
+    /// Commitment to the secret the project hashes were salted with, if they were.
+    pub salt_commitment: Option<[u8; 32]>,
+    /// Number of ballots in the processed inputs.
+    pub ballot_count: u32,
+    /// Number of ballots that passed validation and were counted.
+    pub accepted_count: u32,
+    /// Number of valid ballots left out of the tally because their signer cast a later ballot.
+    pub superseded_count: u32,
+    /// Ballots that were left out of the tally, in input order.
+    pub rejections: Vec<Rejection>,
+}
+
+impl JournalHeader {
+    /// Checks that the journal was produced with the layout these types decode.
+    pub fn check_version(&self) -> Result<(), UnsupportedVersion> {
+        if self.version == JOURNAL_VERSION {
+            Ok(())
+        } else {
+            Err(UnsupportedVersion(self.version))
+        }
+    }
+}
+
+/// A journal version other than [`JOURNAL_VERSION`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnsupportedVersion(pub u32);
+
+impl fmt::Display for UnsupportedVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported journal version {}, expected {}",
+            self.0, JOURNAL_VERSION
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedVersion {}
+
+/// Journal committed by the single project guest (project.rs).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectJournal {
+    pub header: JournalHeader,
+    /// The project the vote amounts were hashed for.
+    pub project_id: ProjectId,
+    /// Poseidon hash of the project's vote amounts, salted if the header has a salt commitment,
+    /// zero if it received no votes.
+    pub hash: Felt,
+    /// Root of the Poseidon Merkle tree of the project's `(badgeholder commitment, amount)` vote
+    /// leaves hashed with their number, zero if it received no votes.
+    pub votes_root: Felt,
+}
+
+/// The hashes of a project committed by the aggregate guest (projects.rs).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectHash {
+    pub project_id: ProjectId,
+    /// Number of counted votes for the project.
+    pub vote_count: u32,
+    /// See [`ProjectJournal::hash`].
+    pub hash: Felt,
+    /// See [`ProjectJournal::votes_root`].
+    pub votes_root: Felt,
+}
+
+/// Journal committed by the aggregate guest (projects.rs).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectsJournal {
+    pub header: JournalHeader,
+    /// The criteria the projects were selected with.
+    pub filter: ProjectFilter,
+    /// Every project of the registry the filter selects, in registry order, including the
+    /// ones without votes.
+    pub projects: Vec<ProjectHash>,
+    /// Allocations of the pool to the projects, if the config asked for them.
+    pub tally: Option<Tally>,
+    /// Image id of the shard guest (shard.rs) whose receipts the ballots were validated by, if
+    /// they were proven in shards by the shards guest (shards.rs), `None` in the journal of the
+    /// aggregate guest. Verifiers check it with [`ProjectsJournal::check_shard_image_id`].
+    ///
+    /// It is the only field the journals of the two guests differ in for the same round: the
+    /// header, and its hashes of the round files in particular, is the same.
+    pub shard_image_id: Option<[u32; 8]>,
+}
+
+impl ProjectsJournal {
+    /// Checks that the ballots were validated by the shard guest of image id `shard_image_id`
+    /// for a receipt of the shards guest, or weren't proven in shards (`None`) for a receipt of
+    /// the aggregate guest. The shards guest takes the image id its assumptions are verified
+    /// against from the host, so its receipt only holds with the published one.
+    pub fn check_shard_image_id(
+        &self,
+        shard_image_id: Option<&[u32; 8]>,
+    ) -> Result<(), UnexpectedImageId> {
+        if self.shard_image_id.as_ref() == shard_image_id {
+            Ok(())
+        } else {
+            Err(UnexpectedImageId(self.shard_image_id))
+        }
+    }
+}
+
+/// An image id committed by a journal, of a guest whose receipts it verified, other than the
+/// published one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnexpectedImageId(pub Option<[u32; 8]>);
+
+impl fmt::Display for UnexpectedImageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(image_id) => write!(f, "unexpected image id {:08x?} in the journal", image_id),
+            None => write!(f, "missing image id in the journal"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnexpectedImageId {}
+
+/// A ballot that passed validation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidBallot {
+    /// Index of the ballot in the processed inputs.
+    pub index: u32,
+    /// The address that cast the ballot, the Safe address for Safe ballots.
+    pub signer: Address,
+    pub nonce: u64,
+    /// The project and fixed point amount of each vote of the ballot, in vote order.
+    pub votes: Vec<(ProjectId, i128)>,
+}
+
+/// Journal committed by the shard guest (shard.rs) for a contiguous range of the processed
+/// inputs, which the shards guest (shards.rs) verifies as an assumption.
+///
+/// Shards don't supersede ballots, since a badgeholder's ballots may land in different shards:
+/// they commit every valid ballot, and the shards guest keeps the latest of each signer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShardJournal {
+    /// Index of the first ballot of the shard in the processed inputs.
+    pub offset: u32,
+    /// Number of ballots in the shard.
+    pub ballot_count: u32,
+    /// Number of verifying keys of the shard's ballots, fewer than its ballots if the processed
+    /// inputs ran out of verifying keys.
+    pub verifying_key_count: u32,
+    /// The shard's part in the sha256 hash of the processed inputs file: the bincode encoded
+    /// ballots of the shard.
+    pub inputs: HashSpan,
+    /// The shard's part in the sha256 hash of the verifying keys file: the bincode encoded
+    /// verifying keys of the shard's ballots.
+    pub verifying_keys: HashSpan,
+    pub project_registry_hash: [u8; 32],
+    pub safe_owners_hash: Option<[u8; 32]>,
+    /// Sha256 hash of the guest config the ballots were validated with.
+    pub config_hash: [u8; 32],
+    /// The ballots that passed validation, in input order.
+    pub ballots: Vec<ValidBallot>,
+    /// The ballots that were rejected, in input order.
+    pub rejections: Vec<Rejection>,
+}
+
+/// The processed inputs a step of the incremental guest (increment.rs) was proven over.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputCommitment {
+    /// Sha256 hash of the processed inputs file.
+    pub inputs_hash: [u8; 32],
+    /// Number of ballots in the processed inputs.
+    pub ballot_count: u32,
+}
+
+/// Journal committed by the incremental guest (increment.rs), which extends the receipt of the
+/// previous step, verified as an assumption, with the ballots appended to the processed inputs
+/// since, checking the signatures of the new ballots only.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IncrementalJournal {
+    /// The aggregate journal of every ballot so far, the one of the non-incremental run.
+    pub journal: ProjectsJournal,
+    /// Image id the previous steps were verified against, which the guest can't know by itself.
+    /// Verifiers check it with [`IncrementalJournal::check_image_id`], which makes every step
+    /// of the chain a run of the same guest.
+    pub image_id: [u32; 8],
+    /// Sha256 hash of the guest config, the same for every step. The salt secret is hashed as
+    /// its commitment, never as is.
+    pub config_hash: [u8; 32],
+    /// The processed inputs of every step, oldest first, each extending the previous one.
+    pub input_chain: Vec<InputCommitment>,
+}
+
+impl IncrementalJournal {
+    /// Checks that the previous steps were verified against `image_id`, the published image id
+    /// of the incremental guest the receipt itself verifies against.
+    pub fn check_image_id(&self, image_id: &[u32; 8]) -> Result<(), UnexpectedImageId> {
+        if self.image_id == *image_id {
+            Ok(())
+        } else {
+            Err(UnexpectedImageId(Some(self.image_id)))
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Types shared by the RetroPGF ballot guests (risc0 zkVM), the host and `browser-verify`.
+//!
+//! Builds without `std` (with `alloc`) when the default `std` and `poseidon` features are
+//! disabled. The Poseidon hashes need `halo2curves`, which requires `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod ballot;
+pub mod config;
+pub mod eip712;
+pub mod eligibility;
+pub mod felt;
+pub mod filter;
+pub mod journal;
+#[cfg(feature = "poseidon")]
+pub mod merkle;
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+pub mod rejection;
+pub mod safe;
+pub mod salt;
+pub mod sha256;
+pub mod signature;
+pub mod supersede;
+pub mod tally;
+pub mod tally_rule;
+pub mod validation;
+
+pub use ballot::{
+    Amount, Ballot, BallotError, ProjectId, Vote, DEFAULT_AMOUNT_SCALE, MAX_AMOUNT_SCALE,
+};
+pub use config::{
+    GuestConfig, PoseidonConfig, PoseidonConfigError, PoseidonSpecId, TreeHashMode,
+    DEFAULT_POSEIDON_CHUNK_LEN, POSEIDON_CHUNK_LENS,
+};
+pub use eip712::{ballot_digest, Eip712Domain};
+pub use eligibility::{Address, Allowlist};
+pub use felt::{Felt, FeltError};
+pub use filter::{Comparison, FilterError, FilterExpr, ProjectFilter};
+pub use journal::{
+    IncrementalJournal, InputCommitment, JournalHeader, PoseidonParams, ProjectHash,
+    ProjectJournal, ProjectsJournal, ShardJournal, UnexpectedImageId, UnsupportedVersion,
+    ValidBallot, JOURNAL_VERSION,
+};
+pub use rejection::{Rejection, RejectionPolicy, RejectionReason};
+pub use safe::{find_safe, parse_safe_owners, SafeOwners, SafeOwnersError, SAFE_SIGNATURE_LEN};
+pub use salt::{leaf_nonce, project_salt, salt_commitment, votes_root_salt};
+pub use sha256::{HashSpan, Sha256State};
+pub use supersede::superseded;
+pub use tally::{allocate, tally, Allocation, Tally, TallyConfig, TallyError};
+pub use tally_rule::{BuiltinRule, Mean, Median, Quadratic, Sum, TallyRule, TrimmedMean};
+pub use validation::{validate_ballot, BallotChecks};
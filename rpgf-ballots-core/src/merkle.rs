@@ -0,0 +1,153 @@
+use crate::ballot::ProjectId;
+use crate::eligibility::Address;
+use crate::felt::Felt;
+use crate::poseidon::{
+    i128_to_felt, ConstantLength, Hash, PoseidonSpec, POSEIDON_RATE, POSEIDON_WIDTH,
+};
+use ff::Field;
+use halo2curves::bn256::Fr as Fp;
+use serde::{Deserialize, Serialize};
+
+// Merkle trees of the votes for a project, letting each badgeholder check that their vote was
+// counted without seeing the other votes.
+
+/// Domain tags of the leaves, inner nodes and root of the votes trees, so that none of them can
+/// be passed off as another.
+const LEAF_TAG: u32 = 1;
+const NODE_TAG: u32 = 2;
+const ROOT_TAG: u32 = 3;
+
+fn hash<const L: usize>(message: [Fp; L]) -> Fp {
+    Hash::<_, PoseidonSpec, ConstantLength<L>, POSEIDON_WIDTH, POSEIDON_RATE>::init().hash(message)
+}
+
+fn tagged_hash<const L: usize>(tag: u32, message: [Fp; L]) -> Fp {
+    Hash::<_, PoseidonSpec, ConstantLength<L>, POSEIDON_WIDTH, POSEIDON_RATE>::init_tagged(tag)
+        .hash(message)
+}
+
+/// The commitment standing for a badgeholder in the vote leaves: the Poseidon hash of its
+/// address, read as a big endian integer, and its leaf nonce if the hashes are salted (zero
+/// otherwise).
+pub fn badgeholder_commitment(address: &Address, nonce: Option<Felt>) -> Fp {
+    let mut limbs = [0u64; 4];
+    for (index, byte) in address.iter().rev().enumerate() {
+        limbs[index / 8] |= (*byte as u64) << (8 * (index % 8));
+    }
+    let nonce = nonce.map(Fp::from).unwrap_or(Fp::ZERO);
+    hash([Fp::from_raw(limbs), nonce])
+}
+
+/// The leaf of a vote: the Poseidon hash of the badgeholder commitment and the fixed point
+/// vote amount.
+pub fn vote_leaf(commitment: Fp, amount: i128) -> Fp {
+    tagged_hash(LEAF_TAG, [commitment, i128_to_felt(amount)])
+}
+
+/// The votes root of `leaves`: the Poseidon hash of the root of the binary Merkle tree over
+/// them, their number, which fixes the shape of the tree, and the project's votes root salt if
+/// the hashes are salted (zero otherwise). Zero if there are no leaves.
+///
+/// A node without a sibling, the last one of a level with an odd number of nodes, is promoted
+/// to the next level as is.
+pub fn votes_root(leaves: &[Fp], salt: Option<Felt>) -> Fp {
+    let mut level = leaves.to_vec();
+    if level.is_empty() {
+        return Fp::ZERO;
+    }
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    seal_root(level[0], leaves.len() as u32, salt)
+}
+
+fn seal_root(tree_root: Fp, leaf_count: u32, salt: Option<Felt>) -> Fp {
+    let salt = salt.map(Fp::from).unwrap_or(Fp::ZERO);
+    tagged_hash(ROOT_TAG, [tree_root, Fp::from(leaf_count as u64), salt])
+}
+
+fn next_level(level: &[Fp]) -> Vec<Fp> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => tagged_hash(NODE_TAG, [*left, *right]),
+            [node] => *node,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// The siblings of the leaf at `index` from the bottom of the tree up, skipping the levels it
+/// is promoted at.
+pub fn inclusion_path(leaves: &[Fp], index: usize) -> Vec<Fp> {
+    let mut siblings = vec![];
+    let mut level = leaves.to_vec();
+    let mut index = index;
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            siblings.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    siblings
+}
+
+/// Proof that a badgeholder's vote for a project is a leaf of the project's committed votes
+/// root.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub project_id: ProjectId,
+    pub badgeholder: Address,
+    /// The fixed point vote amount, scaled with the journal's amount scale.
+    pub amount: i128,
+    /// Position of the vote among the counted votes for the project, in ballot order.
+    pub leaf_index: u32,
+    /// Number of counted votes for the project, which fixes the shape of the tree and is bound
+    /// into its root.
+    pub leaf_count: u32,
+    pub siblings: Vec<Felt>,
+    /// The salt of the project's votes root (see [`crate::salt::votes_root_salt`]), if the
+    /// journal's hashes are salted.
+    pub salt: Option<Felt>,
+    /// The badgeholder's leaf nonce (see [`crate::salt::leaf_nonce`]), if the journal's hashes
+    /// are salted.
+    pub nonce: Option<Felt>,
+}
+
+impl InclusionProof {
+    /// The votes root of the tree the proof's vote is included in, or `None` if the path doesn't
+    /// match the shape of the tree.
+    pub fn root(&self) -> Option<Felt> {
+        let mut node = vote_leaf(
+            badgeholder_commitment(&self.badgeholder, self.nonce),
+            self.amount,
+        );
+        let mut siblings = self.siblings.iter();
+        let (mut index, mut level_len) = (self.leaf_index, self.leaf_count);
+        if index >= level_len {
+            return None;
+        }
+        while level_len > 1 {
+            if index ^ 1 < level_len {
+                let sibling = Fp::from(*siblings.next()?);
+                node = if index % 2 == 0 {
+                    tagged_hash(NODE_TAG, [node, sibling])
+                } else {
+                    tagged_hash(NODE_TAG, [sibling, node])
+                };
+            }
+            index /= 2;
+            level_len = level_len.div_ceil(2);
+        }
+        if siblings.next().is_some() {
+            return None;
+        }
+        Some(seal_root(node, self.leaf_count, self.salt).into())
+    }
+
+    /// Checks the proof against a committed votes root.
+    pub fn verify(&self, root: &Felt) -> bool {
+        self.root().as_ref() == Some(root)
+    }
+}
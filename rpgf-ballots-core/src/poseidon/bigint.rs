@@ -0,0 +1,219 @@
+//! The Poseidon permutation on the 256-bit modular multiplication accelerator of the R0 zkVM.
+//!
+//! `Fp` keeps its elements in Montgomery form, so each of its multiplications takes a few
+//! hundred RISC-V instructions in the guest. The accelerator multiplies canonical integers modulo
+//! the field's modulus in a handful of cycles instead, so the permutation converts the state to
+//! canonical 32-bit words once, runs its rounds on them, and converts it back.
+//!
+//! Off the zkVM, multiplications fall back to `Fp`, so the Poseidon tests can run this path on
+//! the host with `--features zkvm-bigint`.
+
+use super::{OptimizedConstants, State};
+use ff::{Field, PrimeField};
+use halo2curves::bn256::Fr as Fp;
+
+/// The number of 32-bit little endian words of a field element.
+const WORDS: usize = 8;
+
+/// A canonical field element, as little endian 32-bit words.
+type Words = [u32; WORDS];
+
+/// The optimized constants of a spec, converted to [`Words`] once per guest run.
+#[derive(Debug)]
+pub(crate) struct Constants<const T: usize> {
+    modulus: Words,
+    round_constants: Vec<[Words; T]>,
+    mds: [[Words; T]; T],
+    pre_sparse_round_constants: [Words; T],
+    pre_sparse_mds: [[Words; T]; T],
+    partial_round_constants: Vec<Words>,
+    sparse_mds: Vec<([Words; T], [Words; T])>,
+}
+
+impl<const T: usize> Constants<T> {
+    pub(crate) fn new(constants: &OptimizedConstants<Fp, T>) -> Self {
+        // the modulus is the successor of the largest field element
+        let mut modulus = to_words(&-Fp::ONE);
+        for word in modulus.iter_mut() {
+            let (sum, carry) = word.overflowing_add(1);
+            *word = sum;
+            if !carry {
+                break;
+            }
+        }
+        let matrix = |mds: &[[Fp; T]; T]| mds.map(|row| row.map(|entry| to_words(&entry)));
+        Constants {
+            modulus,
+            round_constants: constants
+                .round_constants
+                .iter()
+                .map(|rcs| rcs.map(|rc| to_words(&rc)))
+                .collect(),
+            mds: matrix(constants.mds),
+            pre_sparse_round_constants: constants
+                .pre_sparse_round_constants
+                .map(|rc| to_words(&rc)),
+            pre_sparse_mds: matrix(constants.pre_sparse_mds),
+            partial_round_constants: constants
+                .partial_round_constants
+                .iter()
+                .map(to_words)
+                .collect(),
+            sparse_mds: constants
+                .sparse_mds
+                .iter()
+                .map(|mds| {
+                    (
+                        mds.row.map(|entry| to_words(&entry)),
+                        mds.column.map(|entry| to_words(&entry)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// `x + y` modulo the modulus, for canonical `x` and `y`.
+    #[inline]
+    fn add(&self, x: &Words, y: &Words) -> Words {
+        // the modulus is below 2^255, so the sum of two canonical elements can't overflow
+        let mut sum = [0u32; WORDS];
+        let mut carry = 0u64;
+        for ((word, x), y) in sum.iter_mut().zip(x.iter()).zip(y.iter()) {
+            let wide = *x as u64 + *y as u64 + carry;
+            *word = wide as u32;
+            carry = wide >> 32;
+        }
+        if !less_than(&sum, &self.modulus) {
+            let mut borrow = 0i64;
+            for (word, m) in sum.iter_mut().zip(self.modulus.iter()) {
+                let wide = *word as i64 - *m as i64 - borrow;
+                *word = wide as u32;
+                borrow = (wide < 0) as i64;
+            }
+        }
+        sum
+    }
+
+    /// `x * y` modulo the modulus, for canonical `x` and `y`.
+    #[inline]
+    fn mul(&self, x: &Words, y: &Words) -> Words {
+        #[cfg(target_os = "zkvm")]
+        {
+            use risc0_zkvm_platform::syscall::{bigint, sys_bigint};
+
+            let mut product = [0u32; WORDS];
+            unsafe {
+                sys_bigint(&mut product, bigint::OP_MULTIPLY, x, y, &self.modulus);
+            }
+            // the accelerator only constrains `x * y = q * modulus + product`, the guest has to
+            // check the product is reduced
+            assert!(less_than(&product, &self.modulus));
+            product
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            to_words(&(from_words(x) * from_words(y)))
+        }
+    }
+
+    #[inline]
+    fn sbox(&self, x: &Words) -> Words {
+        let square = self.mul(x, x);
+        self.mul(&self.mul(&square, &square), x)
+    }
+
+    #[inline]
+    fn apply_mds(&self, state: &mut [Words; T], mds: &[[Words; T]; T]) {
+        let mut new_state = [[0u32; WORDS]; T];
+        for (new_word, row) in new_state.iter_mut().zip(mds.iter()) {
+            for (entry, word) in row.iter().zip(state.iter()) {
+                *new_word = self.add(new_word, &self.mul(entry, word));
+            }
+        }
+        *state = new_state;
+    }
+
+    #[inline]
+    fn apply_sparse_mds(&self, state: &mut [Words; T], (row, column): &([Words; T], [Words; T])) {
+        let first = state[0];
+        let mut new_first = [0u32; WORDS];
+        for (entry, word) in row.iter().zip(state.iter()) {
+            new_first = self.add(&new_first, &self.mul(entry, word));
+        }
+        for (word, entry) in state.iter_mut().zip(column.iter()).skip(1) {
+            *word = self.add(word, &self.mul(entry, &first));
+        }
+        state[0] = new_first;
+    }
+
+    #[inline]
+    fn full_round(&self, state: &mut [Words; T], rcs: &[Words; T]) {
+        for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+            *word = self.sbox(&self.add(word, rc));
+        }
+        self.apply_mds(state, &self.mds);
+    }
+}
+
+/// Runs the Poseidon permutation with `full_rounds` and `partial_rounds` on the given state,
+/// like [`super::permute_optimized`].
+pub(crate) fn permute<const T: usize>(
+    state: &mut State<Fp, T>,
+    full_rounds: usize,
+    partial_rounds: usize,
+    constants: &Constants<T>,
+) {
+    let r_f = full_rounds / 2;
+    let r_p = partial_rounds;
+
+    let mut words = state.map(|word| to_words(&word));
+    for rcs in &constants.round_constants[..r_f] {
+        constants.full_round(&mut words, rcs);
+    }
+    for (word, rc) in words
+        .iter_mut()
+        .zip(constants.pre_sparse_round_constants.iter())
+    {
+        *word = constants.add(word, rc);
+    }
+    constants.apply_mds(&mut words, &constants.pre_sparse_mds);
+    for (round, sparse_mds) in constants.sparse_mds.iter().enumerate() {
+        words[0] = constants.sbox(&words[0]);
+        if let Some(rc) = constants.partial_round_constants.get(round) {
+            words[0] = constants.add(&words[0], rc);
+        }
+        constants.apply_sparse_mds(&mut words, sparse_mds);
+    }
+    for rcs in &constants.round_constants[r_f + r_p..r_f + r_p + r_f] {
+        constants.full_round(&mut words, rcs);
+    }
+    *state = words.map(|word| from_words(&word));
+}
+
+fn to_words(x: &Fp) -> Words {
+    let repr = x.to_repr();
+    let mut words = [0u32; WORDS];
+    for (word, bytes) in words.iter_mut().zip(repr.as_ref().chunks(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+    words
+}
+
+fn from_words(words: &Words) -> Fp {
+    let mut repr = <Fp as PrimeField>::Repr::default();
+    for (bytes, word) in repr.as_mut().chunks_mut(4).zip(words.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    Fp::from_repr(repr).expect("the words of a canonical field element")
+}
+
+/// Whether `x < y`.
+#[inline]
+fn less_than(x: &Words, y: &Words) -> bool {
+    for (x, y) in x.iter().zip(y.iter()).rev() {
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
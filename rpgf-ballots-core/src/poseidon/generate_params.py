@@ -0,0 +1,281 @@
+#!/usr/bin/env python3
+"""Generates the round constants and MDS matrices of a Poseidon spec over the BN256 scalar field.
+
+Port of the Grain LFSR parameter generation of `generate_parameters_grain.sage`
+(https://github.com/daira/pasta-hadeshash) for a prime field and a x^5 sbox, which
+poseidon_params.rs was generated with. The MDS matrix is the first Cauchy matrix sampled by the
+LFSR, as for the existing width 2 spec; the Sage script's security checks are not rerun.
+
+    $ python3 generate_params.py <width> <full rounds> <partial rounds> > poseidon_params_w<width>.rs
+
+`python3 generate_params.py 2 8 56` reproduces the constants of poseidon_params.rs.
+
+It also generates the constants of the optimized permutation (ePrint 2019/458 appendix B),
+whose partial rounds add a round constant to the first element only and multiply the state by
+sparse matrices.
+"""
+
+import sys
+
+PRIME = 0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001
+FIELD_SIZE = 254
+
+
+def grain_bits(width, full_rounds, partial_rounds):
+    """The bits of the Grain LFSR seeded with the spec's parameters."""
+    state = (
+        "01"  # prime field
+        + "0000"  # non-negative sbox
+        + format(FIELD_SIZE, "012b")
+        + format(width, "012b")
+        + format(full_rounds, "010b")
+        + format(partial_rounds, "010b")
+        + "1" * 30
+    )
+    state = [int(bit) for bit in state]
+
+    def step():
+        bit = state[62] ^ state[51] ^ state[38] ^ state[23] ^ state[13] ^ state[0]
+        state.pop(0)
+        state.append(bit)
+        return bit
+
+    for _ in range(160):
+        step()
+    while True:
+        # self-shrinking: a pair of bits outputs its second bit if the first one is set
+        while step() == 0:
+            step()
+        yield step()
+
+
+def random_int(bits, num_bits=FIELD_SIZE):
+    value = 0
+    for _ in range(num_bits):
+        value = (value << 1) | next(bits)
+    return value
+
+
+def generate(width, full_rounds, partial_rounds):
+    bits = grain_bits(width, full_rounds, partial_rounds)
+
+    round_constants = []
+    for _ in range((full_rounds + partial_rounds) * width):
+        # rejection sampling
+        while True:
+            constant = random_int(bits)
+            if constant < PRIME:
+                break
+        round_constants.append(constant)
+
+    while True:
+        samples = [random_int(bits) % PRIME for _ in range(2 * width)]
+        while len(set(samples)) != len(samples):
+            samples = [random_int(bits) % PRIME for _ in range(2 * width)]
+        xs, ys = samples[:width], samples[width:]
+        if all((x + y) % PRIME != 0 for x in xs for y in ys):
+            break
+    mds = [[pow(x + y, PRIME - 2, PRIME) for y in ys] for x in xs]
+
+    return round_constants, mds, invert(mds)
+
+
+def invert(matrix):
+    """Inverts a matrix over the field by Gauss-Jordan elimination."""
+    n = len(matrix)
+    rows = [row[:] + [int(i == j) for j in range(n)] for i, row in enumerate(matrix)]
+    for column in range(n):
+        pivot = next(row for row in range(column, n) if rows[row][column] != 0)
+        rows[column], rows[pivot] = rows[pivot], rows[column]
+        inverse = pow(rows[column][column], PRIME - 2, PRIME)
+        rows[column] = [value * inverse % PRIME for value in rows[column]]
+        for row in range(n):
+            if row != column and rows[row][column] != 0:
+                factor = rows[row][column]
+                rows[row] = [
+                    (value - factor * pivot_value) % PRIME
+                    for value, pivot_value in zip(rows[row], rows[column])
+                ]
+    return [row[n:] for row in rows]
+
+
+def mat_vec(matrix, vector):
+    return [sum(m * v for m, v in zip(row, vector)) % PRIME for row in matrix]
+
+
+def mat_mul(a, b):
+    return [[sum(x * y for x, y in zip(row, column)) % PRIME for column in zip(*b)] for row in a]
+
+
+def optimize(width, full_rounds, partial_rounds, round_constants, mds, mds_inv):
+    """The constants of the optimized permutation.
+
+    Returns the constants added ahead of the partial rounds, the constants added to the first
+    element after the S-box of every partial round but the last, the matrix applied ahead of the
+    partial rounds and the (first row, first column) of the sparse matrix of each partial round.
+    """
+    r_f = full_rounds // 2
+    constants = [
+        round_constants[(r_f + i) * width : (r_f + i + 1) * width] for i in range(partial_rounds)
+    ]
+
+    # the constants of partial round i + 1 are moved ahead of the matrix of round i: the first
+    # element of M^-1 c is added after the S-box of round i, the others ahead of it
+    partial_round_constants = [0] * (partial_rounds - 1)
+    for i in range(partial_rounds - 2, -1, -1):
+        moved = mat_vec(mds_inv, constants[i + 1])
+        partial_round_constants[i] = moved[0]
+        constants[i] = [constants[i][0]] + [
+            (c + m) % PRIME for c, m in zip(constants[i][1:], moved[1:])
+        ]
+
+    # the matrix A of each partial round is split into a sparse matrix S times a matrix
+    # A' = diag(1, A^), which is moved ahead of the S-box: the previous round's matrix is A' M
+    sparse = []
+    hat = [[int(i == j) for j in range(width - 1)] for i in range(width - 1)]
+    hat_inv = hat
+    mds_hat_inv = invert([row[1:] for row in mds[1:]])
+    for _ in range(partial_rounds):
+        # A = A' M, so A^ = A'^ M^ and the first column of A below the diagonal is A'^ times M's
+        column = [mds[0][0]] + mat_vec(hat, [row[0] for row in mds[1:]])
+        hat = mat_mul(hat, [row[1:] for row in mds[1:]])
+        hat_inv = mat_mul(mds_hat_inv, hat_inv)
+        # the first row of A is M's, times A^^-1 in S
+        row = [mds[0][0]] + [
+            sum(m * h for m, h in zip(mds[0][1:], column_inv)) % PRIME
+            for column_inv in zip(*hat_inv)
+        ]
+        sparse.append((row, column))
+    sparse.reverse()
+    pre_sparse_mds = [[int(i == j) for j in range(width)] for i in range(width)]
+    for i in range(1, width):
+        pre_sparse_mds[i][1:] = hat[i - 1]
+
+    return constants[0], partial_round_constants, pre_sparse_mds, sparse
+
+
+def add_round_constants(state, round_constants):
+    return [(s + c) % PRIME for s, c in zip(state, round_constants)]
+
+
+def permute(width, full_rounds, partial_rounds, round_constants, mds, state):
+    """The reference permutation, to check the optimized one against."""
+    r_f = full_rounds // 2
+    for r in range(full_rounds + partial_rounds):
+        state = add_round_constants(state, round_constants[r * width : (r + 1) * width])
+        if r < r_f or r >= r_f + partial_rounds:
+            state = [pow(s, 5, PRIME) for s in state]
+        else:
+            state[0] = pow(state[0], 5, PRIME)
+        state = mat_vec(mds, state)
+    return state
+
+
+def permute_optimized(width, full_rounds, partial_rounds, round_constants, mds, optimized, state):
+    pre_sparse_round_constants, partial_round_constants, pre_sparse_mds, sparse = optimized
+    r_f = full_rounds // 2
+    for r in range(r_f):
+        state = add_round_constants(state, round_constants[r * width : (r + 1) * width])
+        state = mat_vec(mds, [pow(s, 5, PRIME) for s in state])
+    state = mat_vec(pre_sparse_mds, add_round_constants(state, pre_sparse_round_constants))
+    for i, (row, column) in enumerate(sparse):
+        state[0] = pow(state[0], 5, PRIME)
+        if i < partial_rounds - 1:
+            state[0] = (state[0] + partial_round_constants[i]) % PRIME
+        first = sum(r * s for r, s in zip(row, state)) % PRIME
+        state = [first] + [(s + c * state[0]) % PRIME for s, c in zip(state[1:], column[1:])]
+    for r in range(r_f + partial_rounds, full_rounds + partial_rounds):
+        state = add_round_constants(state, round_constants[r * width : (r + 1) * width])
+        state = mat_vec(mds, [pow(s, 5, PRIME) for s in state])
+    return state
+
+
+def felt(value, indent):
+    limbs = [(value >> (64 * i)) & (2**64 - 1) for i in range(4)]
+    pad = " " * indent
+    lines = [pad + "Fp::from_raw(["]
+    for limb in limbs:
+        digits = format(limb, "016x")
+        lines.append(pad + "    0x" + "_".join(digits[i : i + 4] for i in range(0, 16, 4)) + ",")
+    lines.append(pad + "]),")
+    return "\n".join(lines)
+
+
+def values(name, values):
+    lines = ["pub(crate) const {}: [Fp; {}] = [".format(name, len(values))]
+    lines.extend(felt(value, 4) for value in values)
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def sparse_matrices(name, sparse, width):
+    lines = ["pub(crate) const {}: [SparseMds<Fp, {}>; {}] = [".format(name, width, len(sparse))]
+    for row, column in sparse:
+        lines.append("    SparseMds {")
+        for field, entries in (("row", row), ("column", column)):
+            lines.append("        {}: [".format(field))
+            lines.extend(felt(value, 12) for value in entries)
+            lines.append("        ],")
+        lines.append("    },")
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def rows(name, values, width):
+    lines = ["pub(crate) const {}: [[Fp; {}]; {}] = [".format(name, width, len(values))]
+    for row in values:
+        lines.append("    [")
+        lines.extend(felt(value, 8) for value in row)
+        lines.append("    ],")
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def main():
+    width, full_rounds, partial_rounds = (int(arg) for arg in sys.argv[1:4])
+    round_constants, mds, mds_inv = generate(width, full_rounds, partial_rounds)
+    print("//! This file was generated by running generate_params.py")
+    print(
+        "//! Parameters for using width {} (rate {}) Poseidon with the BN256 field,".format(
+            width, width - 1
+        )
+    )
+    print(
+        "//! with {} full rounds and {} partial rounds:".format(full_rounds, partial_rounds)
+    )
+    print("//!")
+    print("//! ```text")
+    print(
+        "//! $ python3 generate_params.py {} {} {}".format(width, full_rounds, partial_rounds)
+    )
+    print("//! ```")
+    print("use super::SparseMds;")
+    print("use halo2curves::bn256::Fr as Fp;")
+    print(
+        rows(
+            "ROUND_CONSTANTS",
+            [round_constants[i : i + width] for i in range(0, len(round_constants), width)],
+            width,
+        )
+    )
+    print(rows("MDS", mds, width))
+    print(rows("MDS_INV", mds_inv, width))
+    print_optimized(width, full_rounds, partial_rounds, round_constants, mds, mds_inv)
+
+
+def print_optimized(width, full_rounds, partial_rounds, round_constants, mds, mds_inv):
+    """Prints the constants of the optimized permutation, once checked against the reference."""
+    spec = (width, full_rounds, partial_rounds, round_constants, mds)
+    optimized = optimize(*spec, mds_inv)
+    state = list(range(width))
+    assert permute(*spec, state) == permute_optimized(*spec, optimized, state)
+    pre_sparse_round_constants, partial_round_constants, pre_sparse_mds, sparse = optimized
+    print("// Constants of the optimized permutation:")
+    print(values("PRE_SPARSE_ROUND_CONSTANTS", pre_sparse_round_constants))
+    print(values("PARTIAL_ROUND_CONSTANTS", partial_round_constants))
+    print(rows("PRE_SPARSE_MDS", pre_sparse_mds, width))
+    print(sparse_matrices("SPARSE_MDS", sparse, width))
+
+
+if __name__ == "__main__":
+    main()
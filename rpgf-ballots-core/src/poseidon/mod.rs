@@ -0,0 +1,721 @@
+use crate::config::{
+    PoseidonConfig, PoseidonConfigError, PoseidonSpecId, TreeHashMode, DEFAULT_POSEIDON_CHUNK_LEN,
+};
+use crate::felt::Felt;
+use crate::journal::PoseidonParams;
+use ff::Field;
+use ff::PrimeField;
+use halo2curves::bn256::Fr as Fp;
+use std::convert::TryInto;
+use std::fmt;
+use std::iter;
+use std::marker::PhantomData;
+#[cfg(feature = "zkvm-bigint")]
+use std::sync::OnceLock;
+
+// Fork of the Poseidon hash function from EZKL's fork of the Halo2 library,
+// (https://github.com/zkonduit/halo2/blob/main/halo2_gadgets/src/poseidon/primitives.rs)
+// optimized for the R0 zkVM, shared by the guests and the host
+
+#[cfg(feature = "zkvm-bigint")]
+mod bigint;
+mod poseidon_params;
+mod poseidon_params_w3;
+
+/// The type used to hold permutation state.
+pub(crate) type State<F, const T: usize> = [F; T];
+
+/// The type used to hold sponge rate.
+pub(crate) type SpongeRate<F, const RATE: usize> = [Option<F>; RATE];
+
+#[inline]
+pub fn i128_to_felt<F: PrimeField>(x: i128) -> F {
+    if x >= 0 {
+        F::from_u128(x as u128)
+    } else {
+        -F::from_u128((-x) as u128)
+    }
+}
+
+/// The specification for the Poseidon hash function, of width [`POSEIDON_WIDTH`].
+#[derive(Debug, Clone, Copy)]
+pub struct PoseidonSpec;
+
+/// Basically the number of columns allocated within the poseidon chip
+pub const POSEIDON_WIDTH: usize = 2;
+/// The number of full SBox rounds
+pub const POSEIDON_RATE: usize = 1;
+
+pub(crate) type Mds<Fp, const T: usize> = [[Fp; T]; T];
+
+impl Spec<Fp, POSEIDON_WIDTH, POSEIDON_RATE> for PoseidonSpec {
+    fn full_rounds() -> usize {
+        8
+    }
+
+    fn partial_rounds() -> usize {
+        56
+    }
+
+    fn sbox(val: Fp) -> Fp {
+        let square = val.square(); // x^2
+        square.square().mul(&val) // (x^2)^2 * x = x^5
+    }
+
+    // generate_params.py takes the first Cauchy matrix the LFSR samples
+    fn secure_mds() -> usize {
+        0
+    }
+
+    fn constants() -> (
+        Vec<[Fp; POSEIDON_WIDTH]>,
+        Mds<Fp, POSEIDON_WIDTH>,
+        Mds<Fp, POSEIDON_WIDTH>,
+    ) {
+        (
+            poseidon_params::ROUND_CONSTANTS[..].to_vec(),
+            poseidon_params::MDS,
+            poseidon_params::MDS_INV,
+        )
+    }
+
+    fn optimized_constants() -> OptimizedConstants<Fp, POSEIDON_WIDTH> {
+        OptimizedConstants {
+            round_constants: &poseidon_params::ROUND_CONSTANTS,
+            mds: &poseidon_params::MDS,
+            pre_sparse_round_constants: &poseidon_params::PRE_SPARSE_ROUND_CONSTANTS,
+            pre_sparse_mds: &poseidon_params::PRE_SPARSE_MDS,
+            partial_round_constants: &poseidon_params::PARTIAL_ROUND_CONSTANTS,
+            sparse_mds: &poseidon_params::SPARSE_MDS,
+        }
+    }
+
+    #[cfg(feature = "zkvm-bigint")]
+    fn permute(state: &mut State<Fp, POSEIDON_WIDTH>) {
+        static CONSTANTS: OnceLock<bigint::Constants<POSEIDON_WIDTH>> = OnceLock::new();
+        let constants =
+            CONSTANTS.get_or_init(|| bigint::Constants::new(&Self::optimized_constants()));
+        bigint::permute(
+            state,
+            Self::full_rounds(),
+            Self::partial_rounds(),
+            constants,
+        );
+    }
+}
+
+/// The width 3, rate 2 specification, which absorbs twice as many elements per permutation.
+#[derive(Debug, Clone, Copy)]
+pub struct PoseidonSpecW3;
+
+pub const POSEIDON_W3_WIDTH: usize = 3;
+pub const POSEIDON_W3_RATE: usize = 2;
+
+impl Spec<Fp, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE> for PoseidonSpecW3 {
+    fn full_rounds() -> usize {
+        8
+    }
+
+    fn partial_rounds() -> usize {
+        57
+    }
+
+    fn sbox(val: Fp) -> Fp {
+        PoseidonSpec::sbox(val)
+    }
+
+    // generate_params.py takes the first Cauchy matrix the LFSR samples
+    fn secure_mds() -> usize {
+        0
+    }
+
+    fn constants() -> (
+        Vec<[Fp; POSEIDON_W3_WIDTH]>,
+        Mds<Fp, POSEIDON_W3_WIDTH>,
+        Mds<Fp, POSEIDON_W3_WIDTH>,
+    ) {
+        (
+            poseidon_params_w3::ROUND_CONSTANTS[..].to_vec(),
+            poseidon_params_w3::MDS,
+            poseidon_params_w3::MDS_INV,
+        )
+    }
+
+    fn optimized_constants() -> OptimizedConstants<Fp, POSEIDON_W3_WIDTH> {
+        OptimizedConstants {
+            round_constants: &poseidon_params_w3::ROUND_CONSTANTS,
+            mds: &poseidon_params_w3::MDS,
+            pre_sparse_round_constants: &poseidon_params_w3::PRE_SPARSE_ROUND_CONSTANTS,
+            pre_sparse_mds: &poseidon_params_w3::PRE_SPARSE_MDS,
+            partial_round_constants: &poseidon_params_w3::PARTIAL_ROUND_CONSTANTS,
+            sparse_mds: &poseidon_params_w3::SPARSE_MDS,
+        }
+    }
+
+    #[cfg(feature = "zkvm-bigint")]
+    fn permute(state: &mut State<Fp, POSEIDON_W3_WIDTH>) {
+        static CONSTANTS: OnceLock<bigint::Constants<POSEIDON_W3_WIDTH>> = OnceLock::new();
+        let constants =
+            CONSTANTS.get_or_init(|| bigint::Constants::new(&Self::optimized_constants()));
+        bigint::permute(
+            state,
+            Self::full_rounds(),
+            Self::partial_rounds(),
+            constants,
+        );
+    }
+}
+
+/// A specification for a Poseidon permutation.
+pub trait Spec<F: Field, const T: usize, const RATE: usize>: fmt::Debug {
+    /// The number of full rounds for this specification.
+    ///
+    /// This must be an even number.
+    fn full_rounds() -> usize;
+
+    /// The number of partial rounds for this specification.
+    fn partial_rounds() -> usize;
+
+    /// The S-box for this specification.
+    fn sbox(val: F) -> F;
+
+    /// Side-loaded index of the first correct and secure MDS that will be generated by
+    /// the reference implementation.
+    ///
+    /// This is used by the default implementation of [`Spec::constants`]. If you are
+    /// hard-coding the constants, you may leave this unimplemented.
+    fn secure_mds() -> usize;
+
+    /// Generates `(round_constants, mds, mds^-1)` corresponding to this specification.
+    fn constants() -> (Vec<[F; T]>, Mds<F, T>, Mds<F, T>);
+
+    /// The constants of the optimized permutation of this specification, generated with its
+    /// other constants by `generate_params.py`.
+    fn optimized_constants() -> OptimizedConstants<F, T>;
+
+    /// Runs the permutation of this specification on the given state, with its optimized
+    /// constants.
+    ///
+    /// With the `zkvm-bigint` feature, the BN256 specs run it on the zkVM's bigint accelerator.
+    fn permute(state: &mut State<F, T>)
+    where
+        Self: Sized,
+    {
+        permute_optimized::<F, Self, T, RATE>(state, &Self::optimized_constants())
+    }
+}
+
+/// The sparse matrix of a partial round of the optimized permutation: the identity, but for its
+/// first row and column.
+#[derive(Debug, Clone, Copy)]
+pub struct SparseMds<F, const T: usize> {
+    pub(crate) row: [F; T],
+    /// The first column, whose first element is the first of `row`.
+    pub(crate) column: [F; T],
+}
+
+/// The constants of the optimized permutation (ePrint 2019/458 appendix B).
+///
+/// The round constants of every partial round but the first are moved ahead of the previous
+/// round's matrix, so only their first element is left to add after the S-box. The matrix of each
+/// partial round is split into a sparse matrix and a matrix leaving the first element alone,
+/// which is moved ahead of the S-box and merged into the previous round's, down to a single
+/// matrix ahead of the partial rounds.
+#[derive(Debug, Clone, Copy)]
+pub struct OptimizedConstants<F: 'static, const T: usize> {
+    /// The round constants of the full rounds, at the indices of [`permute`]'s.
+    pub(crate) round_constants: &'static [[F; T]],
+    /// The MDS matrix of the full rounds.
+    pub(crate) mds: &'static Mds<F, T>,
+    /// The round constants of the first partial round, with the moved ones of the others.
+    pub(crate) pre_sparse_round_constants: &'static [F; T],
+    /// The matrix applied after them, ahead of the partial rounds.
+    pub(crate) pre_sparse_mds: &'static Mds<F, T>,
+    /// The constants added after the S-box of every partial round but the last.
+    pub(crate) partial_round_constants: &'static [F],
+    /// The sparse matrices of the partial rounds.
+    pub(crate) sparse_mds: &'static [SparseMds<F, T>],
+}
+
+#[inline]
+fn apply_mds<F: Field, const T: usize>(state: &mut State<F, T>, mds: &Mds<F, T>) {
+    let mut new_state = [F::ZERO; T];
+    for i in 0..T {
+        for j in 0..T {
+            new_state[i] += mds[i][j] * state[j];
+        }
+    }
+    *state = new_state;
+}
+
+#[inline]
+fn apply_sparse_mds<F: Field, const T: usize>(state: &mut State<F, T>, mds: &SparseMds<F, T>) {
+    let first = state[0];
+    let mut new_first = F::ZERO;
+    for (entry, word) in mds.row.iter().zip(state.iter()) {
+        new_first += *entry * word;
+    }
+    for (word, entry) in state.iter_mut().zip(mds.column.iter()).skip(1) {
+        *word += *entry * first;
+    }
+    state[0] = new_first;
+}
+
+/// Runs the Poseidon permutation on the given state, with the dense MDS matrix and round
+/// constants of every round.
+///
+/// This is the reference [`Spec::permute`] is checked against.
+#[inline]
+pub fn permute<F: Field, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>(
+    state: &mut State<F, T>,
+    mds: &Mds<F, T>,
+    round_constants: &[[F; T]],
+) {
+    let r_f = S::full_rounds() / 2;
+    let r_p = S::partial_rounds();
+
+    for (round, rcs) in round_constants.iter().enumerate().take(r_f + r_p + r_f) {
+        if round < r_f || round >= r_f + r_p {
+            // Full round
+            for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+                *word = S::sbox(*word + rc);
+            }
+        } else {
+            // Partial round
+            for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+                *word += rc;
+            }
+            state[0] = S::sbox(state[0]);
+        }
+        apply_mds(state, mds);
+    }
+}
+
+/// Runs the Poseidon permutation on the given state, with the sparse partial rounds of the
+/// `constants`.
+#[inline]
+pub fn permute_optimized<F: Field, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>(
+    state: &mut State<F, T>,
+    constants: &OptimizedConstants<F, T>,
+) {
+    let r_f = S::full_rounds() / 2;
+    let r_p = S::partial_rounds();
+
+    let full_round = |state: &mut State<F, T>, rcs: &[F; T]| {
+        for (word, rc) in state.iter_mut().zip(rcs.iter()) {
+            *word = S::sbox(*word + rc);
+        }
+        apply_mds(state, constants.mds);
+    };
+
+    for rcs in &constants.round_constants[..r_f] {
+        full_round(state, rcs);
+    }
+    for (word, rc) in state
+        .iter_mut()
+        .zip(constants.pre_sparse_round_constants.iter())
+    {
+        *word += rc;
+    }
+    apply_mds(state, constants.pre_sparse_mds);
+    for (round, sparse_mds) in constants.sparse_mds.iter().enumerate() {
+        state[0] = S::sbox(state[0]);
+        if let Some(rc) = constants.partial_round_constants.get(round) {
+            state[0] += rc;
+        }
+        apply_sparse_mds(state, sparse_mds);
+    }
+    for rcs in &constants.round_constants[r_f + r_p..r_f + r_p + r_f] {
+        full_round(state, rcs);
+    }
+}
+
+#[inline]
+fn poseidon_sponge<F: Field, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>(
+    state: &mut State<F, T>,
+    input: Option<&Absorbing<F, RATE>>,
+) -> Squeezing<F, RATE> {
+    if let Some(Absorbing(input)) = input {
+        // `Iterator::zip` short-circuits when one iterator completes, so this will only
+        // mutate the rate portion of the state.
+        for (word, value) in state.iter_mut().zip(input.iter()) {
+            *word += value.expect("poseidon_sponge is called with a padded input");
+        }
+    }
+
+    S::permute(state);
+
+    let mut output = [None; RATE];
+    for (word, value) in output.iter_mut().zip(state.iter()) {
+        *word = Some(*value);
+    }
+    Squeezing(output)
+}
+
+mod private {
+    pub trait SealedSpongeMode {}
+    impl<F, const RATE: usize> SealedSpongeMode for super::Absorbing<F, RATE> {}
+    impl<F, const RATE: usize> SealedSpongeMode for super::Squeezing<F, RATE> {}
+}
+
+/// The state of the `Sponge`.
+pub trait SpongeMode: private::SealedSpongeMode {}
+
+/// The absorbing state of the `Sponge`.
+#[derive(Debug, Clone)]
+pub struct Absorbing<F, const RATE: usize>(pub(crate) SpongeRate<F, RATE>);
+
+/// The squeezing state of the `Sponge`.
+#[derive(Debug)]
+pub struct Squeezing<F, const RATE: usize>(pub(crate) SpongeRate<F, RATE>);
+
+impl<F, const RATE: usize> SpongeMode for Absorbing<F, RATE> {}
+impl<F, const RATE: usize> SpongeMode for Squeezing<F, RATE> {}
+
+impl<F: fmt::Debug, const RATE: usize> Absorbing<F, RATE> {
+    pub(crate) fn init_with(val: F) -> Self {
+        Self(
+            iter::once(Some(val))
+                .chain((1..RATE).map(|_| None))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        )
+    }
+}
+
+#[derive(Clone)]
+/// A Poseidon sponge.
+pub(crate) struct Sponge<
+    F: Field,
+    S: Spec<F, T, RATE>,
+    M: SpongeMode,
+    const T: usize,
+    const RATE: usize,
+> {
+    mode: M,
+    state: State<F, T>,
+    _marker: PhantomData<S>,
+}
+
+impl<F: Field, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>
+    Sponge<F, S, Absorbing<F, RATE>, T, RATE>
+{
+    /// Constructs a new sponge for the given Poseidon specification.
+    pub(crate) fn new(initial_capacity_element: F) -> Self {
+        let mode = Absorbing([None; RATE]);
+        let mut state = [F::ZERO; T];
+        state[RATE] = initial_capacity_element;
+
+        Sponge {
+            mode,
+            state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Absorbs an element into the sponge.
+    pub(crate) fn absorb(&mut self, value: F) {
+        for entry in self.mode.0.iter_mut() {
+            if entry.is_none() {
+                *entry = Some(value);
+                return;
+            }
+        }
+
+        // We've already absorbed as many elements as we can
+        let _ = poseidon_sponge::<F, S, T, RATE>(&mut self.state, Some(&self.mode));
+        self.mode = Absorbing::init_with(value);
+    }
+
+    /// Transitions the sponge into its squeezing state.
+    pub(crate) fn finish_absorbing(mut self) -> Sponge<F, S, Squeezing<F, RATE>, T, RATE> {
+        let mode = poseidon_sponge::<F, S, T, RATE>(&mut self.state, Some(&self.mode));
+
+        Sponge {
+            mode,
+            state: self.state,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>
+    Sponge<F, S, Squeezing<F, RATE>, T, RATE>
+{
+    /// Squeezes an element from the sponge.
+    pub(crate) fn squeeze(&mut self) -> F {
+        loop {
+            for entry in self.mode.0.iter_mut() {
+                if let Some(e) = entry.take() {
+                    return e;
+                }
+            }
+
+            // We've already squeezed out all available elements
+            self.mode = poseidon_sponge::<F, S, T, RATE>(&mut self.state, None);
+        }
+    }
+}
+
+/// A domain in which a Poseidon hash function is being used.
+pub trait Domain<F: Field, const RATE: usize> {
+    /// Iterator that outputs padding field elements.
+    type Padding: IntoIterator<Item = F>;
+
+    /// The name of this domain, for debug formatting purposes.
+    fn name() -> String;
+
+    /// The initial capacity element, encoding this domain.
+    fn initial_capacity_element() -> F;
+
+    /// Returns the padding to be appended to the input.
+    fn padding() -> Self::Padding;
+}
+
+/// A Poseidon hash function used with constant input length.
+///
+/// Domain specified in [ePrint 2019/458 section 4.2](https://eprint.iacr.org/2019/458.pdf).
+#[derive(Clone, Copy, Debug)]
+pub struct ConstantLength<const L: usize>;
+
+impl<F: PrimeField, const RATE: usize, const L: usize> Domain<F, RATE> for ConstantLength<L> {
+    type Padding = Vec<F>; // Change to Vec for dynamic sizing
+
+    fn name() -> String {
+        format!("ConstantLength<{}>", L)
+    }
+
+    fn initial_capacity_element() -> F {
+        F::from_u128((L as u128) << 64)
+    }
+
+    fn padding() -> Self::Padding {
+        let k = L.div_ceil(RATE);
+        vec![F::ZERO; k * RATE - L]
+    }
+}
+
+#[derive(Clone)]
+/// A Poseidon hash function, built around a sponge.
+pub struct Hash<
+    F: Field,
+    S: Spec<F, T, RATE>,
+    D: Domain<F, RATE>,
+    const T: usize,
+    const RATE: usize,
+> {
+    sponge: Sponge<F, S, Absorbing<F, RATE>, T, RATE>,
+    _domain: PhantomData<D>,
+}
+
+impl<F: Field, S: Spec<F, T, RATE>, D: Domain<F, RATE>, const T: usize, const RATE: usize>
+    fmt::Debug for Hash<F, S, D, T, RATE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hash")
+            .field("width", &T)
+            .field("rate", &RATE)
+            .field("R_F", &S::full_rounds())
+            .field("R_P", &S::partial_rounds())
+            .field("domain", &D::name())
+            .finish()
+    }
+}
+
+impl<F: Field, S: Spec<F, T, RATE>, D: Domain<F, RATE>, const T: usize, const RATE: usize>
+    Hash<F, S, D, T, RATE>
+{
+    /// Initializes a new hasher.
+    pub fn init() -> Self {
+        Hash {
+            sponge: Sponge::new(D::initial_capacity_element()),
+            _domain: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField, S: Spec<F, T, RATE>, const T: usize, const RATE: usize, const L: usize>
+    Hash<F, S, ConstantLength<L>, T, RATE>
+{
+    /// Initializes a hasher of a chunk of the [`length_binding_tree_hash`] at `level` of the
+    /// tree, `len` elements of which aren't padding.
+    ///
+    /// Both are encoded in the low 64 bits of the capacity element, which are zero in the
+    /// [`ConstantLength`] domain.
+    pub fn init_tree_node(level: u32, len: usize) -> Self {
+        let capacity = ((L as u128) << 64) | ((level as u128) << 32) | len as u128;
+        Hash {
+            sponge: Sponge::new(F::from_u128(capacity)),
+            _domain: PhantomData,
+        }
+    }
+
+    /// Initializes a hasher in the [`ConstantLength`] domain separated by `tag`, encoded above
+    /// the message length in the capacity element, clear of the bits [`Self::init_tree_node`]
+    /// encodes the level and length of a chunk in.
+    pub fn init_tagged(tag: u32) -> Self {
+        let capacity = ((tag as u128) << 96) | ((L as u128) << 64);
+        Hash {
+            sponge: Sponge::new(F::from_u128(capacity)),
+            _domain: PhantomData,
+        }
+    }
+
+    pub fn hash(mut self, message: [F; L]) -> F {
+        let padding = <ConstantLength<L> as Domain<F, RATE>>::padding();
+        for value in message.iter().chain(padding.iter()) {
+            self.sponge.absorb(*value);
+        }
+        self.sponge.finish_absorbing().squeeze()
+    }
+}
+
+#[inline]
+pub fn poseidon<const L: usize, S: Spec<Fp, T, RATE>, const T: usize, const RATE: usize>(
+    message: Vec<Fp>,
+) -> Result<Vec<Vec<Fp>>, Box<dyn std::error::Error>> {
+    let mut hash_inputs = message;
+
+    let mut one_iter = false;
+    // do the Tree dance baby
+    while hash_inputs.len() > 1 || !one_iter {
+        let hashes: Vec<Fp> = hash_inputs
+            .chunks(L)
+            .map(|block| {
+                let mut block = block.to_vec();
+                let remainder = block.len() % L;
+
+                if remainder != 0 {
+                    block.extend(vec![Fp::ZERO; L - remainder].iter());
+                }
+
+                let message = block.try_into().unwrap();
+                Hash::<_, S, ConstantLength<L>, T, RATE>::init().hash(message)
+            })
+            .collect();
+        one_iter = true;
+        hash_inputs = hashes;
+    }
+
+    Ok(vec![hash_inputs])
+}
+
+/// The tree hash of `message` over zero padded chunks of `L` field elements, each hashed with the
+/// level of the tree and the number of its elements, so that messages of different lengths hash
+/// differently even when they only differ by trailing zeros.
+///
+/// An empty message hashes as a single chunk without elements.
+pub fn length_binding_tree_hash<
+    const L: usize,
+    S: Spec<Fp, T, RATE>,
+    const T: usize,
+    const RATE: usize,
+>(
+    message: &[Fp],
+) -> Fp {
+    let chunk_hash = |level: u32, chunk: &[Fp]| {
+        let mut block = [Fp::ZERO; L];
+        block[..chunk.len()].copy_from_slice(chunk);
+        Hash::<_, S, ConstantLength<L>, T, RATE>::init_tree_node(level, chunk.len()).hash(block)
+    };
+    if message.is_empty() {
+        return chunk_hash(0, &[]);
+    }
+
+    let mut level = 0;
+    let mut hashes: Vec<Fp> = message
+        .chunks(L)
+        .map(|chunk| chunk_hash(level, chunk))
+        .collect();
+    while hashes.len() > 1 {
+        level += 1;
+        hashes = hashes
+            .chunks(L)
+            .map(|chunk| chunk_hash(level, chunk))
+            .collect();
+    }
+    hashes[0]
+}
+
+/// Number of field elements hashed together at each level of the vote amounts tree hash, unless
+/// configured otherwise.
+pub const POSEIDON_LEN_GRAPH: usize = DEFAULT_POSEIDON_CHUNK_LEN as usize;
+
+/// The hash of a project's vote amounts committed by the guests, zero if there are none.
+///
+/// The fixed point amounts are hashed as field elements, in ballot order, with the
+/// [`length_binding_tree_hash`] of the configured spec over chunks of `config.chunk_len`
+/// elements, or the [`poseidon`] tree hash in [`TreeHashMode::Legacy`] mode, so hosts and
+/// verifiers recompute exactly the committed hashes. A `salt` is hashed ahead of the amounts.
+/// Fails if the chunk length isn't supported.
+pub fn hash_vote_amounts(
+    amounts: &[i128],
+    salt: Option<Felt>,
+    config: &PoseidonConfig,
+) -> Result<Felt, PoseidonConfigError> {
+    config.check()?;
+    if amounts.is_empty() {
+        return Ok(Felt([0; 4]));
+    }
+    let felts = salt
+        .map(Fp::from)
+        .into_iter()
+        .chain(amounts.iter().map(|amount| i128_to_felt(*amount)))
+        .collect();
+    // the chunk length is a const generic, so each supported one is its own instance
+    let hash = match config.chunk_len {
+        8 => tree_hash::<8>(config, felts),
+        16 => tree_hash::<16>(config, felts),
+        32 => tree_hash::<32>(config, felts),
+        64 => tree_hash::<64>(config, felts),
+        chunk_len => return Err(PoseidonConfigError::UnsupportedChunkLen(chunk_len)),
+    };
+    Ok(hash.into())
+}
+
+fn tree_hash<const L: usize>(config: &PoseidonConfig, felts: Vec<Fp>) -> Fp {
+    match (config.spec, config.tree_hash) {
+        (PoseidonSpecId::W2R1, TreeHashMode::LengthBinding) => {
+            length_binding_tree_hash::<L, PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>(&felts)
+        }
+        (PoseidonSpecId::W3R2, TreeHashMode::LengthBinding) => {
+            length_binding_tree_hash::<L, PoseidonSpecW3, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>(
+                &felts,
+            )
+        }
+        (PoseidonSpecId::W2R1, TreeHashMode::Legacy) => {
+            poseidon::<L, PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>(felts).unwrap()[0][0]
+        }
+        (PoseidonSpecId::W3R2, TreeHashMode::Legacy) => {
+            poseidon::<L, PoseidonSpecW3, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>(felts).unwrap()[0][0]
+        }
+    }
+}
+
+/// The parameters of [`hash_vote_amounts`] with `config`, committed to the journal header.
+pub fn vote_amounts_params(config: &PoseidonConfig) -> PoseidonParams {
+    let (width, rate, full_rounds, partial_rounds) = match config.spec {
+        PoseidonSpecId::W2R1 => (
+            POSEIDON_WIDTH,
+            POSEIDON_RATE,
+            PoseidonSpec::full_rounds(),
+            PoseidonSpec::partial_rounds(),
+        ),
+        PoseidonSpecId::W3R2 => (
+            POSEIDON_W3_WIDTH,
+            POSEIDON_W3_RATE,
+            PoseidonSpecW3::full_rounds(),
+            PoseidonSpecW3::partial_rounds(),
+        ),
+    };
+    PoseidonParams {
+        spec: config.spec,
+        width: width as u32,
+        rate: rate as u32,
+        full_rounds: full_rounds as u32,
+        partial_rounds: partial_rounds as u32,
+        chunk_len: config.chunk_len,
+        tree_hash: config.tree_hash,
+    }
+}
@@ -0,0 +1,3044 @@
+//! This file was generated by running generate_params.py
+//! Number of round constants: 340
+//! Round constants for GF(p):
+//! Parameters for using rate 4 Poseidon with the BN256 field.
+//! The parameters can be reproduced by running the following Sage script from
+//! [this repository](https://github.com/daira/pasta-hadeshash):
+//!
+//! ```text
+//! $ sage generate_parameters_grain.sage 1 0 254 5 8 60 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001 --rust
+//! ```
+//!
+//! where 1 means "prime field", 0 means "non-negative sbox", 254 is the bitsize
+//! of the field, 5 is the Poseidon width (rate + 1), 8 is the number of full
+//! rounds, 60 is the number of partial rounds.
+//! More info here => <https://hackmd.io/@letargicus/SJOvx48Nn>
+use super::SparseMds;
+use halo2curves::bn256::Fr as Fp;
+pub(crate) const ROUND_CONSTANTS: [[Fp; 2]; 64] = [
+    [
+        Fp::from_raw([
+            0x6c7d_c0db_d0ab_d7a7,
+            0xa71a_a177_534c_dd1b,
+            0xfe1f_aaba_294c_ba38,
+            0x09c4_6e9e_c68e_9bd4,
+        ]),
+        Fp::from_raw([
+            0x3c1d_83ff_a604_cb81,
+            0xc514_2b3a_e405_b834,
+            0x2a97_ed93_7f31_35cf,
+            0x0c03_5653_0896_eec4,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x317e_a977_cc15_4a30,
+            0xa00e_a5aa_bd62_68bd,
+            0x142e_5118_2bb5_4cf4,
+            0x1e28_a1d9_3569_8ad1,
+        ]),
+        Fp::from_raw([
+            0x4cf9_e2b1_2b91_251f,
+            0x0e57_57c3_e008_db96,
+            0x0809_65db_30e2_98e4,
+            0x27af_2d83_1a9d_2748,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x79aa_f435_45b7_4e03,
+            0x4129_1462_f214_cd08,
+            0x3a6a_3cfe_16ae_175a,
+            0x1e6f_11ce_60fc_8f51,
+        ]),
+        Fp::from_raw([
+            0xf719_2062_68d1_42d3,
+            0x0446_2ed1_4c36_13d8,
+            0x8541_819c_b681_f0be,
+            0x2a67_384d_3bbd_5e43,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3640_8f5d_5c9f_45d0,
+            0xb985_e381_f025_1889,
+            0x1609_f8e1_2fbf_ecf0,
+            0x0b66_fdf3_5609_3a61,
+        ]),
+        Fp::from_raw([
+            0xdaa6_852d_bdb0_9e21,
+            0x0b26_c83c_c5ce_beed,
+            0x830c_6109_3c2a_de37,
+            0x012e_e3ec_1e78_d470,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2d10_8e7b_445b_b1b9,
+            0x6cd1_c431_b099_b6bb,
+            0xfd88_f67f_8175_e3fd,
+            0x0252_ba5f_6760_bfbd,
+        ]),
+        Fp::from_raw([
+            0xef5a_eaad_7ca9_32f1,
+            0x5439_1a89_35ff_71d6,
+            0x6c6b_ec3c_ef54_2963,
+            0x1794_74cc_eca5_ff67,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7e1a_2589_bbed_2b91,
+            0x9c1f_974a_2649_69b3,
+            0x9228_ff4a_503f_d4ed,
+            0x2c24_2613_79a5_1bfa,
+        ]),
+        Fp::from_raw([
+            0x53e6_6c05_5180_1b05,
+            0xc2f6_3f50_01fc_0fc5,
+            0xac2f_288b_d069_5b43,
+            0x1cc1_d7b6_2692_e63e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5d9e_ff5f_d9c9_1b56,
+            0x0078_4dbf_17fb_acd0,
+            0xb2ed_55f8_5297_9e96,
+            0x2550_5930_1aad_a98b,
+        ]),
+        Fp::from_raw([
+            0xb11c_29ce_7e59_efd9,
+            0xaea2_4234_970a_8193,
+            0x79e1_f5c0_eccd_32b3,
+            0x2843_7be3_ac1c_b2e4,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3387_62c3_7f5f_2043,
+            0x1854_8da8_fb4f_78d4,
+            0x1ca4_fa6b_5376_6eb1,
+            0x2821_6a44_2f2e_1f71,
+        ]),
+        Fp::from_raw([
+            0x131f_2377_3234_82c9,
+            0xeee1_efce_0309_4581,
+            0x1f39_f4e7_056d_d03f,
+            0x2c1f_47cd_17fa_5adf,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x646b_8566_a621_afc9,
+            0xd9da_fca2_7663_8a63,
+            0x8632_bcc9_356c_eb7d,
+            0x07ab_ad02_b7a5_ebc4,
+        ]),
+        Fp::from_raw([
+            0x37da_0c4d_15f9_6c3c,
+            0x9429_f908_80a6_9cd1,
+            0x275b_33ff_aab5_1dfe,
+            0x0230_2646_01ff_df29,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x717e_5d66_899a_a0a9,
+            0xa864_4145_57ee_289e,
+            0xa0f1_6865_6497_ca40,
+            0x1bc9_7305_4e51_d905,
+        ]),
+        Fp::from_raw([
+            0x2a6b_2228_8f0a_67fc,
+            0xd249_aff5_c2d8_421f,
+            0x206c_3157_e863_41ed,
+            0x2e1c_22f9_6443_5008,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa704_52bc_2bba_86b8,
+            0x9e8e_a159_8e46_c9f7,
+            0x121c_1d5f_461b_bc50,
+            0x1224_f38d_f67c_5378,
+        ]),
+        Fp::from_raw([
+            0x69d2_9891_86cd_e20e,
+            0xd7bf_e8cd_9dfe_da19,
+            0x9280_b4bd_9ed0_068f,
+            0x02e4_e69d_8ba5_9e51,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x6d47_e973_5d98_018e,
+            0x4f19_ee36_4e65_3f07,
+            0x7f5d_f81f_c04f_f3ee,
+            0x1f1e_ccc3_4aab_a013,
+        ]),
+        Fp::from_raw([
+            0xeacb_8a4d_4284_f582,
+            0x1424_4480_32cd_1819,
+            0x7426_6c30_39a9_a731,
+            0x1672_ad3d_709a_3539,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1d2e_d602_df8c_8fc7,
+            0xcda6_961f_284d_2499,
+            0x56f4_4af5_192b_4ae9,
+            0x283e_3fdc_2c6e_420c,
+        ]),
+        Fp::from_raw([
+            0x614f_bd69_ff39_4bcc,
+            0x6837_51f8_fdff_59d6,
+            0xd0db_0957_170f_a013,
+            0x1c2a_3d12_0c55_0ecf,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x96cb_6b81_7765_3fbd,
+            0x143a_9a43_773e_a6f2,
+            0xf789_7a73_2345_6efe,
+            0x216f_8487_7aac_6172,
+        ]),
+        Fp::from_raw([
+            0x11a1_f515_52f9_4788,
+            0xceaa_47ea_61ca_59a4,
+            0x64ba_7e8e_3e28_d12b,
+            0x2c0d_272b_ecf2_a757,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xcb4a_6c3d_8954_6f43,
+            0x170a_5480_abe0_508f,
+            0x484e_e7a7_4c45_4e9f,
+            0x16e3_4299_865c_0e28,
+        ]),
+        Fp::from_raw([
+            0x48cd_9397_5548_8fc5,
+            0x7720_4776_5802_290f,
+            0x375a_232a_6fb9_cc71,
+            0x175c_eba5_99e9_6f5b,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd8c5_ffbb_44a1_ee32,
+            0x6aa4_10bf_bc35_4f54,
+            0xfead_9e17_58b0_2806,
+            0x0c75_9444_0dc4_8c16,
+        ]),
+        Fp::from_raw([
+            0x9247_9882_d919_fd8d,
+            0x760e_2001_3ccf_912c,
+            0xc466_db7d_7eb6_fd8f,
+            0x1a3c_29bc_39f2_1bb5,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x95c8_eeab_cd22_e68f,
+            0x0855_d349_074f_5a66,
+            0xc098_6ea0_49b2_5340,
+            0x0ccf_dd90_6f34_26e5,
+        ]),
+        Fp::from_raw([
+            0xe0e6_99b6_7dd9_e796,
+            0x66a7_a8a3_fd06_5b3c,
+            0x2bdb_475c_e6c9_4118,
+            0x14f6_bc81_d9f1_86f6,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x88ed_eb73_86b9_7052,
+            0xcc09_9810_c9c4_95c8,
+            0x9702_ca70_b2f6_c5aa,
+            0x0962_b827_89fb_3d12,
+        ]),
+        Fp::from_raw([
+            0xafef_0c8f_6a31_a86d,
+            0x1328_4ab0_1ef0_2575,
+            0xbf20_c79d_e251_27bc,
+            0x1a88_0af7_074d_18b3,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x4c30_12bb_7ae9_311b,
+            0x20af_2924_fc20_ff3f,
+            0xcd5e_77f0_211c_154b,
+            0x10cb_a184_19a6_a332,
+        ]),
+        Fp::from_raw([
+            0x756a_2849_f302_f10d,
+            0xfa27_b731_9cae_3406,
+            0xbdc7_6ba6_3a9e_aca8,
+            0x057e_62a9_a8f8_9b3e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xafa0_413b_4428_0cee,
+            0xb961_303b_bf65_cff5,
+            0xd44a_df53_84b4_988c,
+            0x287c_971d_e91d_c0ab,
+        ]),
+        Fp::from_raw([
+            0x6f7f_7960_e306_891d,
+            0x1e56_2bc4_6d4a_ba4e,
+            0xb3bc_a9da_0cca_908f,
+            0x21df_3388_af16_87bb,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3eff_8b56_0e16_82b3,
+            0x789d_f8f7_0b49_8fd8,
+            0x3e25_cc97_4d09_34cd,
+            0x1be5_c887_d25b_ce70,
+        ]),
+        Fp::from_raw([
+            0x48d5_9c27_06a0_d5c1,
+            0xd2cb_5d42_fda5_acea,
+            0x6811_7175_cea2_cd0d,
+            0x268d_a36f_76e5_68fb,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xbd06_460c_c26a_5ed6,
+            0xc5d8_bb74_135e_bd05,
+            0xc609_beaf_5510_ecec,
+            0x0e17_ab09_1f6e_ae50,
+        ]),
+        Fp::from_raw([
+            0x040f_5caa_1f62_af40,
+            0x91ef_62d8_cf83_d270,
+            0x7aee_535a_b074_a430,
+            0x04d7_27e7_28ff_a0a6,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2b15_417d_7e39_ca6e,
+            0x3370_2ac1_0f1b_fd86,
+            0x81b5_4976_2bc0_22ed,
+            0x0ddb_d7bf_9c29_3415,
+        ]),
+        Fp::from_raw([
+            0x8a29_c49c_8789_654b,
+            0x34f5_b0d1_d3af_9b58,
+            0x7681_62e8_2989_c6c2,
+            0x2790_eb33_5162_1752,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x84b7_6420_6142_f9e9,
+            0x395f_3d9a_b8b2_fd09,
+            0x4471_9501_93d8_a570,
+            0x1e45_7c60_1a63_b73e,
+        ]),
+        Fp::from_raw([
+            0xc4c6_86fc_46e0_91b0,
+            0xfa90_ecd0_c43f_f91f,
+            0x638d_6ab2_bbe7_135f,
+            0x21ae_6430_1dca_9625,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5858_534e_ed8d_350b,
+            0x854b_e9e3_432e_0955,
+            0x4da2_9316_6f49_4928,
+            0x0379_f63c_8ce3_468d,
+        ]),
+        Fp::from_raw([
+            0x8c9f_58a3_24c3_5049,
+            0xca0e_4921_a466_86ac,
+            0x6a74_4a08_0809_e054,
+            0x002d_5642_0359_d026,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0fc2_c5af_9635_15a6,
+            0xda8d_6245_9e21_f409,
+            0x1d68_b3cd_32e1_0bbe,
+            0x1231_58e5_965b_5d9b,
+        ]),
+        Fp::from_raw([
+            0x60c8_0eb4_9cad_9ec1,
+            0x0fbb_2b6f_5283_6d4e,
+            0x661d_14bb_f6cb_e042,
+            0x0be2_9fc4_0847_a941,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2338_02f2_4fdf_4c1a,
+            0x36db_9d85_9cad_5f9a,
+            0x5771_6142_015a_453c,
+            0x1ac9_6991_dec2_bb05,
+        ]),
+        Fp::from_raw([
+            0x51ca_3355_bcb0_627e,
+            0x5e12_c9fa_97f1_8a92,
+            0x5f49_64fc_61d2_3b3e,
+            0x1596_443f_763d_bcc2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd6d0_49ea_e3ba_3212,
+            0xf185_7d9f_17e7_15ae,
+            0x6b28_61d4_ec3a_eae0,
+            0x12e0_bcd3_654b_dfa7,
+        ]),
+        Fp::from_raw([
+            0x04e6_c76c_7cf9_64ba,
+            0xceab_ac7f_3715_4b19,
+            0x9ea7_3d4a_f9af_2a50,
+            0x0fc9_2b4f_1bbe_a82b,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x9c7e_9652_3387_2762,
+            0xb14f_7c77_2223_6f4f,
+            0xd6f2_e592_a801_3f40,
+            0x1f9c_0b16_1044_6442,
+        ]),
+        Fp::from_raw([
+            0x8d15_9f64_3dbb_f4d3,
+            0x050d_914d_a38b_4c05,
+            0xf8cd_e061_57a7_82f4,
+            0x0ebd_7424_4ae7_2675,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7a83_9839_dccf_c6d1,
+            0x3b06_71e9_7346_ee39,
+            0x69a9_fafd_4ab9_51c0,
+            0x2cb7_f0ed_39e1_6e9f,
+        ]),
+        Fp::from_raw([
+            0x90c7_2bca_7352_d9bf,
+            0xce76_1d05_14ce_5266,
+            0x5605_443e_e41b_ab20,
+            0x1a9d_6e2e_cff0_22cc,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x87da_182d_648e_c72f,
+            0xd0c1_3326_a9a7_ba30,
+            0x5ea8_3c3b_c44a_9331,
+            0x2a11_5439_607f_335a,
+        ]),
+        Fp::from_raw([
+            0x9535_c115_c5a4_c060,
+            0xe738_b563_05cd_44f2,
+            0x15b8_fa7a_ee3e_3410,
+            0x23f9_b652_9b5d_040d,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x260e_b939_f0e6_e8a7,
+            0xa3ce_97c1_6d58_b68b,
+            0x249a_c6ba_484b_b9c3,
+            0x0587_2c16_db0f_72a2,
+        ]),
+        Fp::from_raw([
+            0x2b62_4a7c_dedd_f6a7,
+            0x0219_b615_1d55_b5c5,
+            0xca20_fb80_1180_75f4,
+            0x1300_bdee_08bb_7824,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x072e_4e7b_7d52_b376,
+            0x8d7a_d299_16d9_8cb1,
+            0xe638_1786_3a8f_6c28,
+            0x19b9_b63d_2f10_8e17,
+        ]),
+        Fp::from_raw([
+            0x24a2_0128_481b_4f7f,
+            0x13d1_c887_26b5_ec42,
+            0xb5bd_a237_6685_22f6,
+            0x015b_ee13_57e3_c015,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xea92_c785_b128_ffd1,
+            0xfe1e_1ce4_bab2_18cb,
+            0x1b97_07a4_f161_5e4e,
+            0x2953_736e_94bb_6b9f,
+        ]),
+        Fp::from_raw([
+            0x4ce7_266e_d660_8dfc,
+            0x851b_98d3_72b4_5f54,
+            0x862f_8061_80c0_385f,
+            0x0b06_9353_ba09_1618,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x4f58_8ac9_7d81_f429,
+            0x55ae_b7eb_9306_b64e,
+            0x15e4_e0bc_fb93_817e,
+            0x304f_74d4_61cc_c131,
+        ]),
+        Fp::from_raw([
+            0xb8ee_5415_cde9_13fc,
+            0xaad2_a164_a461_7a4c,
+            0xe8a3_3f5e_77df_e4f5,
+            0x15bb_f146_ce9b_ca09,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa9ff_2385_9572_c8c6,
+            0x9b8f_4b85_0405_c10c,
+            0x4490_1031_4879_64ed,
+            0x0ab4_dfe0_c274_2cde,
+        ]),
+        Fp::from_raw([
+            0x251d_e39f_9639_779a,
+            0xef5e_edfe_a546_dea9,
+            0x97f4_5f76_49a1_9675,
+            0x0e32_db32_0a04_4e31,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa307_8efa_516d_a016,
+            0x6797_733a_8277_4896,
+            0xb276_35a7_8b68_88e6,
+            0x0a17_56aa_1f37_8ca4,
+        ]),
+        Fp::from_raw([
+            0x4254_d6a2_a25d_93ef,
+            0x95e6_1d32_8f85_efa9,
+            0x47fd_1717_7f95_2ef8,
+            0x044c_4a33_b10f_6934,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd37b_07b5_466c_4b8b,
+            0xfe08_79d7_9a49_6891,
+            0xbe65_5b53_7f66_f700,
+            0x2ed3_611b_725b_8a70,
+        ]),
+        Fp::from_raw([
+            0xd833_9ea7_1208_58aa,
+            0xadfd_eb9c_fdd3_47b5,
+            0xc8ec_c3d7_22aa_2e0e,
+            0x1f9b_a4e8_bab7_ce42,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xb740_56f8_65c5_d3da,
+            0xa38e_82ac_4502_066d,
+            0x8f7e_e907_a84e_518a,
+            0x1b23_3043_052e_8c28,
+        ]),
+        Fp::from_raw([
+            0xca2f_97b0_2087_5954,
+            0x9020_53bf_c0f1_4db0,
+            0x7403_1ab7_2bd5_5b4c,
+            0x2431_e1cc_164b_b8d0,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa791_f273_9658_01fd,
+            0xa13e_3220_9758_3319,
+            0x30cd_6953_a0a7_db45,
+            0x082f_934c_91f5_aac3,
+        ]),
+        Fp::from_raw([
+            0x9ad6_bb93_0c48_997c,
+            0xc772_45e2_ae7c_be99,
+            0xa34b_e074_3155_42a3,
+            0x2b9a_0a22_3e75_38b0,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xb0b5_89cc_7021_4e7d,
+            0x8164_163e_75a8_a00e,
+            0xceb8_5483_b887_a9be,
+            0x0e1c_d91e_dd2c_fa2c,
+        ]),
+        Fp::from_raw([
+            0x88d3_2460_1ceb_e2f9,
+            0x9977_4f19_854d_00f5,
+            0xc951_f614_77e3_6989,
+            0x2e1e_ac0f_2bfd_fd63,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x23d7_4811_5b50_0b83,
+            0x7345_784d_8efd_b33c,
+            0x0c76_158e_769d_6d15,
+            0x0cbf_a95f_37fb_7406,
+        ]),
+        Fp::from_raw([
+            0x980c_232d_fa4a_4f84,
+            0x76d9_91e3_a775_13d9,
+            0xd65a_d49d_8a61_e9a6,
+            0x08f0_5b3b_e923_ed44,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x25a2_dd51_0c04_7ef6,
+            0xe728_4925_dc07_58a3,
+            0x52bf_8e21_984d_0443,
+            0x2271_9e2a_070b_cd08,
+        ]),
+        Fp::from_raw([
+            0xf41f_62b2_f268_30c0,
+            0x7bdb_f036_1199_82c0,
+            0xc060_f7fc_c3a1_ab4c,
+            0x041f_596a_9ee1_cb2b,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x19fc_dd09_86b1_0f89,
+            0x021b_e1c2_d0dc_464a,
+            0x8762_8eb0_6f6b_1d4c,
+            0x233f_d35d_e1be_520a,
+        ]),
+        Fp::from_raw([
+            0xefcb_453c_61c9_c267,
+            0xd31e_078a_a1b4_707e,
+            0x4325_e0a4_23eb_c810,
+            0x0524_b46d_1aa8_7a5e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xcc44_8623_7c51_5211,
+            0x4227_bb95_4b0f_3199,
+            0xce47_fcac_894b_8582,
+            0x2c34_f424_c81e_5716,
+        ]),
+        Fp::from_raw([
+            0xf330_1032_7de4_915e,
+            0x2dd2_025b_5457_cc97,
+            0x207e_ffc2_b554_1fb7,
+            0x0b5f_2a4b_6338_7819,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xaefa_c41f_e05c_659f,
+            0xc174_35d2_f57a_f6ce,
+            0xc5b7_2fe4_39d2_cfd6,
+            0x2220_7856_082c_cc54,
+        ]),
+        Fp::from_raw([
+            0x2785_4048_ce2c_8171,
+            0xcdfb_2101_94ca_f79f,
+            0x4e24_159b_7f89_50b5,
+            0x24d5_7a8b_f5da_63fe,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7391_9bb2_3b79_396e,
+            0x374a_d709_7bb0_1a85,
+            0x3b37_1d75_bd69_3f98,
+            0x0afa_b181_fdd5_e058,
+        ]),
+        Fp::from_raw([
+            0xf162_90d6_2b11_28ee,
+            0x76c0_0571_94c1_6c0b,
+            0x998a_52ef_ac7c_bd56,
+            0x2dba_9b10_8f20_8772,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5aff_13e6_bce4_20b3,
+            0xcbb8_3de0_bd59_2b25,
+            0x56f8_81c7_88f5_3f83,
+            0x2634_9b66_edb8_b16f,
+        ]),
+        Fp::from_raw([
+            0x2352_88a3_e6f1_37db,
+            0xd81a_56d2_8ecc_193b,
+            0x685e_95f9_2339_753a,
+            0x25af_7ce0_e5e1_0357,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1f7c_0187_fe35_011f,
+            0x70ee_d7aa_e88b_2bff,
+            0xc094_d6a5_5edd_68b9,
+            0x25b4_ce7b_d229_4390,
+        ]),
+        Fp::from_raw([
+            0x8cb9_d54c_1e02_b631,
+            0xde9c_ef28_ebdf_30b1,
+            0x387e_53f1_908a_88e5,
+            0x22c5_43f1_0f6c_89ec,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xdf66_8e74_882f_87a9,
+            0x425e_906a_919d_7a34,
+            0x4fc7_908a_9f19_1e1e,
+            0x0236_f93e_7789_c472,
+        ]),
+        Fp::from_raw([
+            0x9cb4_97af_980c_4b52,
+            0x652b_dae1_14eb_0165,
+            0x0e7d_27e3_7d05_da99,
+            0x2935_0b40_1166_ca01,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xee12_6091_6652_363f,
+            0x65ed_b75d_844e_bb89,
+            0x6bd3_1bba_b547_f75a,
+            0x0eed_787d_6582_0d3f,
+        ]),
+        Fp::from_raw([
+            0x1906_f656_f4de_6fad,
+            0xfdcd_0e99_bd94_297d,
+            0x036a_753f_520b_3291,
+            0x07cc_1170_f13b_46f2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2059_4356_89e8_acea,
+            0x9087_86d7_f9f5_d10c,
+            0xf49b_cf61_3a3d_30b1,
+            0x22b9_3923_3b1d_7205,
+        ]),
+        Fp::from_raw([
+            0xadd6_50ac_e60a_e5a6,
+            0x740f_083a_5aa8_5438,
+            0x8aad_1dc8_bc33_e870,
+            0x0145_1762_a0aa_b81c,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe704_fec0_892f_ce89,
+            0xe32e_aa61_dec7_da57,
+            0x61fa_bf10_25d4_6d1f,
+            0x2350_6bb5_d872_7d44,
+        ]),
+        Fp::from_raw([
+            0x7f8b_d689_0735_5522,
+            0x2a37_0953_1e1e_fea9,
+            0xbac0_6ae3_f71b_dd09,
+            0x2e48_4c44_e838_aea0,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x4541_8da2_6835_b54c,
+            0xaf4a_5945_45ce_dc25,
+            0x379e_78c5_0bd2_e42b,
+            0x0f4b_c7d0_7eba_fd64,
+        ]),
+        Fp::from_raw([
+            0xe620_996d_50d8_e74e,
+            0x5158_2388_725d_f460,
+            0xfa76_6378_62fa_aee8,
+            0x1f4d_3c8f_6583_e9e5,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x53eb_9bcb_48fe_7389,
+            0xfae0_2abc_7b68_1d91,
+            0x2660_d07b_e0e4_a988,
+            0x0935_14e0_c707_11f8,
+        ]),
+        Fp::from_raw([
+            0x4a58_e0a3_47e1_53d8,
+            0x43ee_83ec_e472_28f2,
+            0x4669_9a2b_5f3b_c036,
+            0x1ada_b0c8_e2b3_bad3,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1a22_dbef_9e80_dad2,
+            0x378c_1b94_b807_2bac,
+            0xd147_09eb_b474_641a,
+            0x1672_b172_6057_d99d,
+        ]),
+        Fp::from_raw([
+            0x30d4_7b23_9b47_9c14,
+            0xc5d8_e2fa_e0ac_c4ee,
+            0x8f44_f53f_dcab_468c,
+            0x1dfd_53d4_576a_f2e3,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xbc7f_2077_5320_5c60,
+            0xe6d7_7d64_0f6f_c3de,
+            0xa70a_3626_3a37_e17f,
+            0x0c68_88a1_0b75_b0f3,
+        ]),
+        Fp::from_raw([
+            0x8509_1ecc_a9d1_e508,
+            0x611a_61e0_0ee6_848b,
+            0x92b3_4a7e_77d1_2fe8,
+            0x1add_b933_a65b_e770,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7935_628e_299d_1791,
+            0xf638_ff54_25f0_afff,
+            0x5c10_ae18_d1de_933c,
+            0x00d7_540d_cd26_8a84,
+        ]),
+        Fp::from_raw([
+            0xd316_939d_20b8_2c0e,
+            0x26fe_dde4_acd9_9db1,
+            0x01b2_827a_5664_ca9c,
+            0x140c_0e42_687e_9ead,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xc091_e2ae_5656_5984,
+            0xc20a_0f9b_24f8_c5ed,
+            0x91ba_89b8_d13d_1806,
+            0x2f0c_3a11_5d43_17d1,
+        ]),
+        Fp::from_raw([
+            0xd8c5_38a1_dc95_8c61,
+            0x08a0_cff6_70b2_2b82,
+            0x3006_ed22_0cf9_c810,
+            0x0c4e_e778_ff7c_1455,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x27c3_d748_5de7_4c69,
+            0x9424_ed26_c0ac_c662,
+            0x3693_f004_40cc_c360,
+            0x1704_f276_6d46_f82c,
+        ]),
+        Fp::from_raw([
+            0x39b6_6fe9_009c_3cfa,
+            0xf076_9c9f_8544_e402,
+            0xa7a0_2c1b_51d2_44ab,
+            0x2f2d_19cc_3ea5_d78e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd6c7_66a8_06fc_6629,
+            0xdd7e_e6cb_9cfe_d9c7,
+            0x5053_f112_e2a8_e8dc,
+            0x1ae0_3853_b75f_caba,
+        ]),
+        Fp::from_raw([
+            0x4e41_a86d_daf0_56d5,
+            0x3556_921b_2d6f_014e,
+            0x51d1_31d0_fa61_aa5f,
+            0x0971_aabf_7952_41df,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5f5c_29f7_bfe2_f646,
+            0xda62_4f83_80df_1c87,
+            0x91d4_cf6b_6e0d_e73e,
+            0x1408_c316_e601_4e1a,
+        ]),
+        Fp::from_raw([
+            0x4169_1f39_822e_f5bd,
+            0x6c89_f1f7_73ef_2853,
+            0x248a_be42_b543_093b,
+            0x1667_f3fe_2edb_e850,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x424c_6957_6500_fe37,
+            0x5b81_7184_09e5_c133,
+            0xa48b_0a03_557c_df91,
+            0x13bf_7c5d_0d2c_4376,
+        ]),
+        Fp::from_raw([
+            0x19bc_0ba7_43a6_2c2c,
+            0x024b_9534_7856_b797,
+            0x3016_adf3_d353_3c24,
+            0x0762_0a6d_fb0b_6cec,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1675_de3e_1982_b4d0,
+            0x75d2_959e_2f32_2b73,
+            0x36a8_ca08_bdbd_d8b0,
+            0x1574_c7ef_0c43_545f,
+        ]),
+        Fp::from_raw([
+            0xc06e_03a7_ff83_78f0,
+            0x5bd4_1845_71c2_54fd,
+            0xfd56_7970_a717_ceec,
+            0x269e_4b5b_7a2e_b21a,
+        ]),
+    ],
+]; // n: 254
+   // t: 5
+   // N: 1270
+   // Result Algorithm 1:
+   // [True, 0]
+   // Result Algorithm 2:
+   // [True, None]
+   // Result Algorithm 3:
+   // [True, None]
+   // Prime number: 0x0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+   // MDS matrix:
+pub(crate) const MDS: [[Fp; 2]; 2] = [
+    [
+        Fp::from_raw([
+            0xbcec_a70b_d2af_7ad5,
+            0xaf07_f38a_f8c9_52a7,
+            0xec10_3453_51a2_3a3a,
+            0x066f_6f85_d6f6_8a85,
+        ]),
+        Fp::from_raw([
+            0x0546_2b9f_8125_b1e8,
+            0x20a7_c02b_bd8b_ea73,
+            0x7782_e150_9b1d_0fdb,
+            0x2b9d_4b41_10c9_ae99,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xf573_f431_221f_8ff9,
+            0xb6c0_9d55_7013_fff1,
+            0x2bf6_7a44_93cc_262f,
+            0x0cc5_7cdb_b085_07d6,
+        ]),
+        Fp::from_raw([
+            0x21bc_d147_9432_03c8,
+            0xade8_57e8_6eb5_c3a1,
+            0xa31a_6ed6_9724_e1ad,
+            0x1274_e649_a32e_d355,
+        ]),
+    ],
+]; // Inverse MDS matrix:
+pub(crate) const MDS_INV: [[Fp; 2]; 2] = [
+    [
+        Fp::from_raw([
+            0x8dbe_bd0f_a8c5_3e66,
+            0x0554_569d_9b29_d1ea,
+            0x7081_9ab1_c784_6f21,
+            0x13ab_ec39_0ada_7f43,
+        ]),
+        Fp::from_raw([
+            0xaaf6_185b_1a1e_60fe,
+            0xbd52_1ead_5dfe_0345,
+            0x4c98_62a1_d97d_1510,
+            0x1eb9_e1dc_19a3_3a62,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x763f_7875_036b_cb02,
+            0x8ce5_1690_30a2_ad69,
+            0x601a_bc49_fdad_4f03,
+            0x0fc1_c939_4db8_9bb2,
+        ]),
+        Fp::from_raw([
+            0x8abc_ed6b_d147_c8be,
+            0x2b7e_ac34_3459_61bc,
+            0x9502_054e_dc03_e7b2,
+            0x16a9_e98c_493a_902b,
+        ]),
+    ],
+];
+// Constants of the optimized permutation:
+pub(crate) const PRE_SPARSE_ROUND_CONSTANTS: [Fp; 2] = [
+    Fp::from_raw([
+        0x2d10_8e7b_445b_b1b9,
+        0x6cd1_c431_b099_b6bb,
+        0xfd88_f67f_8175_e3fd,
+        0x0252_ba5f_6760_bfbd,
+    ]),
+    Fp::from_raw([
+        0xa8a9_5b9b_f0fa_a8fd,
+        0x9e39_3e96_c7e3_477c,
+        0x347f_2d23_2b41_1aca,
+        0x18b1_2aeb_a958_2741,
+    ]),
+];
+pub(crate) const PARTIAL_ROUND_CONSTANTS: [Fp; 55] = [
+    Fp::from_raw([
+        0x21f8_0223_a122_4245,
+        0x16e0_fd2f_c38d_5476,
+        0x97ce_21d0_39ef_7e65,
+        0x27f5_692d_65a0_a710,
+    ]),
+    Fp::from_raw([
+        0xdba5_c747_5505_c9e5,
+        0x821d_131d_591f_cf9e,
+        0x6513_3ff5_fa8a_fe96,
+        0x14ec_07c4_af80_f92b,
+    ]),
+    Fp::from_raw([
+        0x9e32_0701_9755_815d,
+        0xbe70_8ef7_4240_85b4,
+        0xefb9_3c31_0fca_5dc6,
+        0x24e8_948a_6fdb_4a58,
+    ]),
+    Fp::from_raw([
+        0x94ad_b92a_51ba_655b,
+        0x5274_9c25_22c5_61e2,
+        0x91c0_4543_b7e9_f312,
+        0x1c36_da09_45fc_7593,
+    ]),
+    Fp::from_raw([
+        0x9d51_b94d_f787_f5f8,
+        0xbfe1_7081_19d8_6e8a,
+        0x9cb8_efd5_af79_1e9b,
+        0x12d8_13bd_b0d2_e356,
+    ]),
+    Fp::from_raw([
+        0x151b_b2dd_66af_fb0d,
+        0x38bd_7374_0cda_af10,
+        0xf839_6d02_02f7_aee5,
+        0x18f5_2a7b_8e1f_ef0b,
+    ]),
+    Fp::from_raw([
+        0xe5a2_c5de_2254_6560,
+        0xf777_02f6_9883_6083,
+        0x9a81_76bd_49be_bcd4,
+        0x2562_ea42_8c65_dde8,
+    ]),
+    Fp::from_raw([
+        0xc197_568b_734e_bf6a,
+        0x2ba1_cd9a_5e9c_e088,
+        0x5098_a6a2_1421_ffb7,
+        0x19b4_b6d8_9683_6ca3,
+    ]),
+    Fp::from_raw([
+        0xd6d8_109c_03af_d72b,
+        0xc4a7_9661_68a8_2919,
+        0x7bb5_4e88_eabc_ebd2,
+        0x0002_69a1_99ae_893e,
+    ]),
+    Fp::from_raw([
+        0x8939_9e23_d82d_9427,
+        0x8550_e3e1_436e_5b53,
+        0xdc99_8dea_6a96_fdb2,
+        0x2161_fe8b_47d5_07cc,
+    ]),
+    Fp::from_raw([
+        0x3f8a_743c_0059_22c4,
+        0xe410_8375_dc12_2c3d,
+        0xc29f_b047_a9a8_db88,
+        0x2900_d1a5_4207_3c40,
+    ]),
+    Fp::from_raw([
+        0xc3a5_2514_aff1_af06,
+        0x8fc8_c062_54e3_717e,
+        0x1e27_795f_3a7b_0779,
+        0x0b97_de80_9e57_a553,
+    ]),
+    Fp::from_raw([
+        0x24cd_1d3d_8256_dc67,
+        0x1eeb_ac81_2fd8_4438,
+        0x7d8a_9f2a_4139_2de1,
+        0x041f_f5ff_3d09_dfa7,
+    ]),
+    Fp::from_raw([
+        0x01b9_25c7_12a5_bc74,
+        0x4c9b_ade1_dab2_ef0f,
+        0xf7a1_1ae7_eec7_13cc,
+        0x28b6_8143_1c3c_a850,
+    ]),
+    Fp::from_raw([
+        0x7961_5e5f_92ee_72b8,
+        0x8cf5_f928_93e6_7189,
+        0x4895_3fd5_5078_2b68,
+        0x171f_431d_eb60_2a4c,
+    ]),
+    Fp::from_raw([
+        0x72bf_6408_5436_ba62,
+        0xdadb_0a23_8644_7716,
+        0xd4fd_052a_5d2d_4958,
+        0x26bf_3e66_ae24_f9f9,
+    ]),
+    Fp::from_raw([
+        0x6bd9_d8fe_9f4b_f973,
+        0x6388_57e8_9222_be50,
+        0xc345_7461_2f62_3a39,
+        0x3025_2856_c81d_d695,
+    ]),
+    Fp::from_raw([
+        0x9ead_9730_3716_a02d,
+        0x70cf_8dfb_3f16_a92b,
+        0x1192_03d8_fcd8_2763,
+        0x20c5_a5b1_ca92_9274,
+    ]),
+    Fp::from_raw([
+        0xaae6_e78b_a7f3_13f1,
+        0x7ae5_3f67_0857_2038,
+        0x4324_3734_342f_f654,
+        0x260c_7122_3966_ee90,
+    ]),
+    Fp::from_raw([
+        0x0ceb_75f5_f3fb_9a02,
+        0xb1b3_00f8_be76_0c3e,
+        0x003a_30b8_a26e_b9af,
+        0x2d87_292b_6903_0e28,
+    ]),
+    Fp::from_raw([
+        0x033f_e03b_3132_296e,
+        0x1bb0_9e24_deab_08cb,
+        0x8f20_053c_39d8_cf37,
+        0x2c72_17c1_0a68_bb96,
+    ]),
+    Fp::from_raw([
+        0x61e6_a630_e98d_9e3e,
+        0xea01_2f75_b2f4_ec2c,
+        0x79af_4546_1288_1494,
+        0x1f53_b812_b263_375d,
+    ]),
+    Fp::from_raw([
+        0x985c_f608_4777_9f5f,
+        0x86be_ed0a_2d47_54f0,
+        0x568a_5410_6734_1ceb,
+        0x2db5_cd30_b036_e1fe,
+    ]),
+    Fp::from_raw([
+        0x0c94_c0ba_eb41_1511,
+        0x4120_d98d_dad5_68d4,
+        0x67f9_9e77_6b6c_98fd,
+        0x207b_fbd8_bfbd_e81f,
+    ]),
+    Fp::from_raw([
+        0xa201_0969_5418_2642,
+        0xe6f9_4d19_1176_6a56,
+        0x85ef_c7be_79f4_c3f2,
+        0x0bb6_0cb2_1cb4_9188,
+    ]),
+    Fp::from_raw([
+        0xf678_782a_a4a5_d1f0,
+        0x28db_428b_3626_fc8f,
+        0x1e39_dea8_b896_4628,
+        0x1928_fbe0_23f5_b82b,
+    ]),
+    Fp::from_raw([
+        0xd1e7_9f12_f1db_e774,
+        0x0022_c4da_169a_151a,
+        0x61f6_ef5d_07af_a98b,
+        0x106b_7f51_31fd_4598,
+    ]),
+    Fp::from_raw([
+        0x9889_7fb1_dd98_2e19,
+        0x7312_5d27_8ed6_c3cd,
+        0xfc22_4c1a_b3bb_1678,
+        0x211a_fbcb_c9c1_c7e9,
+    ]),
+    Fp::from_raw([
+        0x82e0_558f_31ec_73e2,
+        0xe364_75fe_8ce4_23c1,
+        0x629f_da0f_1492_4cbb,
+        0x05fc_1fa6_152f_b937,
+    ]),
+    Fp::from_raw([
+        0xa524_bbcf_21a0_3589,
+        0x6885_3fdb_5326_e2e1,
+        0x1f00_7744_afc8_5bc6,
+        0x1f9a_b155_6aef_5868,
+    ]),
+    Fp::from_raw([
+        0xbda0_edc1_90d5_aebc,
+        0x20d2_d892_083c_96eb,
+        0x5387_7b31_f074_cac1,
+        0x302c_bfdb_d6c8_5bcd,
+    ]),
+    Fp::from_raw([
+        0x555a_9c20_c0da_8b15,
+        0xf48f_094e_e420_42df,
+        0x73a5_f192_8f00_4938,
+        0x2a37_d7c5_7988_e8b6,
+    ]),
+    Fp::from_raw([
+        0x224c_4be4_b3e9_5760,
+        0x7121_4f00_d2d5_b58b,
+        0x2b4c_867a_43ef_7d60,
+        0x1b1e_28d2_e5ed_1174,
+    ]),
+    Fp::from_raw([
+        0x1eb5_b2ba_799d_309c,
+        0x496f_69ec_d23c_5a50,
+        0xa9c1_63ec_b35d_66c7,
+        0x1927_810c_94f3_f41f,
+    ]),
+    Fp::from_raw([
+        0xf548_9eb4_5da5_e565,
+        0x076f_8ba8_b953_fd77,
+        0x9881_4a0f_de4b_84db,
+        0x01bb_47e6_3e38_4c0c,
+    ]),
+    Fp::from_raw([
+        0x15ab_4146_89c3_a496,
+        0x5380_6c8a_5991_bf6a,
+        0x4b6b_b06d_2bf5_3788,
+        0x19a3_6ee6_6a81_0768,
+    ]),
+    Fp::from_raw([
+        0xf1e1_415f_c10d_1fcb,
+        0x19da_da78_3d24_f387,
+        0x3f69_c935_ccaa_d353,
+        0x26ca_ad7d_38ae_294a,
+    ]),
+    Fp::from_raw([
+        0x5134_20d5_2849_aedf,
+        0x7c01_7b90_f301_96d6,
+        0x7eca_e082_85f6_850f,
+        0x13b0_2510_45f6_9250,
+    ]),
+    Fp::from_raw([
+        0x1204_1b0d_d9da_bb84,
+        0xb931_0960_6c23_f25d,
+        0x3ce6_3eb8_bd73_3749,
+        0x0951_b46c_5b28_77ba,
+    ]),
+    Fp::from_raw([
+        0xad06_444e_804e_ad99,
+        0xb5fd_7c27_0994_72c6,
+        0x768b_d3d6_194f_bf97,
+        0x2d77_353c_9e3b_39d4,
+    ]),
+    Fp::from_raw([
+        0x1de7_4497_1975_897b,
+        0xe764_c200_d994_0d06,
+        0xa50e_1be2_f3da_8cf8,
+        0x0e52_9f9e_2ccb_83e6,
+    ]),
+    Fp::from_raw([
+        0x1c02_9da5_08d8_dff0,
+        0xd7a8_2328_4d80_c143,
+        0xe2a1_2c58_3ffe_3767,
+        0x089c_9bb5_7126_33a7,
+    ]),
+    Fp::from_raw([
+        0xcc90_ee84_7fc3_d398,
+        0xfbdf_4d1c_7553_640d,
+        0xb838_5b68_3414_f60f,
+        0x0f69_4249_56d2_7456,
+    ]),
+    Fp::from_raw([
+        0xec20_4081_7a48_cbf5,
+        0x08d0_597a_f30e_5932,
+        0x85e5_9da3_fb98_5569,
+        0x1631_c2de_3460_7c7f,
+    ]),
+    Fp::from_raw([
+        0xe89b_c35a_2e4e_6a93,
+        0x6f79_4877_11c2_56be,
+        0x5f59_0027_5389_0efa,
+        0x00db_4cf3_b230_72e9,
+    ]),
+    Fp::from_raw([
+        0xc774_539a_b7dc_6f17,
+        0xd402_bcb0_58cc_6484,
+        0x98b8_0b48_a557_74c6,
+        0x2100_812c_2954_6b66,
+    ]),
+    Fp::from_raw([
+        0xbafb_924b_4ed4_f0df,
+        0xdc9b_e4bf_438b_db7e,
+        0xd7bf_72a0_ec46_ae9b,
+        0x2b61_767a_0d51_2edd,
+    ]),
+    Fp::from_raw([
+        0x1807_771e_7bbe_2ef9,
+        0xc12e_50fa_4c85_4725,
+        0x3d5e_1be5_889c_bc01,
+        0x076f_4a69_9d71_9b14,
+    ]),
+    Fp::from_raw([
+        0x686b_f2c2_3aef_5047,
+        0xbabb_45a8_1fef_e389,
+        0x699b_bd61_6d1b_874e,
+        0x1c2e_dfd9_c6af_abd8,
+    ]),
+    Fp::from_raw([
+        0xac51_2472_9869_7f8d,
+        0x1cd4_594a_711d_bb65,
+        0xd96d_b4f2_99c1_86a9,
+        0x1268_9a64_8cd7_3868,
+    ]),
+    Fp::from_raw([
+        0x6800_a93e_37ca_8a30,
+        0x29d7_5766_6daf_40f9,
+        0x1a66_fd7f_7f17_eb1b,
+        0x1010_fc45_8e97_a53e,
+    ]),
+    Fp::from_raw([
+        0xb692_3f20_13c2_151a,
+        0xc9a6_6997_665f_6aab,
+        0x657d_9dee_2af2_fc5b,
+        0x1b93_cf60_83b7_6f54,
+    ]),
+    Fp::from_raw([
+        0x9c3f_93c3_2087_e25c,
+        0x8a58_c1ad_c4f8_b485,
+        0x93b5_35b1_c46b_4801,
+        0x1135_ac96_d8dc_6c65,
+    ]),
+    Fp::from_raw([
+        0xd133_e3ca_ead0_9ee6,
+        0xb952_57b1_b157_ee79,
+        0xfcf5_4442_48ff_d3d2,
+        0x02fb_63de_c7ec_15a2,
+    ]),
+    Fp::from_raw([
+        0x6c07_9e0c_4305_0d91,
+        0xef5e_203d_bf22_e644,
+        0x9e26_8868_de17_5131,
+        0x0c38_627d_1181_ccaf,
+    ]),
+];
+pub(crate) const PRE_SPARSE_MDS: [[Fp; 2]; 2] = [
+    [
+        Fp::from_raw([
+            0x0000_0000_0000_0001,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x319e_8d97_bcf3_b57a,
+            0xe264_57ca_7f35_4efe,
+            0x5400_9017_41b7_9aff,
+            0x183b_3233_2186_571e,
+        ]),
+    ],
+];
+pub(crate) const SPARSE_MDS: [SparseMds<Fp, 2>; 56] = [
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3e45_de82_5ff8_cb5f,
+                0x2ec3_3b3c_bd59_0c79,
+                0xfd52_998d_6a5b_e6f1,
+                0x1fd2_0dcb_5850_3896,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x4a11_b5be_4e0a_1efb,
+                0x7096_c1e6_452d_33d6,
+                0x1e97_f0cd_de8f_202a,
+                0x08c8_295d_f0ba_1186,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x401b_9964_21e8_9835,
+                0x47e2_0e05_a76b_c12b,
+                0xd165_d2b0_8b95_cf89,
+                0x2458_ee6d_7c52_6073,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xef84_e938_209c_92f9,
+                0x9f78_a2fe_1fbf_b44e,
+                0xa65b_af06_ffa5_5782,
+                0x18c2_35e6_e723_390a,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x36c3_7df2_4976_a584,
+                0x07b5_2e58_595c_3934,
+                0xe9c5_61b4_b192_899d,
+                0x1868_e106_689f_8ea2,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xcbe8_40b7_b609_ca92,
+                0x2115_3350_df40_75c9,
+                0x5cb8_904a_d3e8_e9ec,
+                0x2d51_6180_4f0e_c644,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x7ecb_79dd_cf7b_0b90,
+                0xab2d_c3f4_9146_12da,
+                0x55c6_90f0_3b9b_815a,
+                0x0351_0550_ddf6_2923,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x909c_f2d5_f6ee_6170,
+                0x5efe_0058_403d_8a52,
+                0x5f26_4a26_345a_d836,
+                0x09a2_e7bb_3b27_8a1d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xfe14_9b50_b557_59c0,
+                0x8ae9_4a0e_6439_be66,
+                0xb015_a7a4_233f_4e48,
+                0x13dd_4148_c491_a166,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x9f86_ddf1_1029_5550,
+                0xd901_50ee_e4ce_55dc,
+                0xc010_f42e_c382_5649,
+                0x1d2d_3b26_1f5b_eb3f,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xcd8b_a686_43ff_77e5,
+                0x1291_90b3_855a_e4ee,
+                0x2abc_abb4_7eb5_e7a4,
+                0x221b_4477_678d_ad4e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x5f1d_40e8_e8fc_d6e4,
+                0x830a_4998_ff45_ebc2,
+                0x5f20_43f5_f09b_edf1,
+                0x16b7_6448_e985_5f16,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x2363_460e_2286_c92d,
+                0x8eae_6d3b_d53a_c860,
+                0x142c_f661_04b6_9e44,
+                0x2a10_b6a2_cbae_9578,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x22e3_1949_b69f_2bec,
+                0x8ccf_a871_b9c0_67c5,
+                0xbe94_dfb1_d59e_dfa5,
+                0x2db8_4dba_4a9d_96ce,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd5c1_49be_890e_7b60,
+                0xc273_cf20_c1f6_8f03,
+                0x96ad_897e_c1a5_5b4f,
+                0x1ca9_bbf2_6402_2382,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x367e_0d7b_5fde_30d6,
+                0x0384_8ca7_30ac_e52f,
+                0x83d1_b9d3_4ecb_00cd,
+                0x283d_37fa_5e35_d25c,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x009a_b5f9_c791_d960,
+                0x1271_e618_a174_e5d8,
+                0xea5a_063a_0ff8_ab22,
+                0x0349_0579_41b1_41ce,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x16c3_44d4_0070_efc2,
+                0xe895_8eb1_e5d6_5380,
+                0x8503_883d_3b9e_d50f,
+                0x1af2_e1d9_8c3c_0990,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x91f5_92e6_c1da_4679,
+                0x2743_d002_3bb6_4022,
+                0xaa30_82e2_c054_4896,
+                0x05b7_bdda_cf45_22b0,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x7e70_4131_c8a3_5241,
+                0x7ff8_c4a0_bc78_909c,
+                0x3cf6_a0c2_1a64_45da,
+                0x1fce_eccf_337e_8a90,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc960_473e_917a_6768,
+                0xe058_dfb6_d91c_322f,
+                0xafdd_850f_31d1_920e,
+                0x2f30_a25f_4d84_3e28,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x68f3_c08f_7c07_ce87,
+                0x4574_a89e_5857_751b,
+                0xbcc5_9162_6170_2619,
+                0x1a55_160b_f49a_4936,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x03b3_42b9_5709_041e,
+                0xf1af_24f5_4761_998b,
+                0xb175_73b6_ad19_2b7d,
+                0x19c6_6ee8_87ab_4763,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3789_c910_4f9c_781e,
+                0x06ab_3e72_3266_777b,
+                0xf5f8_624b_31e1_1155,
+                0x1a82_828b_7f87_eba7,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd99d_ebc7_faed_3385,
+                0xe84a_242d_81ae_3a81,
+                0xde9f_c4e7_1b25_0825,
+                0x0f50_6d31_99d9_6083,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x4b2c_0447_2aab_9f83,
+                0x1723_74d9_9987_a8b2,
+                0x57db_9072_a975_67ff,
+                0x1cac_7482_d91f_aef6,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3216_939a_7284_8150,
+                0xee63_d489_ee15_3ecc,
+                0x3f31_92f0_e4f5_2884,
+                0x249b_26b1_d4e3_33b2,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x339d_ca33_6180_dfb1,
+                0xf145_972d_cd3a_0073,
+                0x8b97_1311_5440_7d1b,
+                0x13d1_aeb1_0b22_5e2a,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x84e4_1166_24d1_6df3,
+                0x5272_0cbc_14a7_0957,
+                0xb2e0_f940_0787_d1dd,
+                0x0b90_f72b_7fcf_867e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc5a6_cd70_011a_8f39,
+                0x30f0_c8d0_8bc5_c365,
+                0x42ab_d213_5951_3982,
+                0x025e_346e_dd8d_d551,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x587f_2630_a7b8_68a2,
+                0xeb87_b4e2_1b7c_424e,
+                0xc642_7b0b_4589_ef6c,
+                0x2630_03b8_ed50_2577,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x89c0_9961_ec12_ead7,
+                0x8eb9_c122_55b9_10ff,
+                0x1b6c_5b1c_92e5_a824,
+                0x2d92_ef6b_8bdc_53dd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3543_870c_1131_6681,
+                0xb15b_cae4_181b_bb12,
+                0x8d6d_9459_a7af_04f0,
+                0x2eb3_6b6d_0bfc_593a,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x833b_2791_f590_34e0,
+                0xe37f_4a5c_2354_952c,
+                0xc1d2_f085_f397_9139,
+                0x1e48_bb59_1146_f461,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x51bb_0015_50d8_162a,
+                0xcf8a_03f5_4107_9c1b,
+                0x1f03_51ee_180c_a466,
+                0x0e75_9913_7514_1887,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3275_03cf_49ec_a55b,
+                0x2e85_9c46_61b1_4b7e,
+                0xb1f8_1c72_eb0f_e8c6,
+                0x1e45_e194_b169_36c5,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x74ce_4aef_c57f_beb3,
+                0x69c4_f6ac_1867_ed11,
+                0x0a51_af45_2931_8b62,
+                0x28c8_b58c_9e4c_fb83,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd135_5c2c_41b9_3016,
+                0x46fc_3520_8893_67ee,
+                0x9e5a_afd4_cd28_c267,
+                0x255b_293f_cb1b_e27d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x5c94_da70_4155_44fe,
+                0xcf40_da77_5a54_c35d,
+                0x7835_365b_bfa6_6f6e,
+                0x1db1_367a_8e37_c559,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xa9fe_d7ca_34c5_b4ce,
+                0xdf61_2865_78a6_9989,
+                0x5edc_683b_9db7_4f08,
+                0x0cdd_9795_1c2f_0b88,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6ab3_ba29_c573_06c9,
+                0xcca8_a912_b7ec_2857,
+                0x745c_6dd3_630e_c5a4,
+                0x0e4d_4e42_cacb_9fa6,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xdada_0bae_3c7b_3af6,
+                0x02d9_0ef2_137d_1905,
+                0xe351_6417_4475_0d47,
+                0x2141_a925_c279_e4c4,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xdc50_cb04_2a54_d167,
+                0x1119_cc50_648c_e401,
+                0x9c20_597c_fbfe_4902,
+                0x08f9_f9b3_abc7_9ed8,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x952b_9935_3df2_b236,
+                0xdb24_c8d4_763f_a042,
+                0xcd94_5c9f_0eb7_d4ea,
+                0x06af_6c07_2313_d868,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd3ea_e4ac_812b_79d1,
+                0x55cb_f9ba_301f_878e,
+                0xf376_d1fa_0c5e_6e69,
+                0x08b4_3c97_bf4a_40b4,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x2420_e2ab_e161_15a7,
+                0x3985_2377_4465_2003,
+                0x34a7_14e6_c5b7_a4f8,
+                0x11e0_e607_ac67_81ca,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xeb4d_e008_b2c0_0da6,
+                0x3bff_751e_7b65_1152,
+                0xcb7e_6b1c_30ca_428b,
+                0x2dd7_f0e1_b303_f425,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xa412_8b34_9812_dda8,
+                0xee84_349a_aadb_71ea,
+                0x68b9_1712_0f99_3ae3,
+                0x107d_7f92_d1c6_a240,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xcd82_0a78_61ae_1463,
+                0x23c7_e16d_d982_f66d,
+                0xdb11_66c6_4ed2_fc32,
+                0x10ab_9e8e_4ec9_ad5f,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x4d7d_e1d2_09d2_5cb1,
+                0x1739_7713_3035_44a8,
+                0x4ccc_7299_8446_8a73,
+                0x3000_d3b3_ddb3_fb86,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf200_fa27_a0da_6ebd,
+                0xc4a0_c6b2_debc_2dc8,
+                0xb3ee_de80_99a1_dc45,
+                0x1761_0359_e624_e7fe,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x982d_24ad_2a94_4eb8,
+                0x01a8_439b_65d7_4df5,
+                0xac04_f6c2_7087_94cd,
+                0x2aff_ce5b_7a7d_8c5a,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x65e5_895c_a61c_6e09,
+                0x4408_d5fb_69e1_f649,
+                0xed32_658b_d21f_c560,
+                0x2d43_9fbe_fe8d_2b65,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf68a_7935_cd19_3ab9,
+                0xb3cf_c432_0de3_63a3,
+                0xd72a_286d_4938_540d,
+                0x0b51_cc2a_1b1c_329f,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd5e2_695c_43bd_3247,
+                0x3185_9632_72f3_4c51,
+                0xbafe_78af_8c24_656a,
+                0x1468_ea28_68d3_1f7e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xed3f_e194_6487_9b95,
+                0xb662_a0ec_5797_fdd4,
+                0xfa7b_d734_920e_9be7,
+                0x2955_3949_324f_27f4,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x18d2_cfb4_2834_3afa,
+                0x7200_47a7_f9d1_672d,
+                0x977f_d4b8_6329_f6cd,
+                0x0d97_80ee_7d39_5f7c,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x8036_de18_f17c_b31d,
+                0x487f_4716_831d_fff7,
+                0x3997_860e_2201_06c7,
+                0x210a_098a_fd45_1fca,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x0dda_87a0_dd90_ad81,
+                0x5a2b_2d97_c571_889a,
+                0x73d6_ef5a_f88c_d5b7,
+                0x1ced_cfe2_751b_360e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x0de2_b047_880a_ee03,
+                0x40a3_04f3_6486_72f9,
+                0xbd45_a619_e918_36c3,
+                0x2271_9f26_e167_23c1,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc135_0597_be00_0be2,
+                0xef6a_d663_46e0_4613,
+                0x9f67_0f19_c749_0f8e,
+                0x0ae0_cec9_59c4_c785,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3b1c_515e_c64b_0727,
+                0xa16c_d03e_dda2_e039,
+                0xb731_6c23_8bc3_8f20,
+                0x1775_60de_7314_82bd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x75ae_f0b9_8e81_6a40,
+                0x14ed_f0aa_d6f7_a7f1,
+                0xb485_0a4f_458a_dd55,
+                0x1f19_a267_da0b_8fc2,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd377_0e77_24c0_9f31,
+                0x6f39_8051_ae18_70af,
+                0xb545_0903_1ea2_888f,
+                0x1da7_7cde_ff9c_f822,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3eca_a764_f48f_17a8,
+                0x7ba3_38cf_d43e_c9b3,
+                0x665a_8699_7025_5c26,
+                0x02d1_e17a_aaa9_6743,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6c14_6700_2432_9d12,
+                0xae5c_8687_7a8f_8226,
+                0xc980_316e_a26e_f66f,
+                0x0c37_6a8f_f1da_39de,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x2495_a69c_5668_0a79,
+                0xa783_db5c_ad8e_3f42,
+                0x7b39_560a_deb3_c7a9,
+                0x00bb_8560_48ef_43a7,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xb200_6165_6006_c19a,
+                0x532c_88d2_9ad6_52cb,
+                0xb2c9_9b64_03f3_ac4a,
+                0x1d81_808a_3c73_be1b,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xafd3_223c_354a_dd62,
+                0x81e4_45d4_409a_0e2a,
+                0xd24c_c590_0df8_ff0b,
+                0x15e5_2ae8_3a9d_b363,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x751a_bb88_8520_3ad4,
+                0x159b_e533_354f_fb88,
+                0x37f7_d88e_f66f_765a,
+                0x2aa2_fc03_cb5f_72e2,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x7c77_89b0_3ac9_32c0,
+                0xbc35_f419_92b1_062a,
+                0xdace_5c69_546d_0890,
+                0x10af_edad_881a_1da7,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x75fe_2d8c_882c_858a,
+                0xad22_a6e7_d4f6_bde8,
+                0x4042_446e_947d_fedf,
+                0x1d18_1937_0197_9ad2,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc450_dd96_d7b2_4f36,
+                0x001c_d417_061e_826b,
+                0x770b_eb03_00a0_9fc7,
+                0x02f0_b845_7690_e4d9,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x8da1_8ff9_d981_effb,
+                0x53b8_b984_8be3_062f,
+                0x6870_e0b0_2b97_76bc,
+                0x0dc3_1897_d61d_70e1,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x9ebb_0d5d_7366_3525,
+                0x69c1_4120_0fd4_6bdc,
+                0xdad3_a14a_7603_e3cb,
+                0x0203_c94b_b769_4f9c,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x26fe_8b09_f1af_c81f,
+                0x10eb_985d_cc81_af99,
+                0xcdaa_3c84_3729_33e6,
+                0x2b77_8a23_1f21_f8a6,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd98b_2138_c8c9_d5d2,
+                0xfaa7_e359_db11_1e84,
+                0x6a9d_5272_a246_6a28,
+                0x2c8a_0376_c766_055e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x9138_fe44_aaa8_c778,
+                0xce4a_d01e_095f_94f4,
+                0xe16b_a590_ae0c_d49f,
+                0x2ffd_a33f_5b85_a4fd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x1578_9bbe_1544_4844,
+                0x6cb2_7f44_067f_b58d,
+                0x2a7f_4d40_614f_72fc,
+                0x0318_af53_cfd8_22ec,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3ba6_f884_bd72_06cc,
+                0xce9f_8910_b6fa_8a4a,
+                0x4e74_36a4_ecef_2f91,
+                0x0d24_9974_65c5_b23d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf9a3_3577_a3d2_a658,
+                0x6bec_fa66_b3c5_e47f,
+                0x5d64_8e42_880b_396a,
+                0x01ea_39e4_d9ed_ebf6,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x92fd_a1ee_8b50_f2ff,
+                0x7735_cf5e_bdbd_d4a0,
+                0xd9ed_e9bd_cf11_268f,
+                0x15e4_e016_c94a_026f,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6e75_7b00_392e_ac9d,
+                0xc4db_d049_73fb_3ef7,
+                0x2a82_fd43_fe4b_b145,
+                0x2ef1_85c4_b1cd_b707,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xb07a_b81c_7143_5902,
+                0x4f1d_7d1e_3bb0_204c,
+                0x7d1d_d3d9_2255_beb5,
+                0x15ce_dc8a_4ef6_f701,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xeaf5_ce06_45e5_749a,
+                0x8219_5657_3368_4494,
+                0xc117_b111_270f_1c2c,
+                0x0dbf_148e_e898_3db0,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x0880_5ea2_b4c4_8aab,
+                0xed38_0b99_ffc4_2dfb,
+                0xb319_b1ef_e6b3_eabc,
+                0x2842_f44d_dc05_dbd5,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xb62e_1ebf_3298_af2c,
+                0x8426_fdf5_f1e4_fb8b,
+                0x1b7e_fb2e_124e_7502,
+                0x1aa0_7e7f_fa4a_036f,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6565_61eb_96d2_5d32,
+                0x09f0_17fd_43e9_2296,
+                0xa8ba_97ea_683b_605d,
+                0x1e5a_2abd_ed8c_7022,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x8a2a_0cfb_c028_d2af,
+                0xf11e_8cc1_dea2_23e9,
+                0xbc3d_36b0_cb73_de6d,
+                0x0acf_3ab0_2c01_8573,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6105_f7ff_1bea_bb29,
+                0x4f94_9202_1226_73b0,
+                0x5fcc_6df1_f02d_c416,
+                0x1bab_48ad_2c31_dcec,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd11a_38cc_e6a7_f31c,
+                0x04b5_89a4_a8b3_58de,
+                0xdc06_a2a4_c696_7dc0,
+                0x01a3_6af3_ad1c_61cd,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xd3cb_69a5_84e0_d8d2,
+                0xa678_8cb5_b480_5c9c,
+                0x536f_a643_a66e_1d2f,
+                0x125e_0e82_2514_cf49,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc93d_7d0f_17bf_cbd3,
+                0xb142_434a_14d5_d4f8,
+                0x71d6_3940_d8d6_89f9,
+                0x085b_e848_6ecb_3dbf,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x58f1_1e0d_506b_17e3,
+                0x02d5_db98_22ab_63b0,
+                0x2fee_ad8b_c71a_e9c0,
+                0x2756_5136_0d88_063b,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf781_5b24_7b22_8a6f,
+                0x89db_1e1f_9f62_0c18,
+                0xecc2_1446_ed94_c802,
+                0x0f7e_b245_596d_9ac5,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf0fc_2fb1_03ad_549c,
+                0xd887_c8bb_8809_1513,
+                0xbc67_f767_7ab4_59c9,
+                0x2218_62a0_4a00_f406,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x137d_bd47_fc26_e3a2,
+                0x5cda_7eb1_d4ab_7e70,
+                0xcf0a_588a_5fad_f870,
+                0x1bd5_7560_3b70_85af,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x4158_a6f6_2db4_46f1,
+                0x3107_9de6_1e67_184c,
+                0x2db8_575d_986a_82e9,
+                0x146d_1d17_6245_ff77,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x2ef4_fa72_8da4_cd3a,
+                0x8d39_2d59_cb12_a42e,
+                0x7493_9366_4f02_4d57,
+                0x0220_2ec1_e7fd_85c4,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xa730_9c69_7037_1421,
+                0x46b5_dfdb_9dd5_ba26,
+                0xff0a_b3e1_2a12_03c8,
+                0x01c0_e726_93a0_e12b,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xc079_48de_0560_71e0,
+                0x14a5_85db_f5ad_9758,
+                0xcbb0_bd83_3781_63ab,
+                0x2b3d_8733_bb48_72b6,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x8de0_fdc8_7a29_640e,
+                0x4fa3_4643_a429_cfdd,
+                0x575b_9d3d_cee6_332f,
+                0x185d_12aa_30ae_d1a0,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3593_a9a7_7675_f641,
+                0xe1c6_0ec6_dd94_4dd3,
+                0xe29b_d3df_c35d_fcc8,
+                0x1b39_4227_301f_28be,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x41eb_3a32_5f22_65e7,
+                0xf488_54b4_a496_537d,
+                0xf6c0_8a3d_eb11_4a4f,
+                0x15b1_cfd5_22bd_c418,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xdd5a_0713_ff4c_bbb8,
+                0x6198_00d2_da15_da01,
+                0x30a9_db0a_16b5_9abc,
+                0x0bca_3aef_4683_3e8a,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xe7af_2125_00f5_e6ca,
+                0x8531_5001_5e2e_6b4c,
+                0xa2c2_f8c7_cf4a_dff0,
+                0x0529_0625_96e5_1b8c,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x6105_dcfc_31fd_ef60,
+                0xd150_d022_be29_82eb,
+                0xa736_6a45_da1e_847c,
+                0x02fd_a517_f426_1325,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x154a_1539_d12d_d1cf,
+                0x64db_993e_8d86_29b7,
+                0x5b7f_f9d2_fbab_64d2,
+                0x1fec_5a09_cea4_d25e,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x3b74_d1a9_a42e_b1bb,
+                0x8317_17fa_3f8e_0194,
+                0xe560_5094_751a_f17b,
+                0x2d87_776e_ef5d_fabb,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x8f68_c39f_9f01_ff19,
+                0x67af_a418_8c46_8495,
+                0x5aeb_d0af_b9be_5849,
+                0x0d25_7a43_7910_f399,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0x66f8_d728_9716_aee4,
+                0xe1c3_2fc2_4a9f_0b60,
+                0xbcba_c004_f907_99e6,
+                0x098f_0aa0_6092_ed2c,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xbcec_a70b_d2af_7ad5,
+                0xaf07_f38a_f8c9_52a7,
+                0xec10_3453_51a2_3a3a,
+                0x066f_6f85_d6f6_8a85,
+            ]),
+            Fp::from_raw([
+                0xf573_f431_221f_8ff9,
+                0xb6c0_9d55_7013_fff1,
+                0x2bf6_7a44_93cc_262f,
+                0x0cc5_7cdb_b085_07d6,
+            ]),
+        ],
+    },
+];
@@ -0,0 +1,4252 @@
+//! This file was generated by running generate_params.py
+//! Parameters for using width 3 (rate 2) Poseidon with the BN256 field,
+//! with 8 full rounds and 57 partial rounds:
+//!
+//! ```text
+//! $ python3 generate_params.py 3 8 57
+//! ```
+use super::SparseMds;
+use halo2curves::bn256::Fr as Fp;
+pub(crate) const ROUND_CONSTANTS: [[Fp; 3]; 65] = [
+    [
+        Fp::from_raw([
+            0x8d21_d473_04cd_8e6e,
+            0x14c4_993c_11bb_2993,
+            0xd059_86d6_56f4_0c21,
+            0x0ee9_a592_ba9a_9518,
+        ]),
+        Fp::from_raw([
+            0x5696_fff4_0956_e864,
+            0x887b_08d4_d008_68df,
+            0x5986_5871_69fc_1bcd,
+            0x00f1_4452_35f2_148c,
+        ]),
+        Fp::from_raw([
+            0xe879_f389_0ecf_73f5,
+            0x30c7_2873_0b7a_b36c,
+            0x1f29_a058_d0fa_80b9,
+            0x08df_f348_7e8a_c99e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2096_6310_fadc_01d0,
+            0x56c3_5342_c84b_da6e,
+            0xc3ce_28f7_532b_13c8,
+            0x2f27_be69_0fda_ee46,
+        ]),
+        Fp::from_raw([
+            0x8b83_27be_bca1_6cf2,
+            0xb763_fe04_b804_3ee4,
+            0x2416_bebf_3d4f_6234,
+            0x2b2a_e1ac_f68b_7b8d,
+        ]),
+        Fp::from_raw([
+            0xe64b_44c7_dbf1_1cfa,
+            0x5952_c175_ab6b_03ea,
+            0xcca5_eac0_6f97_d4d5,
+            0x0319_d062_072b_ef7e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8ef7_b387_bf28_526d,
+            0xc8b7_bf27_ad49_c629,
+            0x8a37_6df8_7af4_a63b,
+            0x2881_3dca_ebae_aa82,
+        ]),
+        Fp::from_raw([
+            0x1509_28ad_ddf9_cb78,
+            0x2033_8652_00c3_52bc,
+            0xf181_bf38_e1c1_d40d,
+            0x2727_673b_2ccb_c903,
+        ]),
+        Fp::from_raw([
+            0xb8fb_9e31_e65c_c632,
+            0x6efb_d43e_3405_87d6,
+            0xe74a_bd2b_2a14_94cd,
+            0x234e_c45c_a277_27c2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xcd99_ff6e_8797_d428,
+            0xab10_a815_0a33_7b1c,
+            0x7f86_2cb2_cf7c_f760,
+            0x15b5_2534_031a_e18f,
+        ]),
+        Fp::from_raw([
+            0xd701_d4ee_cf68_d1f6,
+            0x8e0e_8a8d_1b58_b132,
+            0x5ed9_a3d1_86b7_9ce3,
+            0x0dc8_fad6_d9e4_b35f,
+        ]),
+        Fp::from_raw([
+            0x9780_5518_a47e_4d9c,
+            0xea4e_b378_f62e_1fec,
+            0x600f_705f_ad3f_b567,
+            0x1bcd_95ff_c211_fbca,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x17cb_978d_069d_e559,
+            0xc76d_a36c_2578_9378,
+            0xe9ef_f81b_016f_c34d,
+            0x1052_0b0a_b721_cadf,
+        ]),
+        Fp::from_raw([
+            0xe88a_9eb8_1f56_27f6,
+            0x2932_4980_75fe_d0ac,
+            0x9b25_7d8e_d5fb_baf4,
+            0x1f6d_4814_9b8e_7f7d,
+        ]),
+        Fp::from_raw([
+            0xca34_bdb5_460c_8705,
+            0xfff8_dc1c_816f_0dc9,
+            0xd29e_00ef_35a2_089b,
+            0x1d96_55f6_5230_9014,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8fe3_d418_5697_cc7d,
+            0xa731_ff67_e470_3205,
+            0xb051_f7b1_cd43_a99b,
+            0x04df_5a56_ff95_bcaf,
+        ]),
+        Fp::from_raw([
+            0xf6ec_282b_6e4b_e828,
+            0x8690_a10a_8c84_24a7,
+            0x151b_3d29_0ced_af14,
+            0x0672_d995_f8ff_f640,
+        ]),
+        Fp::from_raw([
+            0x9fc1_d820_9b5c_75b9,
+            0x0c9a_9dcc_06f2_708e,
+            0xb212_00d7_ffaf_dd5f,
+            0x0999_52b4_1488_4454,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x83fd_0e84_3a6b_9fa6,
+            0x48e4_3586_a9b4_cd91,
+            0x7c48_3143_ba8d_4694,
+            0x052c_ba22_55df_d00c,
+        ]),
+        Fp::from_raw([
+            0x1607_7cb9_3c46_4ddc,
+            0x82de_5570_7251_ad77,
+            0xb0bd_7471_2b79_99af,
+            0x0b8b_adee_690a_db8e,
+        ]),
+        Fp::from_raw([
+            0xb963_d0a8_e4b2_bdd1,
+            0x49c1_5d60_683a_8050,
+            0x5a1e_e651_020c_07c7,
+            0x119b_1590_f133_07af,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xce15_be0b_fb4a_8d09,
+            0x2c4a_cfc8_84ef_4ee5,
+            0x2529_d36b_e0f6_7b83,
+            0x0315_0b7c_d6d5_d17b,
+        ]),
+        Fp::from_raw([
+            0xbe69_cb31_7c9e_a565,
+            0x5374_efb8_3d80_898a,
+            0x3cf1_951f_1739_1235,
+            0x2cc6_182c_5e14_546e,
+        ]),
+        Fp::from_raw([
+            0x92d2_cd73_111b_f0f9,
+            0x4218_cade_dac1_4e2b,
+            0x50cf_e129_a404_b376,
+            0x0050_3255_1e63_78c4,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x88f9_da2c_c282_76b5,
+            0x6469_c399_fcc0_69fb,
+            0xbb14_7e97_2ebc_b951,
+            0x2332_37e3_289b_aa34,
+        ]),
+        Fp::from_raw([
+            0xe80c_2d4c_24d6_0280,
+            0x2303_7f21_b34a_e5a4,
+            0xc980_d316_74bf_be63,
+            0x05c8_f4f4_ebd4_a6e3,
+        ]),
+        Fp::from_raw([
+            0xee1f_09b2_590f_c65b,
+            0x52bc_f35e_f3ae_ed91,
+            0xba05_d818_a319_f252,
+            0x0a7b_1db1_3042_d396,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5df5_4236_5a40_4ec0,
+            0xf156_e2b0_86ff_47dc,
+            0xb142_9657_2c9d_32db,
+            0x2a73_b71f_9b21_0cf5,
+        ]),
+        Fp::from_raw([
+            0x76a7_60bb_5c50_c460,
+            0xec18_f2c4_dbe7_f229,
+            0x9351_07e9_ffc9_1dc3,
+            0x1ac9_b041_7abc_c9a1,
+        ]),
+        Fp::from_raw([
+            0x9015_ee04_6dc9_3fc0,
+            0x269f_3e4d_6cb1_0434,
+            0x3fab_b076_707e_f479,
+            0x12c0_339a_e083_7482,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8246_682e_56e9_a28e,
+            0x5290_0aa3_253b_aac6,
+            0x7f5b_18db_4e1e_704f,
+            0x0b74_75b1_02a1_65ad,
+        ]),
+        Fp::from_raw([
+            0x32ab_3aa8_8d7f_8448,
+            0x7c84_3e37_9366_f2ea,
+            0xdb1c_5e49_f6e8_b891,
+            0x037c_2849_e191_ca3e,
+        ]),
+        Fp::from_raw([
+            0x45fd_b176_a716_346f,
+            0xd520_6c5c_93a0_7dc1,
+            0xe926_7466_1e21_7e9b,
+            0x05a6_811f_8556_f014,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7b67_5ef5_f38b_d66e,
+            0x4076_e87a_7b28_83b4,
+            0x6e94_7b75_d54e_9f04,
+            0x29a7_95e7_d980_2894,
+        ]),
+        Fp::from_raw([
+            0x507b_e199_981f_d22f,
+            0x6e8c_7382_c8a1_585c,
+            0x45a3_857a_fc18_f582,
+            0x2043_9a0c_84b3_22eb,
+        ]),
+        Fp::from_raw([
+            0x4a2a_6f2a_0982_c887,
+            0xbb50_f277_99a8_4b6d,
+            0x94ec_2050_c737_1ff1,
+            0x2e0b_a8d9_4d9e_cf4a,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe6d0_ddcc_a17d_71c8,
+            0x1782_2cd2_1090_48d2,
+            0xca38_eb7c_ce82_2b45,
+            0x143f_d115_ce08_fb27,
+        ]),
+        Fp::from_raw([
+            0xc843_2362_3be9_caf1,
+            0xf861_1659_323d_bcbf,
+            0x5796_8dbb_dcf8_13cd,
+            0x0c64_cbec_b1c7_34b8,
+        ]),
+        Fp::from_raw([
+            0xf142_6cef_9403_da53,
+            0xe74f_348d_62c2_b670,
+            0x46fc_a925_c163_ff5a,
+            0x028a_3058_47c6_83f6,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x24d6_755b_5db9_e30c,
+            0x6a6b_cb64_d894_27b8,
+            0x5fa9_40ab_4c43_80f2,
+            0x2e4e_f510_ff0b_6fda,
+        ]),
+        Fp::from_raw([
+            0xb963_84f5_0579_400e,
+            0x8925_b4f6_d033_b078,
+            0x63d7_9270_c956_ce3b,
+            0x0081_c95b_c433_84e6,
+        ]),
+        Fp::from_raw([
+            0xba8a_9f40_23a0_bb38,
+            0xe249_1b34_9c03_9a0b,
+            0x187e_2fad_e687_e05e,
+            0x2ed5_f0c9_1cbd_9749,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x990f_01f3_3a73_5206,
+            0x3448_a22c_7623_4c8c,
+            0x4bbf_374e_d5aa_e2f0,
+            0x3050_9991_f88d_a350,
+        ]),
+        Fp::from_raw([
+            0xa752_9094_424e_c6ad,
+            0xf0a1_119f_b206_7b41,
+            0x221b_7c4d_49a3_56b9,
+            0x1c3f_20fd_5540_9a53,
+        ]),
+        Fp::from_raw([
+            0x1708_87b4_7ddc_b96c,
+            0xc46b_b221_3e8e_131e,
+            0x0495_1445_9b6e_18ee,
+            0x10b4_e7f3_ab5d_f003,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x039a_a350_2e43_adef,
+            0xdd80_f804_c077_d775,
+            0x3ddd_543d_891c_2abd,
+            0x2a19_8297_9c3f_f7f4,
+        ]),
+        Fp::from_raw([
+            0x5cad_0f13_15bd_5c91,
+            0xba43_1ebc_396c_9af9,
+            0xfedd_bead_56d6_d55d,
+            0x1c74_ee64_f15e_1db6,
+        ]),
+        Fp::from_raw([
+            0x9c2f_e45a_0ae1_46a0,
+            0x9e4f_2e8b_8270_8cfa,
+            0xeab9_303c_ace0_1b4b,
+            0x0753_3ec8_50ba_7f98,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8a11_abf3_764c_0750,
+            0x285c_68f4_2d42_c180,
+            0xa151_e4ee_af17_b154,
+            0x2157_6b43_8e50_0449,
+        ]),
+        Fp::from_raw([
+            0x743d_6930_836d_4a9e,
+            0xbce8_384c_815f_0906,
+            0x08ad_5ca1_93d6_2f10,
+            0x2f17_c055_9b8f_e796,
+        ]),
+        Fp::from_raw([
+            0xe665_b0b1_b7e2_730e,
+            0x9775_a420_1318_474a,
+            0xa79e_8aae_9461_70bc,
+            0x2d47_7e38_62d0_7708,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd89b_e0f5_b274_7eab,
+            0xafba_2266_c38f_5abc,
+            0x90e0_9557_7984_f291,
+            0x162f_5243_9670_64c3,
+        ]),
+        Fp::from_raw([
+            0x7777_a700_9239_3311,
+            0xd7a8_596a_87f2_9f8a,
+            0x264e_cd2c_8ae5_0d1a,
+            0x2b4c_b233_ede9_ba48,
+        ]),
+        Fp::from_raw([
+            0x4254_e7c3_5e03_b07a,
+            0x6db2_eece_6d85_c4cf,
+            0x1dba_f8f4_6228_5477,
+            0x2c8f_bcb2_dd85_73dc,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe5e8_8db8_7094_9da9,
+            0x9e1b_61e9_f601_e9ad,
+            0xf2ff_453f_0cd5_6b19,
+            0x1d6f_3477_25e4_816a,
+        ]),
+        Fp::from_raw([
+            0x4cd4_9af5_c456_5529,
+            0xf9e6_ac02_b68d_3132,
+            0xebc2_d8b3_df5b_913d,
+            0x204b_0c39_7f4e_be71,
+        ]),
+        Fp::from_raw([
+            0x4ff8_fb75_bc79_c502,
+            0x9ecb_827c_d7dc_2553,
+            0x4f11_49b3_c63c_3c2f,
+            0x0c4c_b9dc_3c4f_d817,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x9a61_6ddc_45bc_7b54,
+            0x1e5c_4947_5279_e063,
+            0xa254_1647_4f49_3030,
+            0x174a_d61a_1448_c899,
+        ]),
+        Fp::from_raw([
+            0x3a98_16d4_9a38_d2ef,
+            0xeaaa_28c1_77cc_0fa1,
+            0xf759_df4e_c2f3_cde2,
+            0x1a96_177b_cf4d_8d89,
+        ]),
+        Fp::from_raw([
+            0x8242_ace3_60b8_a30a,
+            0x0520_2c12_6a23_3c1a,
+            0xd0ef_8054_bc60_c4ff,
+            0x066d_04b2_4331_d71c,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x2703_7a62_aa1b_d804,
+            0x381c_c65f_72e0_2ad5,
+            0x2195_7828_71c6_dd3b,
+            0x2a4c_4fc6_ec0b_0cf5,
+        ]),
+        Fp::from_raw([
+            0xe55a_fc01_219f_d649,
+            0x5e72_7f84_46f6_d9d7,
+            0x47e9_f2e1_4a7c_edc9,
+            0x13ab_2d13_6ccf_37d4,
+        ]),
+        Fp::from_raw([
+            0x4c2e_3e86_9acc_6a9a,
+            0xc1b0_4fce_c26f_5519,
+            0x19d2_4d84_3dc8_2769,
+            0x1121_552f_ca26_0616,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x09a5_546c_7c97_cff1,
+            0xa6cd_267d_595c_4a89,
+            0x889b_c817_15c3_7d77,
+            0x00ef_6533_22b1_3d6c,
+        ]),
+        Fp::from_raw([
+            0x845a_ca35_d8a3_97d3,
+            0x400c_776d_6525_95d9,
+            0x8b26_1d8b_a740_51e6,
+            0x0e25_483e_45a6_6520,
+        ]),
+        Fp::from_raw([
+            0x4644_8db9_79ee_ba89,
+            0x395a_c3d4_dde9_2d8c,
+            0x2452_6465_9e15_d88e,
+            0x29f5_36dc_b9dd_7682,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0e45_6baa_ce0f_a5be,
+            0x5a12_4e27_80bb_ea17,
+            0xdfda_3357_5dbd_bd88,
+            0x2a56_ef9f_2c53_feba,
+        ]),
+        Fp::from_raw([
+            0xee41_6240_a8cb_9af1,
+            0xf2ae_2999_a467_62e8,
+            0xecfb_7a2d_17b5_c409,
+            0x1c83_61c7_8eb5_cf5d,
+        ]),
+        Fp::from_raw([
+            0xd3d0_ab4b_e743_19c5,
+            0x83e8_e68a_7645_07bf,
+            0xc047_3089_aaf0_206b,
+            0x151a_ff5f_38b2_0a0f,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe76e_4761_5b51_f100,
+            0xa9f5_2fc8_c8b6_cdd1,
+            0xc1b2_39c8_8f7f_9d43,
+            0x04c6_187e_41ed_881d,
+        ]),
+        Fp::from_raw([
+            0x9e80_1b7d_dc9c_2967,
+            0x4b81_c61e_d157_7644,
+            0x10d8_4331_f6fb_6d53,
+            0x13b3_7bd8_0f4d_27fb,
+        ]),
+        Fp::from_raw([
+            0x9321_ceb1_c4e8_a8e4,
+            0x2ce3_664c_2a52_032c,
+            0xf578_bfbd_32c1_7b7a,
+            0x01a5_c536_273c_2d9d,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8322_3906_5b7c_3b02,
+            0x4a9a_2c66_6b97_26da,
+            0x5ad0_5f5d_7acb_950b,
+            0x2ab3_5618_34ca_7383,
+        ]),
+        Fp::from_raw([
+            0x9f7e_d516_a597_b646,
+            0xacaf_6af4_e95d_3bf6,
+            0x200f_e6d6_86c0_d613,
+            0x1d4d_8ec2_91e7_20db,
+        ]),
+        Fp::from_raw([
+            0x1514_c9c8_0b65_af1d,
+            0xb925_3512_40a0_4b71,
+            0x8f57_84fe_7919_fd2b,
+            0x0412_94d2_cc48_4d22,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0429_71dd_90e8_1fc6,
+            0x98f5_7939_d126_e392,
+            0x1c4f_a715_991f_0048,
+            0x154a_c98e_0170_8c61,
+        ]),
+        Fp::from_raw([
+            0x4524_563b_c6ea_4da4,
+            0x50b3_684c_88f8_b0b0,
+            0x3eed_d840_93ae_f510,
+            0x0b33_9d8a_cca7_d4f8,
+        ]),
+        Fp::from_raw([
+            0x81ed_95b5_0839_c82e,
+            0x98f0_e71e_aff4_a7dd,
+            0x54a4_f84c_fbab_3445,
+            0x0955_e49e_6610_c942,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3525_401e_a065_4626,
+            0xa9a6_f41e_6f53_5c6f,
+            0x26b9_e222_06f1_5abc,
+            0x0674_6a61_56eb_a544,
+        ]),
+        Fp::from_raw([
+            0xac91_7c7f_f320_77fb,
+            0x38e5_790e_2bd0_a196,
+            0x496f_3820_c549_c278,
+            0x0f18_f5a0_ecd1_423c,
+        ]),
+        Fp::from_raw([
+            0x2a73_8223_d6f7_6e13,
+            0x4bb5_6358_3ede_7bc9,
+            0x8ac5_9eff_5beb_261e,
+            0x04f6_eeca_1751_f730,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xc176_8d26_fc0b_3758,
+            0x8811_eb11_6fb3_e45b,
+            0xc1a3_ec4d_a3cd_ce03,
+            0x2b56_9733_64c4_c4f5,
+        ]),
+        Fp::from_raw([
+            0x83fe_b65d_437f_29ef,
+            0x8e13_92b3_8571_6a5d,
+            0xdcd7_6b89_804b_1bcb,
+            0x1237_69dd_49d5_b054,
+        ]),
+        Fp::from_raw([
+            0x9425_7b2f_b01c_63e9,
+            0xa989_f644_6471_1509,
+            0x88ee_52b9_1169_aace,
+            0x2147_b424_fc48_c80a,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xea54_ad89_7ceb_e54d,
+            0x647e_6f34_ad42_43c2,
+            0x1a6c_5505_ea33_2a29,
+            0x0fdc_1f58_548b_8570,
+        ]),
+        Fp::from_raw([
+            0x944f_685c_c0a0_b1f2,
+            0xbcef_f28c_5dbb_e0c3,
+            0xdf68_abcf_0f77_86d4,
+            0x1237_3a82_51fe_a004,
+        ]),
+        Fp::from_raw([
+            0xdd8a_1f35_c1a9_0035,
+            0xa642_756b_6af4_4203,
+            0xad7e_a52f_f742_c9e8,
+            0x21e4_f4ea_5f35_f85b,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8a81_934f_1bc3_b147,
+            0xb573_6649_2f45_e90d,
+            0xdfb4_7222_24d4_c462,
+            0x1624_3916_d69d_2ca3,
+        ]),
+        Fp::from_raw([
+            0xa13a_4159_cac0_4ac2,
+            0xabc2_1566_e1a0_453c,
+            0xf66f_9adb_c88b_4378,
+            0x1efb_e46d_d7a5_78b4,
+        ]),
+        Fp::from_raw([
+            0x3b67_2cc9_6a88_969a,
+            0xd468_d552_5be6_6f85,
+            0x8886_020e_23a7_f387,
+            0x07ea_5e85_37cf_5dd0,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa9fe_16c0_b76c_00bc,
+            0x650f_19a7_5e7c_e11c,
+            0xb7b4_78a3_0f9a_5b63,
+            0x05a8_c4f9_968b_8aa3,
+        ]),
+        Fp::from_raw([
+            0x2d9d_57b7_2a32_e83f,
+            0x3f78_18c7_01b9_c788,
+            0xfbfe_59bd_345e_8dac,
+            0x20f0_5771_2cc2_1654,
+        ]),
+        Fp::from_raw([
+            0x9bd9_0b33_eb33_db69,
+            0x6dcd_8e88_d01d_4901,
+            0x9672_f8c6_7fee_3163,
+            0x04a1_2ede_da9d_fd68,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe49e_c954_4ccd_101a,
+            0xbd13_6ce5_091a_6767,
+            0xe44f_1e54_25a5_1dec,
+            0x27e8_8d8c_15f3_7dce,
+        ]),
+        Fp::from_raw([
+            0x176c_41ee_433d_e4d1,
+            0x6e09_6619_a770_3223,
+            0xb8a5_c8c5_e95a_41f6,
+            0x2fee_d17b_8428_5ed9,
+        ]),
+        Fp::from_raw([
+            0x6972_b8bd_53af_f2b8,
+            0x94e5_9429_1131_2a0d,
+            0x4042_4142_0f72_9cf3,
+            0x1ed7_cc76_edf4_5c7c,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xdf28_74be_4546_6b1a,
+            0xac67_8347_6144_cdca,
+            0x157f_f8c5_86f5_660e,
+            0x1574_2e99_b9bf_a323,
+        ]),
+        Fp::from_raw([
+            0x284f_033f_27d0_c785,
+            0x7710_7454_c6ec_0317,
+            0xc895_fc68_87dd_f405,
+            0x1aac_2853_87f6_5e82,
+        ]),
+        Fp::from_raw([
+            0xec75_a965_54d6_7c77,
+            0x832e_2e7a_4977_5f71,
+            0xf9dd_adbd_b605_7357,
+            0x2585_1c3c_845d_4790,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0ddc_cc3d_9f14_6a67,
+            0x53b7_ebba_2c55_2337,
+            0xce78_457d_b197_edf3,
+            0x15a5_8215_65cc_2ec2,
+        ]),
+        Fp::from_raw([
+            0x2f15_485f_28c7_1727,
+            0xdcf6_4f36_0442_7750,
+            0x0efa_7e31_a1db_5966,
+            0x2411_d57a_4813_b998,
+        ]),
+        Fp::from_raw([
+            0x5882_8b5e_f6cb_4c9b,
+            0x47e9_a98e_12f4_cd25,
+            0x13e3_35b8_c0b6_d2e6,
+            0x002e_6f8d_6520_cd47,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3988_3460_9e03_15d2,
+            0xaf8f_0e91_e2fe_1ed7,
+            0x97da_00b6_16b0_fcd1,
+            0x2ff7_bc8f_4380_cde9,
+        ]),
+        Fp::from_raw([
+            0xe93b_e4fe_bb0d_3cbe,
+            0x2e95_21f6_b7bb_68f1,
+            0x5ee0_2724_471b_cd18,
+            0x00b9_831b_9485_2559,
+        ]),
+        Fp::from_raw([
+            0x7d77_adbf_0c9c_3512,
+            0x1ca4_0864_8a47_43a8,
+            0x8691_3b0e_57c0_4e01,
+            0x0a2f_5376_8b8e_bf6a,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7f2a_2903_05e1_198d,
+            0x0f59_9ff7_e94b_e69b,
+            0x3a47_9f91_ff23_9e96,
+            0x0024_8156_142f_d037,
+        ]),
+        Fp::from_raw([
+            0x50eb_512a_2b2b_cda9,
+            0x3971_96aa_6a54_2c23,
+            0x28cf_8c02_ab3f_0c9a,
+            0x171d_5620_b87b_fb13,
+        ]),
+        Fp::from_raw([
+            0x9d10_45e4_ec34_a808,
+            0x60c9_5217_2dd5_4dd9,
+            0x7008_7c7c_10d6_fad7,
+            0x170a_4f55_536f_7dc9,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x482e_ca17_e2db_fae1,
+            0xcc37_e38c_1cd2_11ba,
+            0x2ef3_134a_ea04_336e,
+            0x29ab_a33f_799f_e66c,
+        ]),
+        Fp::from_raw([
+            0xb5ba_6503_69e6_4973,
+            0xe70d_114a_03f6_a0e8,
+            0xfdd1_bb19_4508_8d47,
+            0x1e9b_c179_a4fd_d758,
+        ]),
+        Fp::from_raw([
+            0x9c9e_1c43_bdaf_8f09,
+            0xfeaa_d869_a9c4_b44f,
+            0x58f7_f489_2dfb_0b5a,
+            0x1dd2_6979_9b66_0fad,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x5d1d_d2cb_0f24_af38,
+            0x7ccd_426f_e869_c7c9,
+            0x4011_81d0_2e15_459e,
+            0x22cd_bc8b_7011_7ad1,
+        ]),
+        Fp::from_raw([
+            0xd5ba_93b9_c7da_cefd,
+            0xfd31_50f5_2ed9_4a7c,
+            0x3a9f_57a5_5c50_3fce,
+            0x0ef0_42e4_5477_1c53,
+        ]),
+        Fp::from_raw([
+            0x3b30_4ffc_a62e_8284,
+            0x1318_e8b0_8a03_59a0,
+            0xf287_f303_6037_e885,
+            0x1160_9e06_ad6c_8fe2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x08b0_8f5b_783a_a9af,
+            0xfecd_58c0_76df_e427,
+            0x9e75_3eea_427c_17b7,
+            0x1166_d9e5_5461_6dba,
+        ]),
+        Fp::from_raw([
+            0xf855_a888_357e_e466,
+            0x177f_bf4c_d2ac_0b56,
+            0x9341_3026_3544_13db,
+            0x2de5_2989_431a_8595,
+        ]),
+        Fp::from_raw([
+            0x74bf_01cf_5f71_e9ad,
+            0xf51a_ee5b_17b8_e89d,
+            0x9a6d_a492_f3a8_ac1d,
+            0x3006_eb4f_fc7a_8581,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x6234_4c82_2514_5086,
+            0x2993_fe8f_0a46_39f9,
+            0xfdcf_6fff_9e3f_6f42,
+            0x2af4_1fbb_61ba_8a80,
+        ]),
+        Fp::from_raw([
+            0x81b2_14ba_ce48_27c3,
+            0x8718_ab27_889e_85e7,
+            0xe5a6_b41a_8ebc_85db,
+            0x119e_684d_e476_155f,
+        ]),
+        Fp::from_raw([
+            0xcff7_84b9_7b3f_d800,
+            0xb512_48c2_3828_f047,
+            0x188b_ea59_ae36_3537,
+            0x1835_b786_e2e8_925e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x6c40_e285_ab32_eeb6,
+            0xd152_bac2_a790_5c92,
+            0x4d79_4996_c643_3a20,
+            0x2820_1a34_c594_dfa3,
+        ]),
+        Fp::from_raw([
+            0x4a76_1f88_c22c_c4e7,
+            0x864c_82eb_5711_8772,
+            0x94e8_0fef_af78_b000,
+            0x083e_fd7a_27d1_7510,
+        ]),
+        Fp::from_raw([
+            0x9e07_9564_f61f_d13b,
+            0x11c1_6df7_774d_d851,
+            0x6158_e61c_eea2_7be8,
+            0x0b6f_88a3_5771_9952,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1439_0e6e_e425_4f5b,
+            0x5895_11ca_00d2_9e10,
+            0x644f_66e1_d647_1a94,
+            0x0ec8_68e6_d15e_51d9,
+        ]),
+        Fp::from_raw([
+            0x00d9_37ab_84c9_8591,
+            0xecd3_e74b_939c_d40d,
+            0x1ac0_c9b3_ed2e_1142,
+            0x2af3_3e3f_8667_7127,
+        ]),
+        Fp::from_raw([
+            0x364c_e5e4_7951_f178,
+            0x3456_8c54_7dd6_858b,
+            0xd09b_5d96_1c6a_ce77,
+            0x0b52_0211_f904_b5e7,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xca22_8620_188a_1d40,
+            0xa0c5_6ac4_270e_822c,
+            0xd8db_58f1_0062_a92e,
+            0x0b2d_722d_0919_a1aa,
+        ]),
+        Fp::from_raw([
+            0xe006_1d1e_d6e5_62d4,
+            0x57b5_4a99_91ca_38bb,
+            0xd980_ceb3_7c24_53e9,
+            0x1f79_0d4d_7f8c_f094,
+        ]),
+        Fp::from_raw([
+            0xda92_ceb0_1e50_4233,
+            0x0885_c162_35a2_a6a8,
+            0xaea9_7cd3_85f7_8015,
+            0x0171_eb95_dfbf_7d1e,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7623_0538_1b16_8873,
+            0x790b_40de_fd2c_8650,
+            0x329b_f688_5da6_6b9b,
+            0x0c2d_0e3b_5fd5_7549,
+        ]),
+        Fp::from_raw([
+            0x5d38_0305_4407_a18d,
+            0x7cbc_afa5_89e2_83c3,
+            0x4e5a_8228_b4e7_2b37,
+            0x1162_fb28_689c_2715,
+        ]),
+        Fp::from_raw([
+            0x1623_ef82_4971_1bc0,
+            0x282c_5a92_a89e_1992,
+            0x64ad_386a_91e8_310f,
+            0x2f14_59b6_5dee_441b,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xc243_f70d_1b53_cfbb,
+            0xbc48_9d46_754e_b712,
+            0x996d_7436_7d5c_d4c1,
+            0x1e6f_f321_6b68_8c3d,
+        ]),
+        Fp::from_raw([
+            0x7688_1f93_2647_8875,
+            0xd741_a6f3_6cdc_2a05,
+            0x6814_87d2_7d15_7802,
+            0x01ca_8be7_3832_b8d0,
+        ]),
+        Fp::from_raw([
+            0x0b9b_5de3_15f9_650e,
+            0x6802_8608_0b10_cea0,
+            0x86f9_76d5_bdf2_23dc,
+            0x1f77_3570_6ffe_9fc5,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x4745_ca83_8285_f019,
+            0x21ac_10a3_d5f0_96ef,
+            0x40a0_c2dc_e041_fba9,
+            0x2522_b60f_4ea3_3076,
+        ]),
+        Fp::from_raw([
+            0x8ce1_6c23_5572_575b,
+            0x3418_cad4_f52b_6c3f,
+            0x5255_075d_dc95_7f83,
+            0x23f0_bee0_01b1_029d,
+        ]),
+        Fp::from_raw([
+            0x66d9_4010_9308_2d59,
+            0x5d14_2633_e9df_905f,
+            0xcaac_2d44_555e_d568,
+            0x2bc1_ae8b_8ddb_b81f,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8011_fcd6_ad72_205f,
+            0x6237_1273_a07b_1fc9,
+            0x7304_507b_8dba_3ed1,
+            0x0f94_06b8_2965_64a3,
+        ]),
+        Fp::from_raw([
+            0xcb12_6c8c_d995_f0a8,
+            0x17e7_5b17_4a52_ee4a,
+            0x67b7_2998_de90_714e,
+            0x2360_a8eb_0cc7_defa,
+        ]),
+        Fp::from_raw([
+            0x6dcb_bc27_67f8_8948,
+            0xb481_5a5e_96df_8b00,
+            0x804c_803c_baef_255e,
+            0x1587_1a5c_ddea_d976,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x4f95_7ccd_eefb_420f,
+            0x362f_4f54_f723_7954,
+            0x0a86_52dd_2f3b_1da0,
+            0x193a_5676_6998_ee9e,
+        ]),
+        Fp::from_raw([
+            0xe430_9805_e777_ae0f,
+            0x3b2e_63c8_ad33_4834,
+            0x2f9b_e56f_f4fa_b170,
+            0x2a39_4a43_934f_8698,
+        ]),
+        Fp::from_raw([
+            0xb416_6e88_76c0_d142,
+            0x892c_d112_2344_3ba7,
+            0x3e8b_635d_cb34_5192,
+            0x1859_954c_feb8_695f,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x408d_3819_f4fe_d32b,
+            0x2b11_bc25_d90b_bdca,
+            0x0134_44db_cb99_f190,
+            0x04e1_1817_6305_0e58,
+        ]),
+        Fp::from_raw([
+            0x1f5e_5552_bfd0_5f23,
+            0xb10e_b82d_b08b_5e8b,
+            0x40c3_35ea_64de_8c5b,
+            0x0fdb_253d_ee83_869d,
+        ]),
+        Fp::from_raw([
+            0xa9d7_c5ba_e9b4_f1c0,
+            0x75f0_8686_f1c0_8984,
+            0xaa4e_fb62_3ade_ad62,
+            0x058c_be8a_9a50_27bd,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd152_28b4_ccec_a59a,
+            0x23b4_b83b_ef02_3ab0,
+            0x497e_adb1_aeb1_f52b,
+            0x1382_edce_9971_e186,
+        ]),
+        Fp::from_raw([
+            0xe1e6_6346_01d9_e8b5,
+            0x7f61_b8eb_99f1_4b77,
+            0x0819_ca51_fd11_b0be,
+            0x0346_4990_f045_c6ee,
+        ]),
+        Fp::from_raw([
+            0xaa5b_c137_aeb7_0a58,
+            0x6fca_b460_5db2_eb5a,
+            0xfff3_3b41_f98f_f83c,
+            0x23f7_bfc8_720d_c296,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x1963_6158_bbaf_62f2,
+            0x18c3_ffd5_e153_1a92,
+            0x7e6e_94e7_f0e9_decf,
+            0x0a59_a158_e3ee_c211,
+        ]),
+        Fp::from_raw([
+            0xf4c2_3ed0_075f_d07b,
+            0xe2c4_eba0_6542_0af8,
+            0xb58b_f23b_312f_fd3c,
+            0x06ec_54c8_0381_c052,
+        ]),
+        Fp::from_raw([
+            0x962f_0ff9_ed1f_9d01,
+            0xb093_40f7_a7bc_b1b4,
+            0x476b_5664_8e86_7ec8,
+            0x1188_72dc_832e_0eb5,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x95e1_906b_5209_21b1,
+            0x52e0_b0f0_e42d_7fea,
+            0x5ad5_c7cb_a7ad_59ed,
+            0x13d6_9fa1_27d8_3416,
+        ]),
+        Fp::from_raw([
+            0xfd8a_49f1_9f10_c77b,
+            0xde14_3942_fb71_dc55,
+            0x70b1_c687_7a73_d21b,
+            0x169a_177f_63ea_6812,
+        ]),
+        Fp::from_raw([
+            0xfb7e_9a5a_7450_544d,
+            0x3abe_b032_b922_f66f,
+            0xef42_f287_adce_40d9,
+            0x04ef_5159_1c6e_ad97,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd5f4_5ee6_dd0f_69ec,
+            0x19ec_6180_5d4f_03ce,
+            0x0ecd_7ca7_03fb_2e3b,
+            0x256e_175a_1dc0_7939,
+        ]),
+        Fp::from_raw([
+            0xa002_813d_3e2c_eeb2,
+            0x75cc_360d_3205_dd2d,
+            0xe5f2_af41_2ff6_004f,
+            0x3010_2d28_636a_bd5f,
+        ]),
+        Fp::from_raw([
+            0x1fd3_1be1_82fc_c792,
+            0x0443_a3fa_99be_f4a3,
+            0x1c07_14bc_73eb_1bf4,
+            0x1099_8e42_dfcd_3bbf,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xecad_76f8_79e3_6860,
+            0x9f33_62ea_f4d5_82ef,
+            0x25fa_7d24_b598_a1d8,
+            0x193e_dd8e_9fcf_3d76,
+        ]),
+        Fp::from_raw([
+            0xf266_4d7a_a51f_0b5d,
+            0xd1c7_a561_ce61_1425,
+            0xd036_8ce8_0b7b_3347,
+            0x1816_8afd_34f2_d915,
+        ]),
+        Fp::from_raw([
+            0x29e2_e95b_33ea_6111,
+            0xa328_ec77_bc33_626e,
+            0x0c01_7656_ebe6_58b6,
+            0x2938_3c01_ebd3_b6ab,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x00bf_573f_9010_c711,
+            0x702d_b6e8_6fb7_6ab6,
+            0xa1f4_ae5e_7771_a64a,
+            0x1064_6d2f_2603_de39,
+        ]),
+        Fp::from_raw([
+            0x64d0_242d_cb11_17fb,
+            0x2f90_c25b_40da_7b38,
+            0xf575_f139_5a55_bf13,
+            0x0beb_5e07_d1b2_7145,
+        ]),
+        Fp::from_raw([
+            0xdffb_f018_d96f_a336,
+            0x30f9_5bb2_e54b_59ab,
+            0xdc0d_3eca_d62b_5c88,
+            0x16d6_8525_2078_c133,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xfd67_2dd6_2047_f01a,
+            0x0a55_5bbb_ec21_ddfa,
+            0x3c74_154e_0404_b4b4,
+            0x0a6a_bd1d_8339_38f3,
+        ]),
+        Fp::from_raw([
+            0x70a6_f19b_34cf_1860,
+            0xb12d_ffee_c450_3172,
+            0x8ea1_2a4c_2ded_c8fe,
+            0x1a67_9f5d_36eb_7b5c,
+        ]),
+        Fp::from_raw([
+            0xfbc7_592e_3f1b_93d6,
+            0x26a4_23ea_da4e_8f6f,
+            0x3974_d50e_0ebf_de47,
+            0x0980_fb23_3bd4_56c2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x03eb_acb5_c312_c72b,
+            0xcece_3d56_28c9_2820,
+            0xbf18_10af_93a3_8fc0,
+            0x161b_4223_2e61_b84c,
+        ]),
+        Fp::from_raw([
+            0xd092_03db_47de_1a0b,
+            0x493f_0978_7f15_64e5,
+            0x950f_7d47_a60d_5e6a,
+            0x0ada_10a9_0c7f_0520,
+        ]),
+        Fp::from_raw([
+            0xb50d_db9a_f407_f451,
+            0xd3f0_7a8a_2b4e_121b,
+            0x3203_45a2_9ac4_238e,
+            0x1a73_0d37_2310_ba82,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xfbda_10ef_58e8_c556,
+            0x9083_77fe_aba5_c4df,
+            0x8170_64c3_69dd_a7ea,
+            0x2c81_20f2_68ef_054f,
+        ]),
+        Fp::from_raw([
+            0x6e7b_8649_a496_8f70,
+            0xb930_e953_13bc_b73e,
+            0xa57c_0078_9c68_4217,
+            0x1c7c_8824_f758_753f,
+        ]),
+        Fp::from_raw([
+            0xb47b_27fa_3fd1_cf77,
+            0xf400_ad8b_491e_b3f7,
+            0x8e39_e407_7a74_faa0,
+            0x2cd9_ed31_f5f8_691c,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x854a_e239_18a2_2eea,
+            0xa5e0_22ac_321c_a550,
+            0xcf60_d92f_5761_8399,
+            0x23ff_4f9d_4681_3457,
+        ]),
+        Fp::from_raw([
+            0xdff1_ea58_f180_426d,
+            0xaf5a_2c51_0352_9407,
+            0xceec_e640_5ddd_d9d0,
+            0x0994_5a5d_147a_4f66,
+        ]),
+        Fp::from_raw([
+            0x8a6d_d223_ec6f_c630,
+            0x7c7d_a6ea_a29d_3f26,
+            0xb676_60c6_b771_b90f,
+            0x188d_9c52_8025_d4c2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe0c0_d8dd_f4f0_f47f,
+            0xdba7_d926_d363_3595,
+            0x81f6_8311_431d_8734,
+            0x3050_e379_9659_6b7f,
+        ]),
+        Fp::from_raw([
+            0x9d82_9518_d30a_fd78,
+            0x6cea_e546_1e3f_95d8,
+            0x1600_ca81_02c3_5c42,
+            0x15af_1169_3968_30a9,
+        ]),
+        Fp::from_raw([
+            0x0428_4da3_320d_8acc,
+            0xdae9_33e3_5146_6b29,
+            0xa06d_9f37_f873_d985,
+            0x1da6_d098_8543_2ea9,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xe546_ee41_1dda_a9cb,
+            0x4e4f_ad3d_be65_8945,
+            0xf5f8_acf3_3921_124e,
+            0x2796_ea90_d269_af29,
+        ]),
+        Fp::from_raw([
+            0x7cb0_319e_01d3_2d60,
+            0x1e15_612e_c8e9_304a,
+            0x0325_c8b3_3077_42f0,
+            0x202d_7dd1_da0f_6b4b,
+        ]),
+        Fp::from_raw([
+            0xa29d_ace4_c0f8_be5f,
+            0xa2d7_f9c7_88f4_c831,
+            0x156a_952b_a263_d672,
+            0x096d_6790_d05b_b759,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x6379_8cb1_447d_25a4,
+            0x438d_a23c_e5b1_3e19,
+            0x8380_8965_275d_877b,
+            0x054e_fa1f_65b0_fce2,
+        ]),
+        Fp::from_raw([
+            0x64cc_f6e1_8e41_65f1,
+            0xd8aa_6901_13b2_e148,
+            0xdb33_08c2_9802_deb9,
+            0x1b16_2f83_d917_e93e,
+        ]),
+        Fp::from_raw([
+            0xc5ce_b745_a050_6edc,
+            0xedfe_fc14_66cc_568e,
+            0xfd9f_1cdd_2a0d_e39e,
+            0x21e5_241e_1256_4dd6,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x7b43_49e1_0e4b_df08,
+            0xcb73_ab5f_87e1_6192,
+            0x226a_80ee_17b3_6abe,
+            0x1cfb_5662_e8cf_5ac9,
+        ]),
+        Fp::from_raw([
+            0x29c5_3f66_6eb2_4100,
+            0x2c99_af34_6220_ac01,
+            0xbae6_d8d1_ecb3_73b6,
+            0x0f21_177e_302a_771b,
+        ]),
+        Fp::from_raw([
+            0xbcef_7e1f_515c_2320,
+            0xc423_6aed_e629_0546,
+            0xaffb_0dd7_f71b_12be,
+            0x1671_5223_7460_6992,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd419_d2a6_92ca_d870,
+            0xbe2e_c9e4_2c5c_c8cc,
+            0x2eb4_cf24_501b_fad9,
+            0x0fa3_ec5b_9488_259c,
+        ]),
+        Fp::from_raw([
+            0x85e8_c57b_1ab5_4bba,
+            0xd36e_dce8_5c64_8cc0,
+            0x57cb_266c_1506_080e,
+            0x193c_0e04_e0bd_2983,
+        ]),
+        Fp::from_raw([
+            0xce14_ea2a_daba_68f8,
+            0x9f6f_7291_cd40_6578,
+            0x7e91_2830_6dcb_c3c9,
+            0x102a_df8e_f747_35a2,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x40a6_d0cb_70c3_eab1,
+            0x316a_a24b_fbdd_23ae,
+            0xe2a5_4d6f_1ad9_45b1,
+            0x0fe0_af78_58e4_9859,
+        ]),
+        Fp::from_raw([
+            0xe8a5_ea73_4479_8d22,
+            0x2da5_f1da_a9eb_defd,
+            0x0853_6a22_2084_3f4e,
+            0x216f_6717_bbc7_dedb,
+        ]),
+        Fp::from_raw([
+            0xf88e_2e42_2832_5161,
+            0x3c23_b2ac_773c_6b3e,
+            0x4a3e_6943_9191_8a1b,
+            0x1da5_5cc9_00f0_d21f,
+        ]),
+    ],
+];
+pub(crate) const MDS: [[Fp; 3]; 3] = [
+    [
+        Fp::from_raw([
+            0xfedb_6859_2ba8_118b,
+            0x94be_7c11_ad24_378b,
+            0xb2b7_0caf_5c36_a7b1,
+            0x109b_7f41_1ba0_e4c9,
+        ]),
+        Fp::from_raw([
+            0xd6c6_4543_dc49_03e0,
+            0x9314_dc9f_dbde_ea55,
+            0x6ae1_1942_4fdd_bcbc,
+            0x16ed_41e1_3bb9_c0c6,
+        ]),
+        Fp::from_raw([
+            0x791a_93b7_4e36_736d,
+            0xf706_ab64_0ceb_247b,
+            0xf617_e7dc_bfe8_2e0d,
+            0x2b90_bba0_0fca_0589,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd629_40bc_de0b_d771,
+            0x2cc8_fdd1_415c_3dde,
+            0xb9c3_6c76_4379_dbca,
+            0x2969_f27e_ed31_a480,
+        ]),
+        Fp::from_raw([
+            0x29b2_3116_87b1_fe23,
+            0xb89d_743c_8c7b_9640,
+            0x4c98_71c8_3296_3dc1,
+            0x2e24_19f9_ec02_ec39,
+        ]),
+        Fp::from_raw([
+            0xc8aa_cc55_a0f8_9bfa,
+            0x148d_4e10_9f5f_b065,
+            0x9731_5876_690f_053d,
+            0x1010_71f0_0323_79b6,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x3262_44ee_65a1_b1a7,
+            0xe6cd_79e2_8c5b_3753,
+            0x0d5f_9e65_4638_065c,
+            0x1430_21ec_686a_3f33,
+        ]),
+        Fp::from_raw([
+            0xb16c_dfab_c8ee_2911,
+            0xd057_e12e_58e7_d7b6,
+            0x82a7_0eff_08a6_fd99,
+            0x176c_c029_695a_d025,
+        ]),
+        Fp::from_raw([
+            0x7327_9cd7_1d25_d5e0,
+            0xa644_4703_0704_3f77,
+            0x17ba_7fee_3802_593f,
+            0x19a3_fc0a_5670_2bf4,
+        ]),
+    ],
+];
+pub(crate) const MDS_INV: [[Fp; 3]; 3] = [
+    [
+        Fp::from_raw([
+            0x7dfe_a07d_03f8_2f26,
+            0x879a_5f36_b4ba_6dd1,
+            0xb646_5d69_d3e1_2806,
+            0x203d_1d35_1372_bf15,
+        ]),
+        Fp::from_raw([
+            0x6e32_0628_bb03_2c22,
+            0x48e4_2ce2_096b_3a1d,
+            0x4b6a_d7fe_4620_063d,
+            0x29b6_5372_1861_5bcb,
+        ]),
+        Fp::from_raw([
+            0x1e2a_35a9_58b9_3a6a,
+            0x54f5_1f71_1623_515b,
+            0xb51b_d377_d7bb_55c0,
+            0x1155_1257_de3d_4b5a,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xdd13_1981_4f83_3b54,
+            0xbf0d_c3bc_4fb6_2798,
+            0x3d56_9912_c20f_1f82,
+            0x29de_db1b_bf80_c886,
+        ]),
+        Fp::from_raw([
+            0x24ca_8a66_304e_3849,
+            0xbe03_67fe_c157_5cb1,
+            0xd66c_7251_dc8f_56fb,
+            0x130b_5914_3f4e_340c,
+        ]),
+        Fp::from_raw([
+            0x1084_76d2_eeda_95f9,
+            0x248c_8d6d_3b16_d4b4,
+            0x6087_842f_b625_21a3,
+            0x0c28_08c9_533e_2c52,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xd288_9dc1_45c6_1609,
+            0x29e1_387e_706c_f0de,
+            0x9170_6fe0_9af2_2cfd,
+            0x0173_249a_1c9e_ac25,
+        ]),
+        Fp::from_raw([
+            0xd2e5_fb51_38e5_9350,
+            0xd162_70b0_238f_3063,
+            0xc82e_03ec_3cee_0cf1,
+            0x0abc_7f15_8780_841e,
+        ]),
+        Fp::from_raw([
+            0x5413_af26_963b_4700,
+            0xe9f2_478c_2f28_ee94,
+            0x8305_505a_af3b_497f,
+            0x1738_a318_c863_1b6e,
+        ]),
+    ],
+];
+// Constants of the optimized permutation:
+pub(crate) const PRE_SPARSE_ROUND_CONSTANTS: [Fp; 3] = [
+    Fp::from_raw([
+        0x17cb_978d_069d_e559,
+        0xc76d_a36c_2578_9378,
+        0xe9ef_f81b_016f_c34d,
+        0x1052_0b0a_b721_cadf,
+    ]),
+    Fp::from_raw([
+        0x1ae7_8573_0481_1eb6,
+        0x9fb3_4b95_709b_a0d5,
+        0x055c_f4d0_c01f_887f,
+        0x2ba6_ccdc_ecf6_7768,
+    ]),
+    Fp::from_raw([
+        0x7635_479f_5fa1_edef,
+        0x72a6_085d_8614_0193,
+        0xe96a_348e_ff61_2dde,
+        0x0b43_33d1_6604_e6a9,
+    ]),
+];
+pub(crate) const PARTIAL_ROUND_CONSTANTS: [Fp; 56] = [
+    Fp::from_raw([
+        0xc64f_2fb1_3739_268b,
+        0x1444_d6b8_4775_9f3d,
+        0x4a88_143c_a71c_f4ce,
+        0x199c_7d34_412c_fe7e,
+    ]),
+    Fp::from_raw([
+        0x780e_0cfd_f8a7_a8f6,
+        0x546e_fd7e_a3da_6051,
+        0xa395_755c_d0ad_8520,
+        0x1bac_613e_7d89_7eaf,
+    ]),
+    Fp::from_raw([
+        0x2609_73ad_3024_a562,
+        0x3e5a_f9fc_1fbd_1636,
+        0x47b4_a0ad_2b79_df63,
+        0x09f2_da11_af0b_15d1,
+    ]),
+    Fp::from_raw([
+        0xf25e_bbe6_34b5_2a8a,
+        0x4e85_84f1_65fa_f7a5,
+        0x9d25_25db_b3fd_634b,
+        0x2447_d827_f0ef_87b8,
+    ]),
+    Fp::from_raw([
+        0x1009_6077_0c32_afd2,
+        0xc976_69fb_ec96_2acc,
+        0xec14_e5cd_5b06_c21f,
+        0x0c31_1d82_bd7b_2f86,
+    ]),
+    Fp::from_raw([
+        0x90e3_8c1b_e912_af45,
+        0x3051_7b4a_870a_af73,
+        0xac29_f2e6_9419_b143,
+        0x2bd6_b169_28fd_b2e2,
+    ]),
+    Fp::from_raw([
+        0xdfc5_5dda_5807_cf86,
+        0x1274_5106_4232_f429,
+        0x1868_7df4_1c1d_a42e,
+        0x079b_2094_5b82_17e2,
+    ]),
+    Fp::from_raw([
+        0x6055_3302_994f_ced5,
+        0xf6dd_c90f_ea0d_1625,
+        0x45d4_9686_4121_c027,
+        0x038e_401f_71ef_d04d,
+    ]),
+    Fp::from_raw([
+        0x55a0_7c1e_c9ab_2e52,
+        0x937a_3eec_ea81_fbba,
+        0xbdf1_a003_f3d4_d8ee,
+        0x1dfb_d259_54ce_54f9,
+    ]),
+    Fp::from_raw([
+        0x7413_a342_4e35_3391,
+        0xd9fe_d052_d0ee_fb80,
+        0xef0b_c1c0_6875_7eea,
+        0x1f32_6c45_8dbf_469e,
+    ]),
+    Fp::from_raw([
+        0x89fd_1fb3_0422_6928,
+        0x2ab2_5085_1588_571f,
+        0xf16f_e618_3a55_3e75,
+        0x24f9_13ae_7197_ce25,
+    ]),
+    Fp::from_raw([
+        0x611c_996e_e296_fe60,
+        0x179a_129c_37a7_c8a0,
+        0xdb60_47fd_1b88_c491,
+        0x1ba7_9aaa_080d_c25c,
+    ]),
+    Fp::from_raw([
+        0x029c_ff27_f37d_577a,
+        0xe896_37d0_48dc_b308,
+        0x8379_5d80_2172_62b7,
+        0x2e00_cccf_20f4_a49b,
+    ]),
+    Fp::from_raw([
+        0xf948_e744_febc_0cfe,
+        0xbfd9_7391_eb87_246b,
+        0x563e_3a27_f77f_d661,
+        0x20c0_f44c_6f45_4008,
+    ]),
+    Fp::from_raw([
+        0x3082_8856_467f_934c,
+        0x6976_d65e_6ca4_906d,
+        0x7f61_bc5d_79a8_9060,
+        0x0130_8123_df62_85b5,
+    ]),
+    Fp::from_raw([
+        0xae4a_d1fe_09e7_db36,
+        0x59b6_9650_9ae8_9de2,
+        0xbd93_cbd2_c25a_cc29,
+        0x1426_df5f_8689_ca7a,
+    ]),
+    Fp::from_raw([
+        0x1a53_8f12_e717_23fb,
+        0x5510_60a8_c7fd_4ee1,
+        0x2677_6fc6_990d_cc8f,
+        0x2b25_b1e6_1fe4_f60d,
+    ]),
+    Fp::from_raw([
+        0xee74_30f6_f248_ce7d,
+        0x5c29_1ff6_ab5f_b002,
+        0x4d6f_32c9_cfeb_d4e8,
+        0x1b09_3bbf_dd6b_2b16,
+    ]),
+    Fp::from_raw([
+        0x05a4_57c3_1a42_f3b5,
+        0x527f_dc44_fff8_1519,
+        0xb2a9_b531_4fbb_48cd,
+        0x2526_c497_3867_1af6,
+    ]),
+    Fp::from_raw([
+        0xf439_6ca5_2dbc_9acd,
+        0x2df7_bfcf_731f_f89c,
+        0xb0a0_2c0d_e74f_27e6,
+        0x2136_f240_750a_7eef,
+    ]),
+    Fp::from_raw([
+        0x8fd0_eee7_d135_a3de,
+        0x6491_dc2a_d020_86a0,
+        0x31cf_4cf1_63da_e44f,
+        0x0165_343d_b800_cbca,
+    ]),
+    Fp::from_raw([
+        0x56f2_72ad_af1c_ecd5,
+        0x60b7_1cdd_8336_14c7,
+        0x26b5_dc08_7183_e2e0,
+        0x13ad_9df2_2bd2_db53,
+    ]),
+    Fp::from_raw([
+        0x5488_3bff_26c6_5772,
+        0x7b02_728b_4e2b_b8d1,
+        0x382f_bd50_56fa_3d8e,
+        0x1706_2624_0386_09ae,
+    ]),
+    Fp::from_raw([
+        0x6ebd_b921_55fd_2092,
+        0x0d53_b9c4_1f62_be85,
+        0x8ce8_0a18_7440_6148,
+        0x0140_2825_a539_2d0c,
+    ]),
+    Fp::from_raw([
+        0x4ae4_ae72_a066_8be7,
+        0x6707_659e_e8fc_5efa,
+        0xe2c3_f765_287e_841f,
+        0x1e71_5c07_4dd7_24e2,
+    ]),
+    Fp::from_raw([
+        0xc161_af77_1d01_760c,
+        0xb5b8_f138_273c_9d36,
+        0x0b2e_6471_1b96_4adc,
+        0x2fb2_5350_99a5_fe57,
+    ]),
+    Fp::from_raw([
+        0x39c4_e883_3d41_7add,
+        0x9dff_c6a7_a9f6_39e5,
+        0x1744_87a0_8cb9_df5a,
+        0x1156_0e63_c8b4_ade8,
+    ]),
+    Fp::from_raw([
+        0xf833_90e7_3fc4_44f4,
+        0x3ca3_be50_3ee4_bcaa,
+        0x6809_59ac_cf61_1378,
+        0x0e58_3f36_a57e_e9af,
+    ]),
+    Fp::from_raw([
+        0x9f73_2767_f84c_2474,
+        0xaac6_935f_6f7e_0bf2,
+        0x6444_ecf7_231e_ca6e,
+        0x0ae8_d600_5e59_6525,
+    ]),
+    Fp::from_raw([
+        0x832a_99ee_f761_13b3,
+        0x6a49_98eb_fd22_1d49,
+        0xbc3c_1e8e_20c2_9f30,
+        0x05cf_0f85_8ed9_23f2,
+    ]),
+    Fp::from_raw([
+        0x90f5_5924_20f0_140d,
+        0x3931_bd12_c9bb_ac90,
+        0xd81d_a74e_3f60_e36f,
+        0x0dab_ffea_6626_d00a,
+    ]),
+    Fp::from_raw([
+        0x4067_eb38_4aa9_2f96,
+        0x707c_fd16_be9c_0195,
+        0xa154_dfe9_95ce_fe71,
+        0x19b6_4645_e222_5b26,
+    ]),
+    Fp::from_raw([
+        0x50db_b4b8_02a6_2087,
+        0x9006_a3aa_d302_79e2,
+        0x60ad_908c_a1a5_5ae2,
+        0x1a82_3d90_efdc_4d34,
+    ]),
+    Fp::from_raw([
+        0xaef1_f1cb_27ac_e54a,
+        0xf3a3_584c_c842_8323,
+        0x2044_9cae_c4d0_2b65,
+        0x15f2_a364_13e2_4b82,
+    ]),
+    Fp::from_raw([
+        0x5631_09c2_e8f3_c8e5,
+        0x910a_24e2_0841_ff28,
+        0x23f2_9bf0_1f3b_ec40,
+        0x2f99_3e6c_b726_36a7,
+    ]),
+    Fp::from_raw([
+        0x0d84_0553_b36b_5e5c,
+        0x8e4c_431b_8828_0881,
+        0x0a6f_77a1_6257_f695,
+        0x0cca_e7b4_dcf9_2cb6,
+    ]),
+    Fp::from_raw([
+        0xcfbd_a375_0a15_cf55,
+        0x4fe2_11e1_8834_0190,
+        0x48a7_0f5b_804d_91c4,
+        0x15e8_0e5c_5d8a_3c53,
+    ]),
+    Fp::from_raw([
+        0x0d2a_8a4b_6ae0_19dd,
+        0x1704_c7e9_e4c6_a461,
+        0xde6d_a877_7ad9_e827,
+        0x2fca_92c2_dd2a_a24f,
+    ]),
+    Fp::from_raw([
+        0x8414_b331_cfb6_e624,
+        0x597f_1a88_ea07_c5eb,
+        0x537d_e263_55eb_9fa9,
+        0x1b9f_d80c_f967_0d6b,
+    ]),
+    Fp::from_raw([
+        0xc9f7_2565_0a05_b12e,
+        0xf4cf_3454_8d26_eb1d,
+        0xb493_6e4f_ecb3_706f,
+        0x06f6_e261_9a70_4fd5,
+    ]),
+    Fp::from_raw([
+        0x3b97_2d87_2d2f_9682,
+        0x135c_4cf8_d60f_f92f,
+        0xf281_63cb_f773_9ae5,
+        0x0b8a_3e00_068d_b4d4,
+    ]),
+    Fp::from_raw([
+        0x232c_4e46_8224_2ffa,
+        0xa62e_e9d5_7813_47ea,
+        0x195c_8cfe_29ca_a0fd,
+        0x0565_ff86_5d41_74af,
+    ]),
+    Fp::from_raw([
+        0x4866_307d_c7ea_876b,
+        0x6b1a_521a_27b0_5192,
+        0x3956_f3f4_4d2f_f820,
+        0x0f7b_0ca9_fd2b_47f9,
+    ]),
+    Fp::from_raw([
+        0xc329_8d4a_ef04_f528,
+        0x7fbb_b2da_e291_434b,
+        0xa624_5dda_baf7_307e,
+        0x21fc_3257_2200_3f14,
+    ]),
+    Fp::from_raw([
+        0x4e08_df1e_fe94_280b,
+        0x788e_6088_6808_1ba1,
+        0xb73f_9223_0e93_2b09,
+        0x10c5_97a2_015e_571f,
+    ]),
+    Fp::from_raw([
+        0xd31b_29da_0609_9a25,
+        0xb673_2fea_276c_9a5d,
+        0x59d7_ff5f_ba60_93dd,
+        0x2170_73ce_65eb_6144,
+    ]),
+    Fp::from_raw([
+        0x3c88_4109_f47a_1b07,
+        0xb235_85cc_725a_229a,
+        0xad17_6ca0_7108_2e90,
+        0x1f33_fb2e_7bf9_9d79,
+    ]),
+    Fp::from_raw([
+        0xc185_0083_3b79_f06c,
+        0x6700_d88e_63fa_8d23,
+        0x48dd_e648_f3c3_02ee,
+        0x1488_6644_f736_a7c6,
+    ]),
+    Fp::from_raw([
+        0x0f94_75b3_cbdd_c52b,
+        0x9751_6e31_867a_7a1d,
+        0x6ceb_5eaf_e609_e583,
+        0x0168_ed55_8fd9_35ac,
+    ]),
+    Fp::from_raw([
+        0x960a_bfbc_3c1d_48da,
+        0x6de6_77a2_7380_408c,
+        0x0f40_c8a5_d89f_9e00,
+        0x15b6_bf6e_4ef1_b4e5,
+    ]),
+    Fp::from_raw([
+        0x57d9_34b7_588f_d72d,
+        0xdbf5_d8c6_b294_ea35,
+        0x58a5_66e4_8e04_0808,
+        0x2783_9860_af94_8181,
+    ]),
+    Fp::from_raw([
+        0x9f9d_c613_13f9_12db,
+        0x4079_1905_641a_b0d4,
+        0x6747_e90f_4188_6a41,
+        0x214e_de74_e894_8e1d,
+    ]),
+    Fp::from_raw([
+        0x6720_825b_e3a0_f000,
+        0xcde6_efae_9416_c4a1,
+        0x40e6_0631_4f66_e69d,
+        0x1cfa_7fd7_56c8_f77c,
+    ]),
+    Fp::from_raw([
+        0x1887_ff18_3755_42ee,
+        0x6a24_9073_12a9_8f93,
+        0xdebe_fc54_b911_b790,
+        0x0511_cf99_43a2_1504,
+    ]),
+    Fp::from_raw([
+        0xf4a9_a493_c171_13a6,
+        0x68d1_e04d_4eba_1f08,
+        0x1bd2_be1d_98fa_f481,
+        0x14dc_ade6_7ed5_1ec0,
+    ]),
+    Fp::from_raw([
+        0x406e_f4b1_7ed8_1090,
+        0xe33c_deb0_36e2_bcab,
+        0x51b8_a0fa_f37f_b30a,
+        0x1756_f326_9ecb_4092,
+    ]),
+];
+pub(crate) const PRE_SPARSE_MDS: [[Fp; 3]; 3] = [
+    [
+        Fp::from_raw([
+            0x0000_0000_0000_0001,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x87be_a648_df15_b75c,
+            0x37fa_30d5_abcb_c517,
+            0x9856_e0b1_acea_a847,
+            0x075b_e6d3_b7ae_2cb4,
+        ]),
+        Fp::from_raw([
+            0x19b1_17c9_095f_a111,
+            0x415b_7680_449d_4512,
+            0x6d3e_1f3e_cd5b_dbd8,
+            0x1a81_a72b_8e54_3937,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+            0x0000_0000_0000_0000,
+        ]),
+        Fp::from_raw([
+            0x94ac_b673_c9e8_7ede,
+            0x61bf_999c_84ff_13b1,
+            0x8b1c_0e35_472d_f289,
+            0x2b3d_ee28_c2ad_6179,
+        ]),
+        Fp::from_raw([
+            0xff92_75d3_bf83_1c89,
+            0x1fed_9aee_9f67_d3ca,
+            0x032d_0944_d68b_2ba2,
+            0x23aa_557f_8476_747f,
+        ]),
+    ],
+];
+pub(crate) const SPARSE_MDS: [SparseMds<Fp, 3>; 57] = [
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x3ab7_18e7_0757_6b31,
+                0x1a89_752f_427f_4f06,
+                0x6ee2_5a9b_8768_b323,
+                0x03f0_815a_b463_f1b7,
+            ]),
+            Fp::from_raw([
+                0xe008_859f_1dbf_b317,
+                0x5701_2a3d_3b1d_34c8,
+                0x54c7_e330_29b3_6173,
+                0x1564_8bf4_6f60_d829,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xa0cf_efbf_7fbf_ba85,
+                0xd05e_a850_cf61_f1da,
+                0x18ca_7f2e_afdd_7564,
+                0x127e_00c2_253d_e078,
+            ]),
+            Fp::from_raw([
+                0xf7d4_8051_6307_47bd,
+                0xd3ce_470a_8cbb_b878,
+                0x9382_fc0b_1d26_5cb4,
+                0x0663_65af_d18a_41ef,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xb099_715c_4404_aae7,
+                0xf4fa_24c8_4e57_dcf2,
+                0xdc69_a96f_7fe7_e086,
+                0x219d_14f8_2351_3140,
+            ]),
+            Fp::from_raw([
+                0xaefa_9ac2_3021_32d5,
+                0xf14f_4d33_696d_37eb,
+                0x4a6a_63a8_050d_91f9,
+                0x03a3_0bfb_bf2c_b86d,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8510_4b0b_4193_5bcc,
+                0xdad5_a84d_74b0_6e33,
+                0xb027_0fb7_d5c9_f94e,
+                0x2121_bbcd_eaa3_3a35,
+            ]),
+            Fp::from_raw([
+                0xd053_ef8b_3d10_e70e,
+                0xcd55_80c2_e338_a389,
+                0xcfbb_82c2_89e5_79b7,
+                0x196b_544f_beb0_a792,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x7d90_7ea0_202f_560f,
+                0x6973_ec73_edb4_bd4e,
+                0x89c1_db27_0ef4_79c2,
+                0x2809_c3a1_547c_0cee,
+            ]),
+            Fp::from_raw([
+                0xd40d_4e96_dfc5_c8f1,
+                0x2a4c_6717_5b31_f4b5,
+                0xca15_7585_a02b_8b34,
+                0x11c3_4446_b083_ef92,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6d9d_8026_e2e3_9925,
+                0xf624_2ad7_709d_90b8,
+                0x367c_030e_3289_cbe0,
+                0x253e_a0b3_3a8b_f3b2,
+            ]),
+            Fp::from_raw([
+                0x38c1_6df8_5637_bd5f,
+                0x4f5a_19c0_06d1_0304,
+                0x90c8_9d40_07ad_29fc,
+                0x3046_7dc1_930f_6afe,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x13c8_ebfb_bba5_4f44,
+                0xaa6a_5364_58b3_8bbe,
+                0x7e20_e6f5_a3a8_8af7,
+                0x2f9d_4b55_495f_7e37,
+            ]),
+            Fp::from_raw([
+                0x4cfd_03ff_0fea_c4b8,
+                0xd8ee_2353_be18_aad5,
+                0xf11d_36d4_99e7_e093,
+                0x1d9e_9d5c_736e_3151,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x5f34_3de3_01e5_4841,
+                0xd1bf_edb8_7e09_7c31,
+                0xebf6_22f7_823a_3de7,
+                0x124b_617b_43e5_98f9,
+            ]),
+            Fp::from_raw([
+                0x89cc_5748_ffe1_99b2,
+                0xa5f9_c5b1_9cae_08d7,
+                0x4055_cf07_3bed_c945,
+                0x198e_7cfc_66ae_4577,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x5bb6_c27e_d977_fe24,
+                0xeb94_5ba5_7443_099c,
+                0xfd12_4ab3_aad5_7789,
+                0x2eac_25b3_498d_fadf,
+            ]),
+            Fp::from_raw([
+                0x0b3c_6ab5_f4f9_0126,
+                0x4e8a_f1d4_454e_d355,
+                0x1b37_8305_c1bb_9c90,
+                0x1ee0_2c17_5cdf_e187,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9fa9_8301_d0f6_79d5,
+                0xf6fb_b1d9_745c_4860,
+                0xb29e_a8f9_d2df_a47f,
+                0x0616_f8c3_4c60_7266,
+            ]),
+            Fp::from_raw([
+                0xa43a_4283_2803_370b,
+                0x853e_51ed_385e_4883,
+                0x58b9_f19c_bbdb_972a,
+                0x181d_68b0_a188_5049,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x059e_9327_f5ba_7004,
+                0x81d1_ce2f_24cb_abf6,
+                0x5d6b_7f5b_015d_5791,
+                0x2d53_97ce_8634_64a2,
+            ]),
+            Fp::from_raw([
+                0x1724_d8db_d4bc_2618,
+                0x7713_e7d3_2da2_b659,
+                0xe891_2940_cc0b_8027,
+                0x15bf_8174_91b9_4d71,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x94a3_827d_29c0_8714,
+                0xc8cc_6877_40bc_9109,
+                0xb76f_eab2_8b69_485a,
+                0x2a7c_bd11_460b_177a,
+            ]),
+            Fp::from_raw([
+                0x4c23_7b29_0de9_d502,
+                0x63cb_462d_a80a_8561,
+                0xab56_e447_fae5_cc17,
+                0x0f7c_d5ff_a466_1730,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x644c_e045_3100_8100,
+                0xf768_137d_86d3_05be,
+                0xeb13_2735_08eb_6575,
+                0x0e07_6600_4b4c_4176,
+            ]),
+            Fp::from_raw([
+                0x4e1f_7630_00b9_924c,
+                0xb54e_e3c1_afac_0010,
+                0xf6d1_48be_6b9c_8bb7,
+                0x0625_fa71_4581_3481,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xb651_3631_8ce2_c6a5,
+                0xb19c_d9c7_b184_f515,
+                0x16ee_0f54_61aa_d2e0,
+                0x007c_5472_508b_4599,
+            ]),
+            Fp::from_raw([
+                0x4685_879c_b7a8_9fcb,
+                0x31d5_3073_951d_43c5,
+                0x93ac_77ab_3fb7_5572,
+                0x0567_3754_70d1_89b6,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6011_74ba_5c7b_8bcc,
+                0x8ad2_1f51_ea4b_fc71,
+                0x5165_f56c_063e_4210,
+                0x1d04_06bc_bec8_3f8d,
+            ]),
+            Fp::from_raw([
+                0x7c73_b46c_6327_2cb7,
+                0x375f_0634_2f86_96ee,
+                0x280a_8aa1_f864_05f3,
+                0x0c02_b18e_ef22_332d,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xad05_3a55_ad6d_a4cf,
+                0x8235_09ad_4fd1_b15a,
+                0xeaa7_add2_f801_a664,
+                0x17c1_fc17_4cd9_a6eb,
+            ]),
+            Fp::from_raw([
+                0x8a08_638e_9584_b32d,
+                0xaaa6_caf4_33f7_ed25,
+                0xab7e_bbc8_6709_a021,
+                0x05f8_43c2_3024_eb1d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x589f_792f_0ad8_cb37,
+                0x27b4_5ccd_90a5_5c87,
+                0x5cc5_1c53_165e_0027,
+                0x22df_2420_697c_a28b,
+            ]),
+            Fp::from_raw([
+                0x4175_88ef_c4d7_302a,
+                0x9fd3_af80_4b76_be86,
+                0x7340_0aae_df0f_4800,
+                0x2f14_3830_3a7b_49d4,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6155_c546_3093_b23b,
+                0xbaa7_f4dc_cd35_d5ca,
+                0xc6b2_b7b4_fbf9_a24b,
+                0x2323_d5fc_f2da_8965,
+            ]),
+            Fp::from_raw([
+                0x4e37_7000_73d4_d26e,
+                0xf40f_7b96_1e9c_54f9,
+                0xe83b_753a_5e73_36b9,
+                0x026c_85b9_dfbb_e48f,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x28e4_1d65_384c_318f,
+                0x70b2_71df_4c20_9795,
+                0xfeb3_8b5a_b4e3_35f0,
+                0x0315_1100_0251_ec86,
+            ]),
+            Fp::from_raw([
+                0x0ff5_df9a_26c0_3af1,
+                0xe27c_d16b_941e_34a6,
+                0xb42f_a69e_5d90_a0c0,
+                0x18e5_8832_4a9b_baac,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xd99f_03c1_0ea1_f95f,
+                0x9835_7d6a_d9be_f2e7,
+                0x0706_3577_5c8d_3c94,
+                0x2642_b5d8_e16b_953b,
+            ]),
+            Fp::from_raw([
+                0x21f9_09ae_f836_c133,
+                0x3118_9b0b_4833_5c42,
+                0xe84f_f60d_b906_a0f0,
+                0x21fc_313b_a11c_60e8,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xf2f4_d93d_06da_e151,
+                0x3112_98bc_bac6_e4e9,
+                0x890b_698c_c6ab_89f7,
+                0x2d35_62e3_d4b4_2bc6,
+            ]),
+            Fp::from_raw([
+                0x189d_886d_fd2e_0808,
+                0x7934_a5f6_7616_f01c,
+                0x2e3e_0b6f_f7e5_c7c7,
+                0x0a74_ef54_1d36_0e84,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x67c2_c9b1_b02c_af8a,
+                0x43f4_3408_7d9e_7549,
+                0xc398_3d6e_3b43_3afa,
+                0x1405_64b5_3e0a_812a,
+            ]),
+            Fp::from_raw([
+                0x3adb_6f2d_b6bb_a9d0,
+                0x59c4_36c8_e83f_a699,
+                0x18b4_0018_1e71_ab97,
+                0x1470_9e32_d98a_e4cd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x4a61_9a4b_52bd_c010,
+                0x2372_db4f_2dba_651f,
+                0x423f_179e_1266_dd39,
+                0x0734_b236_6c59_e394,
+            ]),
+            Fp::from_raw([
+                0xd02d_7e71_088f_d2d4,
+                0xe963_ed92_9136_42c7,
+                0x5ad3_e3c5_fb66_29ab,
+                0x11fb_2d70_5c94_b08d,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x34e4_cc36_1805_9484,
+                0xef6e_b7f7_8fd8_4be8,
+                0x5d71_5eba_1937_1050,
+                0x27d0_3abf_5c1f_290e,
+            ]),
+            Fp::from_raw([
+                0xbb9b_441a_c139_5861,
+                0xbe81_7f21_2a39_c6a8,
+                0x7fb3_353c_fc2c_d63e,
+                0x13ed_9e9e_6b45_2df2,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x7c5b_79ab_99cb_d23c,
+                0x795d_e445_2604_263a,
+                0x246c_daaa_04a1_2e88,
+                0x1319_c51c_f37a_aa10,
+            ]),
+            Fp::from_raw([
+                0x715b_6cea_019a_c3f2,
+                0x26a4_cf44_4eeb_bd0e,
+                0x7f9d_ad83_9f2c_8cb5,
+                0x000b_ca25_588d_187b,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xc01b_e23c_f51d_593f,
+                0x1a06_9b49_3f02_f7a3,
+                0x1812_2687_4b92_3cd0,
+                0x1d83_7ea0_341c_5964,
+            ]),
+            Fp::from_raw([
+                0x0120_90db_7de4_e7f9,
+                0x4149_e2a6_dbd2_5f24,
+                0x42c4_27ce_4c5c_8377,
+                0x1b41_ce9e_d363_4cbd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x7b56_bd66_73f1_ce1f,
+                0xbca7_4b98_b78a_127c,
+                0xddc7_90ec_c4e9_46f4,
+                0x0671_f0e3_b674_ae7c,
+            ]),
+            Fp::from_raw([
+                0x071d_0449_a542_6e4e,
+                0xefeb_682c_1ac1_4143,
+                0x72e4_0cd3_0615_f55f,
+                0x019f_c073_797a_39b2,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x11c5_0966_19e9_fd13,
+                0xfa42_0948_0bf5_d973,
+                0xfd1f_7c5c_6d5a_7c70,
+                0x017b_ee47_d262_a497,
+            ]),
+            Fp::from_raw([
+                0x00cc_8527_2744_71e3,
+                0x4c79_4472_1cc9_37ba,
+                0x8076_3539_cff2_978a,
+                0x2073_cff9_2d31_41b4,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xc7aa_46b1_fa66_3baa,
+                0xf9c5_8d15_2e73_0fe2,
+                0x7f43_182a_55a9_1d48,
+                0x03bd_7b3e_2c18_8587,
+            ]),
+            Fp::from_raw([
+                0xfed9_ffbb_bad9_b6b7,
+                0x0ceb_1007_19a1_4c8d,
+                0xff12_8edf_b9bb_f5fa,
+                0x226e_bc9a_538b_5bba,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xc6e3_94e8_a5d3_b21a,
+                0x34a4_9572_af1d_830d,
+                0x0373_a06e_1552_c0e6,
+                0x0d39_5f0b_08b9_fede,
+            ]),
+            Fp::from_raw([
+                0x41f7_7d53_31f9_9ffa,
+                0x5284_bd3b_cf1e_0f2f,
+                0x30d4_9b68_e19e_31ba,
+                0x2824_2439_b524_540a,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x754b_48c6_154d_4df6,
+                0x0b45_7e12_9e91_f929,
+                0x2d2d_e034_801a_b85e,
+                0x0370_d6fa_19ea_ac14,
+            ]),
+            Fp::from_raw([
+                0x0a2b_2e0b_cbde_1659,
+                0xa379_39bc_0c75_3feb,
+                0x9076_2abf_2695_79ea,
+                0x09a1_6f57_3b32_80f3,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8d99_9813_6823_1d97,
+                0x3c00_21a6_90b7_1b26,
+                0x496a_c443_f981_27ee,
+                0x2228_e360_fb5b_162b,
+            ]),
+            Fp::from_raw([
+                0xb976_75f3_c567_f944,
+                0x9431_e34d_8032_b6a1,
+                0x9fab_f839_9147_6d20,
+                0x07e4_2c2c_a633_d2c4,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xa50f_e960_9772_4a9f,
+                0xed35_fda1_d8e9_d753,
+                0xc3ca_b85a_6215_a32e,
+                0x2ce1_2d72_6966_3770,
+            ]),
+            Fp::from_raw([
+                0xba0b_2231_815b_15de,
+                0x084b_c4da_f709_73a7,
+                0x09ee_b9b1_b45a_0125,
+                0x03d7_4277_04c6_1e20,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x2d86_921e_553e_69c6,
+                0xa096_fb4d_dc46_2673,
+                0x1c12_67fc_f4b4_b33c,
+                0x10f8_abf0_7641_8586,
+            ]),
+            Fp::from_raw([
+                0x5c95_644c_7c59_14cc,
+                0x51a1_a620_aaf6_568a,
+                0x025d_7cb4_56e3_aeb2,
+                0x17cc_af6f_26f7_267a,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x59de_6df2_8cea_4d51,
+                0xd0e3_651a_6e55_754d,
+                0x1385_c3ce_00ca_820a,
+                0x063b_b306_b963_1005,
+            ]),
+            Fp::from_raw([
+                0x920c_f993_a416_9974,
+                0x0324_2e0b_65e6_08bc,
+                0xf2c3_04a1_8095_ab74,
+                0x1f76_1ee5_553c_5e86,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xcd6b_dfc0_9de4_e8f2,
+                0x64bc_de87_61b4_5717,
+                0xa23c_0e66_6859_ba65,
+                0x0dc5_f00b_bfd7_c1d9,
+            ]),
+            Fp::from_raw([
+                0x9108_9760_bfc4_0ef2,
+                0xb44c_f790_a230_abc3,
+                0xdf07_c353_6381_c13e,
+                0x06de_5115_20e2_77b7,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xf24a_8c06_e10c_ca03,
+                0x1fd4_481b_50a1_fe21,
+                0xf9ef_5486_3e70_528a,
+                0x2a13_4348_c866_0efc,
+            ]),
+            Fp::from_raw([
+                0xa1b3_3520_bcfc_e37b,
+                0xd5f6_f1ff_b63a_7dbb,
+                0x4bd8_0089_e99e_df8e,
+                0x0aeb_5023_bbb9_a64c,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x36fc_51fb_7cb9_33d1,
+                0x406c_5960_ab26_1558,
+                0x25ec_b5f0_bfdc_9995,
+                0x141a_6d08_1036_6ae2,
+            ]),
+            Fp::from_raw([
+                0xbfe8_7497_f1e2_c5b7,
+                0xcc0b_2539_990b_c0b8,
+                0xbe77_6f40_4dca_6626,
+                0x09d2_ea05_ef54_dadb,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xff1a_16e9_1719_cdde,
+                0x22d4_a543_2441_bfe8,
+                0xd104_d5f8_ef70_891d,
+                0x1e56_d244_a8e4_1be5,
+            ]),
+            Fp::from_raw([
+                0x8ba4_d5c5_f50c_7b49,
+                0x5e09_447f_a85c_2fd6,
+                0xec90_8b2f_99b5_c4fd,
+                0x1d4f_020c_57c4_f14a,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xee1e_8337_64c1_8fd3,
+                0x8d82_a1e0_9db8_0fb0,
+                0xe09f_4e14_cd03_398d,
+                0x0763_911a_3a92_a4f0,
+            ]),
+            Fp::from_raw([
+                0x6843_66e5_4b30_2946,
+                0x1fc5_d9a2_c5e4_7e55,
+                0xba2e_c68f_9061_643f,
+                0x1285_7275_be2f_e6b9,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xd0eb_f50d_1bbf_87c0,
+                0xdc0a_6035_3c5d_83d4,
+                0x655f_fe9a_96c4_b81a,
+                0x2ed1_1ccd_2e2e_2376,
+            ]),
+            Fp::from_raw([
+                0xd288_a215_43c6_d594,
+                0x726d_5b1c_2cfb_b4ac,
+                0x5b32_0d5e_3e96_6ef4,
+                0x03e3_1de8_958e_8264,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6ec2_ca4a_36e7_1963,
+                0xda28_a608_d7e9_0536,
+                0x58ae_8900_4653_3d58,
+                0x11e8_80df_efdb_d088,
+            ]),
+            Fp::from_raw([
+                0x90dc_25e9_69a5_07b2,
+                0x44a3_4662_978d_53c1,
+                0x0704_a9c3_cc21_ab7a,
+                0x1835_b275_deae_d2d0,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x419f_372f_f8d3_c3f5,
+                0xe5d4_4f76_f132_4240,
+                0xce5a_4a94_80e1_d82c,
+                0x068b_7531_5e25_ed4a,
+            ]),
+            Fp::from_raw([
+                0x98ad_3b22_8232_74d1,
+                0x268f_ccd7_95c8_39d6,
+                0x2b05_2d2a_d12b_92a4,
+                0x1b7e_f7d0_4aec_73d6,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xc240_d30b_baa9_f03f,
+                0x16b6_7072_7f4b_8efc,
+                0x6f61_93ff_5501_b572,
+                0x28c0_c848_022a_9060,
+            ]),
+            Fp::from_raw([
+                0xd6e5_d82d_4f06_8e1b,
+                0x5437_0985_a166_60ef,
+                0x686a_7bfb_1c39_f3f2,
+                0x13bd_a492_96cb_cc51,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xfd38_490d_3a59_4141,
+                0xa945_729f_86c3_e0e2,
+                0x11eb_10b3_4265_e378,
+                0x2e79_87ea_8204_389d,
+            ]),
+            Fp::from_raw([
+                0xe541_5fff_d039_35c8,
+                0xef70_2aef_fda3_226c,
+                0x4b2b_45c1_0a19_0fed,
+                0x0826_d4a2_324a_d3aa,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x685f_93b4_3403_6ded,
+                0xbb96_4a85_435c_3b59,
+                0xfa36_75ef_541c_9df7,
+                0x002d_beee_85ea_eaa9,
+            ]),
+            Fp::from_raw([
+                0x966a_4be5_99cb_86c7,
+                0xe6fa_44f5_f5c5_abfb,
+                0x9194_18ec_b327_9b11,
+                0x227e_e7a9_45ed_aee6,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8d54_1471_c724_4220,
+                0xffad_c239_86de_8c69,
+                0x05ac_90d6_96fa_f2a5,
+                0x1d0a_6d1a_9519_8778,
+            ]),
+            Fp::from_raw([
+                0x9f4d_e02b_25f6_e9d4,
+                0xd10e_ea1d_b284_ec3e,
+                0xda4f_333b_7854_fbbc,
+                0x2208_aaba_508a_e816,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x1757_2097_1ccf_04ec,
+                0xc9e5_9268_e2f8_e01a,
+                0xe36a_7d29_b587_a215,
+                0x28a5_8901_035b_2c99,
+            ]),
+            Fp::from_raw([
+                0x226f_38a8_adfd_6238,
+                0xf317_a2a1_4ffc_0191,
+                0x123a_0786_5ca1_376d,
+                0x0112_f6d8_d42b_0a0d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xaf90_6b6d_3c6e_2308,
+                0xc727_f97f_b4d0_1f1d,
+                0x3174_dda1_82d2_66d5,
+                0x08c6_eb19_c016_d183,
+            ]),
+            Fp::from_raw([
+                0xcfea_9103_f73f_1879,
+                0x75b1_be9a_48c8_698e,
+                0xd0b3_8b95_f9c6_42df,
+                0x1359_d2d6_c8b5_a116,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xed42_b699_c4af_3ca7,
+                0xaa07_aacf_7725_f8a5,
+                0xa467_c1cc_1878_d91a,
+                0x10c5_052e_c67a_b9b6,
+            ]),
+            Fp::from_raw([
+                0x0193_8236_84c9_6c75,
+                0xafdb_188d_5d4e_9f06,
+                0xdb70_8803_e633_8fc6,
+                0x0583_c4d2_92d5_4f3c,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xee2c_18bf_c06f_57b4,
+                0xbcd1_fe2b_3e07_6e16,
+                0x1a40_54c5_b963_22e7,
+                0x2d94_a1c5_5be3_8215,
+            ]),
+            Fp::from_raw([
+                0x0dbc_64dd_2211_c3ec,
+                0x3ef7_7c67_1927_ead8,
+                0xb997_3695_79c1_b170,
+                0x15e3_402f_dde8_770f,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x3026_77e2_0a72_7be3,
+                0xb500_0bef_8bb9_02eb,
+                0xf7b2_1e6b_867d_5a71,
+                0x185b_e987_8481_7f22,
+            ]),
+            Fp::from_raw([
+                0x5ca1_4cd9_4de4_67b6,
+                0x8aad_1b00_c054_547b,
+                0x66ed_8927_c898_90aa,
+                0x18db_4321_c721_c036,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x4eb2_134a_039b_5126,
+                0x5288_49bc_d2cd_0aff,
+                0x0c39_0b3f_3d79_9188,
+                0x2a85_2b62_47f5_d61f,
+            ]),
+            Fp::from_raw([
+                0x1659_3020_4da5_8f22,
+                0xa527_6f6d_e1cd_771b,
+                0xe65f_b9a1_8ee0_124a,
+                0x2510_aeed_51b7_f506,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x77d6_4865_13ba_b5f2,
+                0x47b7_fb54_dcad_1d79,
+                0xb5bd_3a23_6f03_a47b,
+                0x0f20_74a3_2eb8_260f,
+            ]),
+            Fp::from_raw([
+                0x4971_404b_fa04_4090,
+                0xc353_1c9e_12c4_c2c8,
+                0xa827_0e19_9419_26ce,
+                0x2f4c_6929_7866_bd45,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9b79_2c56_2a37_473f,
+                0xe92d_f5fd_5f3f_d75e,
+                0x05d0_83a6_5093_c0d0,
+                0x1546_6872_7d2d_badf,
+            ]),
+            Fp::from_raw([
+                0xa91e_5b0f_7b13_cadf,
+                0x8d3e_2e37_5bcd_1194,
+                0x4fd7_7fc5_ab5c_8c4e,
+                0x1e6f_fc5d_6a1f_f5dc,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xfafa_389d_a299_90c6,
+                0x98c8_b2d4_2853_8571,
+                0x9d75_acbc_9395_cb83,
+                0x2cf1_a1d7_c443_0910,
+            ]),
+            Fp::from_raw([
+                0x6ecf_64cf_793c_9880,
+                0xaa5d_8a02_3e24_cf01,
+                0x87cf_76cd_5ce8_da47,
+                0x140f_b39a_89f2_6f6d,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8298_a93a_8589_f9e8,
+                0xce2c_16da_c159_990b,
+                0xf071_2b20_1fb3_cddf,
+                0x1289_d13d_58a1_7b5b,
+            ]),
+            Fp::from_raw([
+                0x6109_20fe_98b2_db2e,
+                0x370c_f56b_c521_8749,
+                0x5781_e8d3_d207_adc8,
+                0x0f45_cf97_4d2c_9edb,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8687_a1c9_eceb_44d4,
+                0x585a_81d1_b333_568b,
+                0x6b79_edfd_24f5_abcc,
+                0x1190_9c81_a165_1804,
+            ]),
+            Fp::from_raw([
+                0xfd7b_bd67_9233_0d16,
+                0x6917_672f_2d5a_1041,
+                0x09f3_b891_a0e3_da4d,
+                0x2990_b23c_8188_2f77,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x2ecf_461e_4aef_7277,
+                0xe0a0_83ea_9a16_dc10,
+                0xcd55_60e0_821e_7285,
+                0x0609_551b_1471_6ca3,
+            ]),
+            Fp::from_raw([
+                0xc088_0eaa_08d0_3f77,
+                0x6175_de17_55f4_f93d,
+                0xfd93_dced_2467_354b,
+                0x0c8c_1abd_fab9_9d03,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8a7a_55d0_8f2e_0b10,
+                0x0db3_fed2_98ec_09f7,
+                0xbd02_f33f_8bec_6c73,
+                0x138b_d098_c492_3b9f,
+            ]),
+            Fp::from_raw([
+                0xf21b_b5a3_190f_14c0,
+                0xdcd0_b45c_e07d_9ae3,
+                0x4673_f0f7_7161_ae55,
+                0x2e61_e4bc_0216_3011,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x5ddb_4082_60d8_e910,
+                0x626a_bd1c_2240_1c90,
+                0x65a9_c406_0ce3_297c,
+                0x0124_8609_13e3_df8f,
+            ]),
+            Fp::from_raw([
+                0x6c6a_c8b7_ed05_2ec8,
+                0x125f_24c5_701d_9828,
+                0x3ec1_0480_4d95_5cbe,
+                0x0138_07f8_9c39_4a13,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xe855_78ab_b0fc_2fe5,
+                0x59aa_4440_50c8_f4c4,
+                0x132a_a9ee_aec0_8d2f,
+                0x2e88_d1a6_938f_0788,
+            ]),
+            Fp::from_raw([
+                0xe8fc_c1f2_6abf_3104,
+                0x2257_be3c_3ba6_07c2,
+                0x0a0c_bf64_e1f1_787e,
+                0x01f3_d24f_17cf_c605,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xd9b6_2f82_f0f8_d0bf,
+                0x3fe6_c76a_82a9_16bf,
+                0x3b9d_4f13_3d41_fb5b,
+                0x1fe1_cb0e_2ae1_69f8,
+            ]),
+            Fp::from_raw([
+                0x5e33_77f9_1770_71f3,
+                0x1994_6f3d_8d1c_48bf,
+                0x3533_2922_1229_827e,
+                0x0ef7_9351_2294_09cd,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8908_8608_373b_eda9,
+                0x5075_5188_3127_860e,
+                0x1c48_93ef_77a9_d111,
+                0x18fb_2e46_fc1b_90fe,
+            ]),
+            Fp::from_raw([
+                0x1916_bd57_120d_1868,
+                0xc0ad_6263_a68c_5cb6,
+                0x4c32_ef07_61e2_3a3c,
+                0x077a_fe25_79f4_2ec1,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x48b1_86f1_490b_7b99,
+                0x4e2a_c983_6fdd_65d2,
+                0x2642_c04c_cf8a_6ea5,
+                0x0797_6909_2daa_5a75,
+            ]),
+            Fp::from_raw([
+                0x663c_76cf_76ba_b4a5,
+                0x67eb_9734_606b_676b,
+                0x254e_b6e0_9c5c_8bfd,
+                0x1d8b_f229_c199_68f0,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x428f_29f6_ec1b_ddad,
+                0x7664_f142_36f1_7256,
+                0xf935_56e4_9e4b_3773,
+                0x2a33_b7d8_55e7_fe55,
+            ]),
+            Fp::from_raw([
+                0x340d_c318_7ccc_a8b2,
+                0xb205_6077_e7aa_7536,
+                0x4ec1_61c8_6e84_ba6a,
+                0x25b0_331d_7e2b_15af,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xf4f1_3f22_3424_74e2,
+                0xffcf_8ccb_b92c_16e2,
+                0xccbf_45e4_8102_11b0,
+                0x0762_098f_5fe2_6598,
+            ]),
+            Fp::from_raw([
+                0x6dec_e2b6_172c_2514,
+                0x2362_e144_185c_7071,
+                0x6d0d_a4c0_07b1_bda4,
+                0x0e23_4d72_0d70_b288,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x135c_8111_52aa_4b60,
+                0xea72_182f_11c0_c60d,
+                0x6e37_42e7_20b7_fec2,
+                0x1d82_bedc_cd2b_c8a0,
+            ]),
+            Fp::from_raw([
+                0x14bd_1c69_0a17_c979,
+                0xc339_7fd6_b94d_4813,
+                0xa5e9_a3e7_d059_30b7,
+                0x0480_064d_4b3e_b0ad,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x1867_f746_4fb0_c11a,
+                0xc8e4_5805_6856_0cf4,
+                0xf759_3fbb_1140_edc8,
+                0x10a8_9276_3b3c_ca9e,
+            ]),
+            Fp::from_raw([
+                0x4dde_4cd7_f4de_8b91,
+                0x5978_b315_667a_e471,
+                0xc921_f9b2_5536_8078,
+                0x0b5e_c645_48ea_841a,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xce3c_65c9_db93_1d46,
+                0xd780_10ed_d030_e1a9,
+                0x4976_1bd7_131d_faeb,
+                0x1055_4aca_4e34_8e59,
+            ]),
+            Fp::from_raw([
+                0xac64_8fab_3db0_cff6,
+                0xb9be_9de3_06e1_50d4,
+                0x8b93_6554_62b1_f475,
+                0x15be_66f3_8d86_b099,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xaaf3_08e4_27d3_dbe8,
+                0xf6c2_6e9d_4ab0_c23c,
+                0x82d1_8295_7ffa_d01b,
+                0x176a_d360_0fd3_4911,
+            ]),
+            Fp::from_raw([
+                0xce82_b5cf_0af2_e6f2,
+                0xe3be_b20f_4fc1_1bd3,
+                0x9335_001d_705a_c125,
+                0x2b6f_355b_3dbf_65f0,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xb6de_9ff7_88c7_7451,
+                0xa844_8c51_288f_a296,
+                0x81d7_c89e_defb_32d1,
+                0x01c8_5c06_a6d5_d40d,
+            ]),
+            Fp::from_raw([
+                0x51a7_0aaa_c246_0d79,
+                0x2361_c389_e43f_7d1f,
+                0xbd9a_51d7_6b2e_25f8,
+                0x20e1_e876_c474_6a0c,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x2d98_7dda_9217_fa08,
+                0xee3b_08ce_7377_0139,
+                0x2a02_4b63_7bc3_5a29,
+                0x20e4_6219_f684_186d,
+            ]),
+            Fp::from_raw([
+                0x28be_0f2e_d609_1367,
+                0x4807_6636_7a8b_d90e,
+                0x654e_9879_0727_7c24,
+                0x2ea7_279d_b9f2_aa0f,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x641b_e950_9178_0f74,
+                0x0969_dc07_7c91_71b1,
+                0x3620_96d4_72bc_75ca,
+                0x136b_e2a7_f189_24c9,
+            ]),
+            Fp::from_raw([
+                0xc924_baf0_df5a_0e9e,
+                0x19ed_5736_fbc8_f1f6,
+                0x3067_c430_0fb0_f511,
+                0x1ca2_0335_01ba_a3f7,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xda81_0832_b48a_50c7,
+                0x2582_4f7a_4a9d_9fa1,
+                0xecaa_75e4_95f3_4e35,
+                0x0a82_f199_c250_5277,
+            ]),
+            Fp::from_raw([
+                0x7925_a2de_a580_b7d5,
+                0x9f37_a272_2e7e_d9eb,
+                0xe92f_efb0_d7f7_782a,
+                0x0ecf_1048_5307_b4ba,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6aeb_bbb3_39a3_936b,
+                0x6861_5c84_78f1_3af1,
+                0xd12a_a22f_08a8_296d,
+                0x07b6_4213_8dfd_6a6d,
+            ]),
+            Fp::from_raw([
+                0xcbc3_b6dd_0f1a_2150,
+                0xd70e_760b_a76d_61e9,
+                0xd225_6d34_921f_b86e,
+                0x1d9d_da43_a255_93ff,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x958c_e2fd_3d7d_2fce,
+                0xd536_7eb0_8213_d392,
+                0x1dc9_1136_c91c_6bcc,
+                0x2f1a_f228_520c_8b75,
+            ]),
+            Fp::from_raw([
+                0x1855_1a45_a6cd_e123,
+                0xe61a_da62_5a1a_2b6b,
+                0x5c6d_6c1a_b3de_4aba,
+                0x1fec_fe83_3ad5_4045,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xbdd3_ad28_e4a2_3c88,
+                0x5657_ff8a_77ab_e637,
+                0x3b0d_7583_4602_2757,
+                0x18fc_8e60_8c73_5b2b,
+            ]),
+            Fp::from_raw([
+                0x8638_8a75_47fa_a815,
+                0xa43c_e0b6_1878_3a55,
+                0x6ebf_03cb_3f53_aba8,
+                0x28f7_40bc_1182_e970,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x2bed_35b7_1469_66a4,
+                0xe960_a485_1cfd_1382,
+                0x94ad_301e_4b99_8d29,
+                0x0479_98cc_0af5_a26b,
+            ]),
+            Fp::from_raw([
+                0x30a1_4a69_2b77_7b70,
+                0x9725_c7b5_2e88_0ee1,
+                0xdda4_3e41_5e1b_9a3a,
+                0x1b5f_1525_b31d_b911,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x945f_a57e_d5c8_de6e,
+                0xf770_ae9b_d1d7_b1af,
+                0x5f65_e965_a90e_ac9b,
+                0x275a_83fa_5d19_b453,
+            ]),
+            Fp::from_raw([
+                0xce3e_40a6_f6a2_7aa8,
+                0x2a56_3359_808c_9897,
+                0xcb43_0568_e49b_c9dc,
+                0x2e87_8925_7ed2_cbcc,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xe292_5a39_c8e2_c7c1,
+                0xf60c_3450_0dcd_6e41,
+                0xeb27_21a4_c09e_9d17,
+                0x0927_f46c_fe80_feef,
+            ]),
+            Fp::from_raw([
+                0x0e48_4bbf_e769_8101,
+                0xfd8f_b2cf_bc1e_cf9e,
+                0xc376_19bf_e6ab_6a97,
+                0x1f86_8ae0_4832_a5db,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9ce6_d56c_9b45_eff4,
+                0x65d9_4ba8_0f30_8fb1,
+                0x09b7_3f74_5b2d_efed,
+                0x09d7_a11e_27d2_f531,
+            ]),
+            Fp::from_raw([
+                0xb0c3_51aa_2b87_9dff,
+                0x9a7b_2592_4fda_5995,
+                0x104e_1c28_23fb_7c5b,
+                0x282d_857c_fe8d_a3b5,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x987f_b0f6_ff49_c217,
+                0x2757_6e13_5c07_44f6,
+                0x3f34_9ff8_30ae_663b,
+                0x20ba_8a9f_cec8_15b1,
+            ]),
+            Fp::from_raw([
+                0x66a8_ae4d_cbfb_136e,
+                0x6d57_b471_ddd2_ab1b,
+                0x4589_fba1_2e65_7d22,
+                0x11b6_afc9_1e32_f1ca,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9081_4173_6ceb_c3be,
+                0x4788_eec2_c72d_df3f,
+                0x316e_335c_7d93_db34,
+                0x2e66_6402_ac9c_c588,
+            ]),
+            Fp::from_raw([
+                0x7492_0f27_94f1_8595,
+                0x7057_aec5_c9ed_9a1a,
+                0xa202_a110_e283_faad,
+                0x1752_2e0e_9e64_f795,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x878c_b987_8edb_d3b9,
+                0x9e6a_db40_e2ff_24b7,
+                0xe20b_470c_ad4c_c731,
+                0x2d2e_d17f_7a1f_3ee9,
+            ]),
+            Fp::from_raw([
+                0x3c93_7da1_6c7b_f9f4,
+                0x8f75_e54a_8136_f4d7,
+                0xa96f_a276_e89e_85d0,
+                0x1a81_efb1_9d7e_1eda,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x15a1_6bb3_bd33_e237,
+                0xf299_c5f4_51c7_a0d5,
+                0x210a_7b44_e52e_5630,
+                0x27ff_57c1_ca84_7e57,
+            ]),
+            Fp::from_raw([
+                0x11be_26a7_f5fb_1a94,
+                0x840d_117b_3c6a_5a0a,
+                0x3c5b_e960_31bf_a167,
+                0x1c1a_8e22_230a_bcd1,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9d58_36f9_c19a_5657,
+                0x0d81_e777_4d2c_32b5,
+                0x4362_7a9c_d533_e425,
+                0x02a1_c3f1_5d49_27c8,
+            ]),
+            Fp::from_raw([
+                0x85ac_b219_8993_57e4,
+                0xaf03_73a1_0ac1_12e1,
+                0x1c52_499b_37cb_4be1,
+                0x2ddb_b723_9eb9_04d8,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x8a86_fad6_da0a_fb60,
+                0x8edf_8bc2_5eda_dab4,
+                0x4e0d_6fae_c54b_e81d,
+                0x0dff_1983_9308_5a75,
+            ]),
+            Fp::from_raw([
+                0xb6d5_f504_bd16_45ca,
+                0xec8d_b287_2878_9f28,
+                0x7627_5fcc_589d_038d,
+                0x10d5_0c24_7314_6bbc,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x536b_08b4_476c_1538,
+                0x231b_a459_4850_6282,
+                0x2a53_dfd4_0e10_22e6,
+                0x061e_8328_fb55_93f9,
+            ]),
+            Fp::from_raw([
+                0xcfa5_a0dd_9f6d_9784,
+                0x067d_ebf3_f07d_3c51,
+                0xd90b_644b_ee31_ac58,
+                0x1b58_9243_8471_98de,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xe6f6_7a42_0a3b_d1f7,
+                0x190f_0bdc_ced9_9d5c,
+                0x9863_b053_bd4c_6087,
+                0x04b0_0c0d_a1f8_51e5,
+            ]),
+            Fp::from_raw([
+                0x3bec_8cdc_b5dd_fd67,
+                0x27f8_a8d4_2e35_018b,
+                0x126a_7016_3009_a7ac,
+                0x2399_41a4_6c2b_93d9,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xe87e_f681_4acf_2ea2,
+                0xbfc9_bc3e_c0bf_ecb4,
+                0xc2c3_5377_cb0a_3712,
+                0x204f_26ca_7993_b03a,
+            ]),
+            Fp::from_raw([
+                0x48ef_117e_926d_721c,
+                0x5747_cf73_08d5_15e3,
+                0x39d8_32d8_be16_5a1e,
+                0x085a_ff9c_7fda_dba0,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xefd3_75df_00ea_2068,
+                0xf10e_57d0_5e09_3158,
+                0xc4ae_9db0_44c0_b0b3,
+                0x2490_42a8_dc11_1f27,
+            ]),
+            Fp::from_raw([
+                0x3e08_2597_7413_e96b,
+                0xf845_5066_5203_327b,
+                0x5428_54f3_0298_03e2,
+                0x06e7_99bc_df2b_4a74,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xc612_4961_83b8_7996,
+                0xfffe_d333_cae1_2085,
+                0xa9f4_d2c0_0292_1bc3,
+                0x1cb3_caed_4bff_b6ac,
+            ]),
+            Fp::from_raw([
+                0xdcd9_9e84_d310_d51c,
+                0xdd6e_a03c_ab56_6889,
+                0x28a1_28bf_d4fa_a6a3,
+                0x0b47_e975_5fae_4801,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x3f0b_b730_da88_6a4f,
+                0x5062_93bc_024f_d1ca,
+                0x920a_0c9f_d2c3_60a6,
+                0x0c7e_4cea_365c_2061,
+            ]),
+            Fp::from_raw([
+                0x8dcd_7ba4_1872_15df,
+                0x3dbe_1b20_d9d6_988c,
+                0xbbaa_30d9_64d6_f6f6,
+                0x21da_1f70_1bac_77bc,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xdd1a_9486_e3c6_cc55,
+                0xb86b_47bd_1996_5b6e,
+                0x7090_5fb6_7899_d10d,
+                0x09ae_612e_8ba1_ca13,
+            ]),
+            Fp::from_raw([
+                0xfe1f_7a0e_3b95_cf3d,
+                0xaab7_5445_b0c9_9373,
+                0xc150_f284_4911_90e6,
+                0x262e_1e0b_56ca_c47f,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xaa5d_3f67_491d_34bd,
+                0x2a7b_fa5f_29fd_4dde,
+                0x2c87_c293_e3bb_7c9e,
+                0x234b_f4a7_dce7_587c,
+            ]),
+            Fp::from_raw([
+                0xeee6_7f6c_b69f_70c2,
+                0x58d2_690e_213d_7432,
+                0x2d0a_527c_ac74_4fb6,
+                0x2f6c_bac6_94c8_86b0,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x9a33_0087_6dec_3ad9,
+                0xd52a_a184_2fff_818d,
+                0x7bb8_c9fd_f78b_7ade,
+                0x22ac_cb18_b7c4_9b4b,
+            ]),
+            Fp::from_raw([
+                0xbf62_3a1d_f7f8_f2fc,
+                0x2eab_d918_2a0b_3d3c,
+                0xd659_f22d_2c77_be30,
+                0x081e_2f06_52f8_98c6,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x16b3_186a_d675_b935,
+                0x534b_9628_90f3_ffc0,
+                0xcea3_ada7_5d66_9b8c,
+                0x12c0_a25e_70d0_06ec,
+            ]),
+            Fp::from_raw([
+                0x9f8d_4f23_81df_3259,
+                0xb56e_fd34_9edd_56f4,
+                0x2fd6_fc86_9df2_4d7a,
+                0x10ef_9c23_8481_28cc,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x0bda_3479_62b2_40f0,
+                0xc2c1_d41b_9491_e062,
+                0xd4a8_1262_b71d_f1bc,
+                0x2161_cd28_0772_819d,
+            ]),
+            Fp::from_raw([
+                0xb7f4_fdd1_2cb8_d38a,
+                0x9253_3364_f799_bc41,
+                0xb406_5900_41b5_2482,
+                0x2ceb_b0ae_5108_318e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xb759_e087_09a0_a62f,
+                0xf285_2283_a656_880f,
+                0xfe4f_7c22_d956_1f3b,
+                0x2b20_92f8_6b59_79a7,
+            ]),
+            Fp::from_raw([
+                0x82b2_d850_7a06_5fed,
+                0x50cf_c900_cf64_3e73,
+                0x0814_6188_425a_4424,
+                0x1566_b340_2d77_4b8c,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x1a16_3e60_1d1a_0173,
+                0x627c_3635_fccf_8d3d,
+                0x8fb4_c56d_6c57_ba01,
+                0x11a3_16aa_3160_7f26,
+            ]),
+            Fp::from_raw([
+                0x52e3_53d9_c287_4e44,
+                0x08a5_e843_4644_6091,
+                0xb782_648b_560e_5954,
+                0x0de7_ee06_9c93_4256,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6712_8b89_49ea_b1af,
+                0x5845_c36a_e706_c72e,
+                0xcc84_df02_9770_8c5e,
+                0x02d3_6f40_2924_5704,
+            ]),
+            Fp::from_raw([
+                0x284d_7951_d546_f858,
+                0x99bd_e46c_d82d_abdc,
+                0xf531_98c2_17fb_34e8,
+                0x01b8_cc32_6b5e_e160,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x96ec_e854_0755_0ebb,
+                0x4cbf_9203_fd4d_df8a,
+                0x1068_9fb2_187b_7169,
+                0x2762_5da0_f73e_a071,
+            ]),
+            Fp::from_raw([
+                0x99ba_025b_94e4_790d,
+                0xdea3_49c3_edda_06cb,
+                0xcdc0_da58_1a69_50f6,
+                0x1cd8_338a_3e5b_1ad7,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x7a10_a840_7274_1a56,
+                0x78a8_aed8_d3e6_7e87,
+                0xa763_856c_94b6_438c,
+                0x05ea_02d6_5b20_9f6d,
+            ]),
+            Fp::from_raw([
+                0x8177_43ce_0333_0a55,
+                0x8b62_50ce_d627_e810,
+                0x5366_cfcf_284a_895d,
+                0x09f7_cb68_d4e3_88f8,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xdfeb_969e_9d5c_1212,
+                0xec13_995a_202e_4ebc,
+                0x27b0_43f5_e58d_bd1a,
+                0x18c6_230d_dc0f_8968,
+            ]),
+            Fp::from_raw([
+                0x0578_2320_96db_6dcd,
+                0x452e_4f07_bfd2_e1a1,
+                0x1a91_c0a0_fdcc_daa8,
+                0x073a_6114_b997_285e,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xa3e7_742b_2f37_be7f,
+                0xfe01_3f39_b166_0ce7,
+                0x22c6_a1fc_0838_adf5,
+                0x2e78_7463_40b2_a6d2,
+            ]),
+            Fp::from_raw([
+                0x8e67_5259_d3e5_c851,
+                0x49b7_ea84_6553_def2,
+                0x0630_3ad8_e5e4_bf42,
+                0x07aa_27e7_150b_addd,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x0cbc_9834_5715_ead8,
+                0xa902_73cc_b464_3f68,
+                0xbf62_3d27_12cf_4d9f,
+                0x0b66_fdec_210e_a4ea,
+            ]),
+            Fp::from_raw([
+                0x41ac_ea41_c49a_a5e3,
+                0x9c06_01ce_0b14_0be6,
+                0x9b63_3b8a_4d6b_e51c,
+                0x2fb6_a29d_9f39_4a58,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x75a6_9dc8_89b2_ce2a,
+                0x8569_fb24_3d04_9bd6,
+                0xfc84_5e9c_1c2c_d128,
+                0x2902_5cc6_6fd0_41c4,
+            ]),
+            Fp::from_raw([
+                0xb85d_534b_17be_3f48,
+                0xf7ed_731c_fa69_5168,
+                0x4126_214a_b9c6_27a6,
+                0x1509_63f0_aca9_bcbe,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x98a0_8a32_a61a_8a65,
+                0xb5bc_a2e4_7bec_0d57,
+                0x3f72_c1bf_c665_6eb7,
+                0x0ed5_9780_3022_5766,
+            ]),
+            Fp::from_raw([
+                0xc8d3_0c06_143c_c084,
+                0x1c11_888a_3000_debf,
+                0x3d30_ae18_8c76_7f39,
+                0x07e1_9cb8_a893_369b,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xcd86_0afb_4a3a_a272,
+                0x2b6e_cfe5_28d2_c052,
+                0xe5f1_eeea_fb5e_b8ec,
+                0x0600_c7d2_b694_6345,
+            ]),
+            Fp::from_raw([
+                0xa012_5119_f038_5705,
+                0x773f_2cd0_a480_e19e,
+                0x3022_a1f3_3d65_23b4,
+                0x0596_083b_6c97_2bc1,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x6db2_1043_631e_24c4,
+                0x2e64_5130_99a8_e1ef,
+                0x7f98_b9d8_663d_85db,
+                0x210b_5c36_f27a_07d9,
+            ]),
+            Fp::from_raw([
+                0x26a8_f493_0b78_83f9,
+                0x01c2_4898_74e9_1593,
+                0xc7bb_9f3d_563c_5cc2,
+                0x13bb_2764_bf14_75cf,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x219e_4b75_76e2_4d30,
+                0x2878_72b1_81e8_9997,
+                0x80eb_0828_62a7_6757,
+                0x202c_f557_d625_c260,
+            ]),
+            Fp::from_raw([
+                0x28ea_1f95_fd88_24b2,
+                0x01fb_c5a0_3d90_5a47,
+                0x76d4_9e97_142d_2206,
+                0x0e56_1c3f_8bd4_f76e,
+            ]),
+        ],
+    },
+    SparseMds {
+        row: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0x7c33_ae9e_d789_0597,
+                0xd57d_d859_bbe8_2730,
+                0x4717_85de_07bd_9809,
+                0x0de2_0097_480e_7555,
+            ]),
+            Fp::from_raw([
+                0x0cde_8edd_76d2_e97d,
+                0xfd28_2561_3cb7_2bb8,
+                0xb810_df8c_5788_eebc,
+                0x072f_2a62_87fb_984b,
+            ]),
+        ],
+        column: [
+            Fp::from_raw([
+                0xfedb_6859_2ba8_118b,
+                0x94be_7c11_ad24_378b,
+                0xb2b7_0caf_5c36_a7b1,
+                0x109b_7f41_1ba0_e4c9,
+            ]),
+            Fp::from_raw([
+                0xd629_40bc_de0b_d771,
+                0x2cc8_fdd1_415c_3dde,
+                0xb9c3_6c76_4379_dbca,
+                0x2969_f27e_ed31_a480,
+            ]),
+            Fp::from_raw([
+                0x3262_44ee_65a1_b1a7,
+                0xe6cd_79e2_8c5b_3753,
+                0x0d5f_9e65_4638_065c,
+                0x1430_21ec_686a_3f33,
+            ]),
+        ],
+    },
+];
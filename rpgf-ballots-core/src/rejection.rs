@@ -0,0 +1,71 @@
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// What the guests do with a ballot that fails validation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectionPolicy {
+    /// Panic, so that no proof is produced for the ballot set.
+    #[default]
+    Abort,
+    /// Leave the ballot out of the tally and list it in the journal's rejections.
+    Skip,
+}
+
+/// Why a ballot was rejected. The explicit discriminants are the reason codes reported to
+/// verifiers, and must not be reused.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum RejectionReason {
+    /// The ballot isn't a valid json array of votes.
+    MalformedBallot = 1,
+    /// The signature isn't a valid signature of the ballot by its verifying key.
+    InvalidSignature = 2,
+    /// The verifying key isn't a valid sec1 encoded secp256k1 key.
+    InvalidVerifyingKey = 3,
+    /// There is no verifying key (or Safe address) for the ballot.
+    MissingVerifyingKey = 4,
+    /// The ballot votes for a project that isn't in the project registry.
+    UnknownProject = 5,
+    /// A vote amount can't be represented exactly with the configured amount scale.
+    InvalidAmount = 6,
+    /// The ballot was signed by an address that isn't in the badgeholder allowlist.
+    NotEligible = 7,
+    /// Fewer owners than the threshold of the Safe that cast the ballot signed it.
+    InsufficientSignatures = 8,
+    /// The ballot was cast by a Safe that isn't in the owner sets.
+    UnknownSafe = 9,
+    /// The host couldn't recover the verifying key of the record's address from the signature,
+    /// and passed an empty one.
+    UnrecoveredSigner = 10,
+}
+
+impl RejectionReason {
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for RejectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            RejectionReason::MalformedBallot => "malformed ballot",
+            RejectionReason::InvalidSignature => "invalid signature",
+            RejectionReason::InvalidVerifyingKey => "invalid verifying key",
+            RejectionReason::MissingVerifyingKey => "missing verifying key",
+            RejectionReason::UnknownProject => "unknown project",
+            RejectionReason::InvalidAmount => "invalid amount",
+            RejectionReason::NotEligible => "signer not eligible",
+            RejectionReason::InsufficientSignatures => "insufficient safe owner signatures",
+            RejectionReason::UnknownSafe => "unknown safe",
+            RejectionReason::UnrecoveredSigner => "signer not recovered from the signature",
+        };
+        write!(f, "{} (code {})", reason, self.code())
+    }
+}
+
+/// A ballot left out of the tally, identified by its index in the processed inputs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rejection {
+    pub index: u32,
+    pub reason: RejectionReason,
+}
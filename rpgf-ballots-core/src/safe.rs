@@ -0,0 +1,117 @@
+use crate::eligibility::Address;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Length of each `r || s || v` owner signature in a Safe packed signature.
+pub const SAFE_SIGNATURE_LEN: usize = 65;
+
+/// The owners of a Safe multisig badgeholder and how many of them must sign its ballot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeOwners {
+    pub safe: Address,
+    pub threshold: u32,
+    /// Sorted in ascending order.
+    pub owners: Vec<Address>,
+}
+
+impl SafeOwners {
+    pub fn is_owner(&self, address: &Address) -> bool {
+        self.owners.binary_search(address).is_ok()
+    }
+}
+
+/// Looks up the owner set of `safe`.
+pub fn find_safe<'a>(owner_sets: &'a [SafeOwners], safe: &Address) -> Option<&'a SafeOwners> {
+    owner_sets.iter().find(|owners| owners.safe == *safe)
+}
+
+#[derive(Deserialize)]
+struct SafeOwnersJson {
+    safe: String,
+    threshold: u32,
+    owners: Vec<String>,
+}
+
+/// Parses a json owner set file, an array of
+/// `{"safe": "0x..", "threshold": 2, "owners": ["0x..", ..]}` objects.
+///
+/// Every Safe must be listed once, with distinct owners and a threshold between 1 and the
+/// number of owners, as the Safe contract itself enforces.
+pub fn parse_safe_owners(json: &[u8]) -> Result<Vec<SafeOwners>, SafeOwnersError> {
+    let sets: Vec<SafeOwnersJson> =
+        serde_json::from_slice(json).map_err(|e| SafeOwnersError::Json(e.to_string()))?;
+    let mut safes = BTreeSet::new();
+    let mut owner_sets = Vec::with_capacity(sets.len());
+    for set in sets {
+        let safe = parse_address(&set.safe)?;
+        if !safes.insert(safe) {
+            return Err(SafeOwnersError::DuplicateSafe(set.safe));
+        }
+        let mut owners = set
+            .owners
+            .iter()
+            .map(|owner| parse_address(owner))
+            .collect::<Result<Vec<_>, _>>()?;
+        owners.sort_unstable();
+        let owner_count = owners.len();
+        owners.dedup();
+        if owners.len() != owner_count {
+            return Err(SafeOwnersError::DuplicateOwner(set.safe));
+        }
+        if set.threshold == 0 || set.threshold as usize > owners.len() {
+            return Err(SafeOwnersError::InvalidThreshold {
+                safe: set.safe,
+                threshold: set.threshold,
+            });
+        }
+        owner_sets.push(SafeOwners {
+            safe,
+            threshold: set.threshold,
+            owners,
+        });
+    }
+    Ok(owner_sets)
+}
+
+fn parse_address(address: &str) -> Result<Address, SafeOwnersError> {
+    let mut bytes = [0; 20];
+    hex::decode_to_slice(address.trim_start_matches("0x"), &mut bytes)
+        .map_err(|_| SafeOwnersError::InvalidAddress(address.to_string()))?;
+    Ok(bytes)
+}
+
+/// Why an owner set file is invalid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SafeOwnersError {
+    Json(String),
+    InvalidAddress(String),
+    DuplicateSafe(String),
+    DuplicateOwner(String),
+    InvalidThreshold { safe: String, threshold: u32 },
+}
+
+impl fmt::Display for SafeOwnersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SafeOwnersError::Json(e) => write!(f, "owner sets are not valid json: {}", e),
+            SafeOwnersError::InvalidAddress(address) => {
+                write!(f, "invalid address {}, expected 20 hex bytes", address)
+            }
+            SafeOwnersError::DuplicateSafe(safe) => write!(f, "safe {} is listed twice", safe),
+            SafeOwnersError::DuplicateOwner(safe) => {
+                write!(f, "safe {} lists an owner twice", safe)
+            }
+            SafeOwnersError::InvalidThreshold { safe, threshold } => write!(
+                f,
+                "safe {} has a threshold of {}, expected between 1 and its number of owners",
+                safe, threshold
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SafeOwnersError {}
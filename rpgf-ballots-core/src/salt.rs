@@ -0,0 +1,54 @@
+use crate::ballot::ProjectId;
+use crate::eligibility::Address;
+use crate::felt::Felt;
+use sha3::{Digest, Keccak256};
+
+// Blinding salts of the project hashes, so that the hashes of projects with few votes can't be
+// brute-forced from a rough idea of the amounts. The operator picks a secret and commits to it in
+// the journal header, and reveals the salts of single projects to auditors. The votes roots and
+// their leaves are blinded with salts and nonces derived from the same secret.
+
+/// The commitment to the operator's salt secret committed to the journal header.
+pub fn salt_commitment(secret: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"rpgf salt commitment");
+    hasher.update(secret);
+    hasher.finalize().into()
+}
+
+/// The salt of a project's hash: the keccak256 hash of the secret and the project id, with its
+/// most significant byte cleared so that it is a field element.
+pub fn project_salt(secret: &[u8; 32], project_id: &ProjectId) -> Felt {
+    derive_salt(b"rpgf project salt", secret, &project_id.0)
+}
+
+/// The salt of a project's votes root, derived like [`project_salt`] under another domain so
+/// that the inclusion proofs handed to badgeholders, which carry it, never reveal the salt of
+/// the project's hash.
+pub fn votes_root_salt(secret: &[u8; 32], project_id: &ProjectId) -> Felt {
+    derive_salt(b"rpgf votes root salt", secret, &project_id.0)
+}
+
+/// The nonce blinding a badgeholder's commitment in the vote leaves, derived from the secret
+/// and the badgeholder's address. Only handed to that badgeholder, in their inclusion proofs, so
+/// that the siblings in a proof can't be brute-forced over the badgeholders and amounts.
+pub fn leaf_nonce(secret: &[u8; 32], address: &Address) -> Felt {
+    derive_salt(b"rpgf leaf nonce", secret, address)
+}
+
+fn derive_salt(domain: &[u8], secret: &[u8; 32], message: &[u8]) -> Felt {
+    let mut hasher = Keccak256::new();
+    hasher.update(domain);
+    hasher.update(secret);
+    hasher.update(message);
+    let bytes: [u8; 32] = hasher.finalize().into();
+
+    // little endian limbs of the first 31 bytes
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes[..31].chunks(8)) {
+        let mut le_bytes = [0u8; 8];
+        le_bytes[..chunk.len()].copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(le_bytes);
+    }
+    Felt(limbs)
+}
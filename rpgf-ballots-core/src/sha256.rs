@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+// Sha256 hashes of files split between the shard guests (shard.rs), so that the shards guest
+// (shards.rs) commits the hashes of the whole files from the shard journals alone. Each shard
+// carries on the hash from the state the previous shard left it in.
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+const BLOCK_LEN: usize = 64;
+
+/// The state of a sha256 hash in progress.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sha256State {
+    /// The compression state after the whole blocks hashed so far.
+    pub state: [u32; 8],
+    /// The bytes hashed since the last whole block.
+    pub buffer: Vec<u8>,
+    /// Number of bytes hashed so far.
+    pub len: u64,
+}
+
+impl Default for Sha256State {
+    fn default() -> Self {
+        Sha256State {
+            state: INITIAL_STATE,
+            buffer: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl Sha256State {
+    /// Hashes `bytes` after the ones hashed so far.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        self.len += bytes.len() as u64;
+        if !self.buffer.is_empty() {
+            let fill = BLOCK_LEN.saturating_sub(self.buffer.len()).min(bytes.len());
+            self.buffer.extend_from_slice(&bytes[..fill]);
+            bytes = &bytes[fill..];
+            if self.buffer.len() < BLOCK_LEN {
+                return;
+            }
+            compress(&mut self.state, &self.buffer);
+            self.buffer.clear();
+        }
+        self.buffer = compress(&mut self.state, bytes).to_vec();
+    }
+
+    /// The sha256 hash of every byte hashed.
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.len * 8;
+        self.buffer.push(0x80);
+        while self.buffer.len() % BLOCK_LEN != BLOCK_LEN - 8 {
+            self.buffer.push(0);
+        }
+        self.buffer.extend_from_slice(&bit_len.to_be_bytes());
+        compress(&mut self.state, &self.buffer);
+
+        let mut digest = [0u8; 32];
+        for (bytes, word) in digest.chunks_mut(4).zip(self.state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+/// Compresses the whole blocks of `bytes` into `state`, returning the bytes left over.
+fn compress<'a>(state: &mut [u32; 8], bytes: &'a [u8]) -> &'a [u8] {
+    let whole = bytes.len() - bytes.len() % BLOCK_LEN;
+    for block in bytes[..whole].chunks(BLOCK_LEN) {
+        let block: [u8; BLOCK_LEN] = block.try_into().unwrap();
+        sha2::compress256(state, &[block.into()]);
+    }
+    &bytes[whole..]
+}
+
+/// A shard's part in the hash of a file split between the shards: the state of the hash before
+/// and after the shard's bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashSpan {
+    pub before: Sha256State,
+    pub after: Sha256State,
+}
+
+impl HashSpan {
+    /// Hashes `bytes` from the `before` state.
+    pub fn new(before: Sha256State, bytes: &[u8]) -> Self {
+        let mut after = before.clone();
+        after.update(bytes);
+        HashSpan { before, after }
+    }
+}
+
+/// The state of the hash of a bincode encoded sequence of `len` elements, before the encoding
+/// of the first one: bincode prefixes a sequence with its length.
+pub fn bincode_seq_state(len: usize) -> Sha256State {
+    let mut state = Sha256State::default();
+    state.update(&(len as u64).to_le_bytes());
+    state
+}
+
+/// The encoding of the elements of a bincode encoded sequence, without the length prefix.
+pub fn bincode_seq_elements(encoded: &[u8]) -> &[u8] {
+    &encoded[8..]
+}
@@ -0,0 +1,137 @@
+use crate::ballot::Ballot;
+use crate::eip712::ballot_digest;
+use crate::eligibility::Address;
+use crate::rejection::RejectionReason;
+use crate::safe::{SafeOwners, SAFE_SIGNATURE_LEN};
+use alloc::format;
+use alloc::vec::Vec;
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+// The signature schemes ballots are accepted under, shared by the guests and the host so that
+// they accept exactly the same signatures.
+
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
+
+/// The EIP-191 hash `eth_sign` / `personal_sign` signs for `message`.
+pub fn hash_message(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// The hashes a ballot signature may sign: the EIP-712 typed data digest of the ballot if a
+/// domain separator is given, then the EIP-191 hashes of its json and of the hex string of its
+/// keccak hash.
+pub fn message_hashes(ballot: &Ballot, domain_separator: Option<&[u8; 32]>) -> Vec<[u8; 32]> {
+    let mut message_hashes = Vec::with_capacity(3);
+    if let Some(domain_separator) = domain_separator {
+        message_hashes.push(ballot_digest(domain_separator, ballot));
+    }
+    message_hashes.push(hash_message(ballot.raw()));
+    // double hash the ballot data
+    let khash = keccak256(ballot.raw());
+    message_hashes.push(hash_message(format!("0x{}", hex::encode(khash)).as_bytes()));
+    message_hashes
+}
+
+/// The Ethereum address of a verifying key: the last 20 bytes of the keccak256 hash of its
+/// uncompressed point, without the sec1 tag byte.
+pub fn signer_address(verifying_key: &VerifyingKey) -> Address {
+    let point = verifying_key.to_encoded_point(false);
+    let hash = keccak256(&point.as_bytes()[1..]);
+    let mut address = [0; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Recovers the verifying key of a 65 byte `r || s || v` signature of `prehash`, with a `v` of
+/// 27 or 28, or the bare recovery id.
+pub fn recover_signer(signature: &[u8], prehash: &[u8; 32]) -> Option<VerifyingKey> {
+    if signature.len() != SAFE_SIGNATURE_LEN {
+        return None;
+    }
+    let recovery_id = match signature[64] {
+        v @ (0 | 1) => RecoveryId::from_byte(v)?,
+        v @ (27 | 28) => RecoveryId::from_byte(v - 27)?,
+        _ => return None,
+    };
+    let signature = Signature::try_from(&signature[..64]).ok()?;
+    VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id).ok()
+}
+
+/// Verifies a 64 byte `r || s` signature of one of the ballot's message hashes.
+pub fn verify_signature(
+    verifying_key: &VerifyingKey,
+    signature: &Signature,
+    ballot: &Ballot,
+    domain_separator: Option<&[u8; 32]>,
+) -> Result<(), RejectionReason> {
+    let verified = message_hashes(ballot, domain_separator)
+        .iter()
+        .any(|message_hash| {
+            verifying_key
+                .verify_prehash(message_hash, signature)
+                .is_ok()
+        });
+    if verified {
+        Ok(())
+    } else {
+        Err(RejectionReason::InvalidSignature)
+    }
+}
+
+/// Verifies Safe style packed owner signatures, `r || s || v` each, of one of the ballot's
+/// message hashes.
+///
+/// As in the Safe contract, the signers must be distinct owners in ascending address order and
+/// number at least the threshold. Owners sign either the hash itself (`v` of 27 or 28) or its
+/// EIP-191 `eth_sign` hash (`v` of 31 or 32). Contract signatures and approved hashes need chain
+/// state and are rejected.
+pub fn verify_safe_signatures(
+    owners: &SafeOwners,
+    signatures: &[u8],
+    ballot: &Ballot,
+    domain_separator: Option<&[u8; 32]>,
+) -> Result<(), RejectionReason> {
+    let signer_count = signatures.len() / SAFE_SIGNATURE_LEN;
+    if signer_count == 0 || signer_count * SAFE_SIGNATURE_LEN != signatures.len() {
+        return Err(RejectionReason::InvalidSignature);
+    }
+    let mut reason = RejectionReason::InvalidSignature;
+    for message_hash in message_hashes(ballot, domain_separator) {
+        match safe_signers(owners, signatures, &message_hash) {
+            Some(signer_count) if signer_count >= owners.threshold as usize => return Ok(()),
+            Some(_) => reason = RejectionReason::InsufficientSignatures,
+            None => {}
+        }
+    }
+    Err(reason)
+}
+
+/// Counts the owners that signed `message_hash`, or `None` if a signature isn't a valid
+/// signature of it by an owner in ascending order.
+fn safe_signers(owners: &SafeOwners, signatures: &[u8], message_hash: &[u8; 32]) -> Option<usize> {
+    let mut last_owner: Option<Address> = None;
+    for signature in signatures.chunks(SAFE_SIGNATURE_LEN) {
+        let verifying_key = match signature[64] {
+            27 | 28 => recover_signer(signature, message_hash)?,
+            v @ (31 | 32) => {
+                let mut signature = signature.to_vec();
+                signature[64] = v - 4;
+                recover_signer(&signature, &hash_message(message_hash))?
+            }
+            _ => return None,
+        };
+        let owner = signer_address(&verifying_key);
+        if !owners.is_owner(&owner) || matches!(last_owner, Some(last) if owner <= last) {
+            return None;
+        }
+        last_owner = Some(owner);
+    }
+    Some(signatures.len() / SAFE_SIGNATURE_LEN)
+}
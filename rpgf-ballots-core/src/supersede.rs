@@ -0,0 +1,28 @@
+use crate::eligibility::Address;
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Finds the ballots superseded by a later ballot of the same signer, given the
+/// `(signer, nonce)` of every valid ballot in input order.
+///
+/// Only the ballot with the highest nonce of each signer is counted. A resubmission with the
+/// same nonce supersedes the earlier one, so the last ballot in input order wins ties. Returns
+/// whether each ballot is superseded, in input order.
+pub fn superseded(ballots: &[(Address, u64)]) -> Vec<bool> {
+    let mut superseded = vec![false; ballots.len()];
+    let mut latest: BTreeMap<Address, usize> = BTreeMap::new();
+    for (index, (signer, nonce)) in ballots.iter().enumerate() {
+        match latest.get(signer) {
+            Some(&kept) if ballots[kept].1 > *nonce => superseded[index] = true,
+            Some(&kept) => {
+                superseded[kept] = true;
+                latest.insert(*signer, index);
+            }
+            None => {
+                latest.insert(*signer, index);
+            }
+        }
+    }
+    superseded
+}
@@ -0,0 +1,178 @@
+use crate::ballot::ProjectId;
+use crate::tally_rule::{BuiltinRule, TallyRule};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Parameters of the RetroPGF allocation formula computed by the aggregate guest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TallyConfig {
+    /// Minimum number of ballots voting for a project for it to receive an allocation.
+    pub quorum: u32,
+    /// Total amount allocated, as a fixed point integer with the config's amount scale.
+    pub pool: u128,
+    /// How the vote amounts of a project are aggregated into its share of the pool.
+    pub rule: BuiltinRule,
+}
+
+/// The final allocation of a project that reached the quorum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocation {
+    pub project_id: ProjectId,
+    /// Number of counted ballots that voted for the project.
+    pub ballot_count: u32,
+    /// Share of the pool, as a fixed point integer with the config's amount scale.
+    pub amount: u128,
+}
+
+/// The allocation outcome committed by the aggregate guest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tally {
+    pub config: TallyConfig,
+    /// [`TallyRule::id`] of the rule the allocations were computed with.
+    pub rule_id: u8,
+    /// Allocations of the projects that reached the quorum, in project registry order.
+    pub allocations: Vec<Allocation>,
+}
+
+/// Computes the allocations of `projects`, the `(project id, vote amounts)` of every project in
+/// registry order, with the configured rule.
+pub fn tally(
+    projects: &[(ProjectId, Vec<i128>)],
+    config: TallyConfig,
+) -> Result<Tally, TallyError> {
+    Ok(Tally {
+        config,
+        rule_id: config.rule.id(),
+        allocations: allocate(projects, config.quorum, config.pool, &config.rule)?,
+    })
+}
+
+/// Shares `pool` among `projects` according to `rule`.
+///
+/// Projects voted for by fewer than `quorum` ballots are left out. Every other project gets a
+/// share of the pool proportional to its score. The shares are rounded down and the rest of the
+/// pool is handed out one unit at a time to the largest remainders, ties going to the earlier
+/// project, so the allocations sum exactly to the pool. Nothing is allocated if every score is
+/// zero.
+pub fn allocate<R: TallyRule + ?Sized>(
+    projects: &[(ProjectId, Vec<i128>)],
+    quorum: u32,
+    pool: u128,
+    rule: &R,
+) -> Result<Vec<Allocation>, TallyError> {
+    let mut scores = vec![];
+    for (project_id, amounts) in projects {
+        if amounts.len() < quorum.max(1) as usize {
+            continue;
+        }
+        let mut sorted = amounts
+            .iter()
+            .map(|amount| u128::try_from(*amount).map_err(|_| TallyError::NegativeAmount))
+            .collect::<Result<Vec<_>, _>>()?;
+        sorted.sort_unstable();
+        scores.push((*project_id, amounts.len() as u32, rule.score(&sorted)?));
+    }
+
+    let total = scores
+        .iter()
+        .try_fold(0u128, |total, (_, _, score)| total.checked_add(*score))
+        .ok_or(TallyError::Overflow)?;
+
+    let mut allocations = Vec::with_capacity(scores.len());
+    let mut remainders = Vec::with_capacity(scores.len());
+    for (index, (project_id, ballot_count, score)) in scores.into_iter().enumerate() {
+        let (amount, remainder) = if total == 0 {
+            (0, 0)
+        } else {
+            mul_div(pool, score, total)
+        };
+        allocations.push(Allocation {
+            project_id,
+            ballot_count,
+            amount,
+        });
+        remainders.push((remainder, index));
+    }
+
+    if total != 0 {
+        let allocated: u128 = allocations.iter().map(|allocation| allocation.amount).sum();
+        // every share is rounded down by less than one unit, so fewer units than projects are left
+        let leftover = (pool - allocated) as usize;
+        remainders
+            .sort_unstable_by(|(a, a_index), (b, b_index)| b.cmp(a).then(a_index.cmp(b_index)));
+        for (_, index) in remainders.into_iter().take(leftover) {
+            allocations[index].amount += 1;
+        }
+    }
+    Ok(allocations)
+}
+
+/// `(a * b / d, a * b % d)` with a 256 bit intermediate product, for `b <= d` so that the
+/// quotient fits in a `u128`.
+pub fn mul_div(a: u128, b: u128, d: u128) -> (u128, u128) {
+    debug_assert!(b <= d && d != 0);
+    let (high, low) = widening_mul(a, b);
+    // schoolbook long division of the 256 bit product, one bit at a time
+    let mut quotient = 0u128;
+    let mut remainder = 0u128;
+    for bit in (0..256).rev() {
+        let word = if bit >= 128 { high } else { low };
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((word >> (bit % 128)) & 1);
+        if carry == 1 || remainder >= d {
+            remainder = remainder.wrapping_sub(d);
+            if bit < 128 {
+                quotient |= 1 << bit;
+            }
+        }
+    }
+    (quotient, remainder)
+}
+
+/// The 256 bit product of `a` and `b` as `(high, low)` 128 bit halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (high_low & MASK) + (low_high & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// Why the allocations can't be computed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TallyError {
+    /// A vote amount is negative.
+    NegativeAmount,
+    /// A score, or the sum of the scores of the projects, is more than a `u128`.
+    Overflow,
+    /// The name of a rule isn't one of the [`BuiltinRule`]s.
+    UnknownRule(String),
+}
+
+impl fmt::Display for TallyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TallyError::NegativeAmount => write!(f, "vote amounts must be non negative"),
+            TallyError::Overflow => write!(f, "the project scores overflow a u128"),
+            TallyError::UnknownRule(rule) => write!(
+                f,
+                "unknown tally rule {}, expected sum, mean, median, trimmed_mean:<trim> or quadratic",
+                rule
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TallyError {}
@@ -0,0 +1,199 @@
+use crate::tally::TallyError;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// How the vote amounts of a project are aggregated into its weight in the pool.
+///
+/// Scores only need to be proportional to the rule's aggregate: the pool is shared out by
+/// `score / total score`, so a rule may scale every score by the same constant to stay exact.
+pub trait TallyRule {
+    /// Identifier of the rule, committed to the journal. Identifiers must not be reused.
+    fn id(&self) -> u8;
+
+    /// The score of a project given its vote amounts, sorted in ascending order. There is at
+    /// least one amount.
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError>;
+}
+
+/// The sum of the vote amounts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Sum;
+
+impl TallyRule for Sum {
+    fn id(&self) -> u8 {
+        1
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        checked_sum(amounts)
+    }
+}
+
+/// The mean of the vote amounts, rounded down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Mean;
+
+impl TallyRule for Mean {
+    fn id(&self) -> u8 {
+        2
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        Ok(checked_sum(amounts)? / amounts.len() as u128)
+    }
+}
+
+/// The median of the vote amounts, the mean of the two middle amounts for an even number of
+/// votes. Scores are twice the median so that they are exact.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Median;
+
+impl TallyRule for Median {
+    fn id(&self) -> u8 {
+        3
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        // both indices are the middle one for an odd number of votes
+        let (low, high) = ((amounts.len() - 1) / 2, amounts.len() / 2);
+        amounts[low]
+            .checked_add(amounts[high])
+            .ok_or(TallyError::Overflow)
+    }
+}
+
+/// The mean of the vote amounts once the `trim` lowest and `trim` highest are dropped, rounded
+/// down. Projects with at most `2 * trim` votes score zero, so the quorum should be above it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TrimmedMean {
+    pub trim: u32,
+}
+
+impl TallyRule for TrimmedMean {
+    fn id(&self) -> u8 {
+        4
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        let trim = self.trim as usize;
+        if amounts.len() <= 2 * trim {
+            return Ok(0);
+        }
+        Mean.score(&amounts[trim..amounts.len() - trim])
+    }
+}
+
+/// Quadratic funding: the square of the sum of the square roots of the vote amounts, each
+/// rounded down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Quadratic;
+
+impl TallyRule for Quadratic {
+    fn id(&self) -> u8 {
+        5
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        let roots = amounts
+            .iter()
+            .map(|amount| isqrt(*amount))
+            .collect::<Vec<_>>();
+        let sum = checked_sum(&roots)?;
+        sum.checked_mul(sum).ok_or(TallyError::Overflow)
+    }
+}
+
+fn checked_sum(amounts: &[u128]) -> Result<u128, TallyError> {
+    amounts
+        .iter()
+        .try_fold(0u128, |sum, amount| sum.checked_add(*amount))
+        .ok_or(TallyError::Overflow)
+}
+
+/// The square root of `n`, rounded down.
+fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from above, starting at a power of two no smaller than the root
+    let mut root = 1u128 << (64 - n.leading_zeros() / 2);
+    loop {
+        let next = (root + n / root) / 2;
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+/// The rules that can be selected by the guest config.
+///
+/// Written as `sum`, `mean`, `median`, `trimmed_mean:<trim>` or `quadratic`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuiltinRule {
+    Sum,
+    Mean,
+    #[default]
+    Median,
+    TrimmedMean {
+        trim: u32,
+    },
+    Quadratic,
+}
+
+impl TallyRule for BuiltinRule {
+    fn id(&self) -> u8 {
+        match self {
+            BuiltinRule::Sum => Sum.id(),
+            BuiltinRule::Mean => Mean.id(),
+            BuiltinRule::Median => Median.id(),
+            BuiltinRule::TrimmedMean { trim } => TrimmedMean { trim: *trim }.id(),
+            BuiltinRule::Quadratic => Quadratic.id(),
+        }
+    }
+
+    fn score(&self, amounts: &[u128]) -> Result<u128, TallyError> {
+        match self {
+            BuiltinRule::Sum => Sum.score(amounts),
+            BuiltinRule::Mean => Mean.score(amounts),
+            BuiltinRule::Median => Median.score(amounts),
+            BuiltinRule::TrimmedMean { trim } => TrimmedMean { trim: *trim }.score(amounts),
+            BuiltinRule::Quadratic => Quadratic.score(amounts),
+        }
+    }
+}
+
+impl FromStr for BuiltinRule {
+    type Err = TallyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || TallyError::UnknownRule(s.to_string());
+        Ok(match s {
+            "sum" => BuiltinRule::Sum,
+            "mean" => BuiltinRule::Mean,
+            "median" => BuiltinRule::Median,
+            "quadratic" => BuiltinRule::Quadratic,
+            _ => {
+                let trim = s.strip_prefix("trimmed_mean:").ok_or_else(unknown)?;
+                BuiltinRule::TrimmedMean {
+                    trim: trim.parse().map_err(|_| unknown())?,
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for BuiltinRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuiltinRule::Sum => write!(f, "sum"),
+            BuiltinRule::Mean => write!(f, "mean"),
+            BuiltinRule::Median => write!(f, "median"),
+            BuiltinRule::TrimmedMean { trim } => write!(f, "trimmed_mean:{}", trim),
+            BuiltinRule::Quadratic => write!(f, "quadratic"),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::ballot::{Ballot, ProjectId};
+use crate::config::GuestConfig;
+use crate::eligibility::Address;
+use crate::rejection::RejectionReason;
+use crate::safe::{find_safe, SafeOwners};
+use crate::signature::{signer_address, verify_safe_signatures, verify_signature};
+use alloc::vec::Vec;
+use k256::ecdsa::{Signature, VerifyingKey};
+
+// The checks every ballot goes through, shared by the guests and the host so that the host can
+// tell which reason the guests reject a ballot for.
+
+/// What every ballot is checked against.
+pub struct BallotChecks<'a> {
+    /// The project registry ballots must only vote for, if there is one.
+    pub project_ids: Option<&'a [ProjectId]>,
+    pub safe_owners: &'a [SafeOwners],
+    pub config: &'a GuestConfig,
+    domain_separator: Option<[u8; 32]>,
+}
+
+impl<'a> BallotChecks<'a> {
+    pub fn new(
+        project_ids: Option<&'a [ProjectId]>,
+        safe_owners: &'a [SafeOwners],
+        config: &'a GuestConfig,
+    ) -> Self {
+        BallotChecks {
+            project_ids,
+            safe_owners,
+            config,
+            domain_separator: config.eip712_domain_separator(),
+        }
+    }
+}
+
+/// Validates a `(ballot, signature)` input against its verifying key, returning the signer, the
+/// parsed ballot and its fixed point vote amounts, or the reason it is rejected.
+///
+/// A 20 byte verifying key is the address of a Safe multisig, whose ballot signature is the
+/// packed signatures of at least threshold of its owners. An empty one stands for a signer the
+/// host couldn't recover from the signature.
+///
+/// Ballots voting for a project outside of the registry are rejected, and if the config has an
+/// eligibility allowlist, so are ballots signed by an address outside of it.
+pub fn validate_ballot(
+    ballot: Vec<u8>,
+    signature: &[u8],
+    verifying_key: Option<&[u8]>,
+    checks: &BallotChecks,
+) -> Result<(Address, Ballot, Vec<i128>), RejectionReason> {
+    // the ballot is parsed first, so that a malformed ballot is reported as such even when the
+    // host couldn't recover its signer
+    let ballot = Ballot::parse(ballot).map_err(|_| RejectionReason::MalformedBallot)?;
+    let verifying_key = verifying_key.ok_or(RejectionReason::MissingVerifyingKey)?;
+
+    if verifying_key.is_empty() {
+        return Err(RejectionReason::UnrecoveredSigner);
+    }
+
+    let address = if let Ok(safe) = Address::try_from(verifying_key) {
+        let owners = find_safe(checks.safe_owners, &safe).ok_or(RejectionReason::UnknownSafe)?;
+        verify_safe_signatures(owners, signature, &ballot, checks.domain_separator.as_ref())?;
+        safe
+    } else {
+        let verifying_key = VerifyingKey::from_sec1_bytes(verifying_key)
+            .map_err(|_| RejectionReason::InvalidVerifyingKey)?;
+        let signature =
+            Signature::try_from(signature).map_err(|_| RejectionReason::InvalidSignature)?;
+        verify_signature(
+            &verifying_key,
+            &signature,
+            &ballot,
+            checks.domain_separator.as_ref(),
+        )?;
+        signer_address(&verifying_key)
+    };
+
+    if let Some(eligibility) = &checks.config.eligibility {
+        if !eligibility.contains(&address) {
+            return Err(RejectionReason::NotEligible);
+        }
+    }
+
+    let mut amounts = Vec::with_capacity(ballot.votes().len());
+    for vote in ballot.votes() {
+        if let Some(project_ids) = checks.project_ids {
+            if !project_ids.contains(&vote.project_id) {
+                return Err(RejectionReason::UnknownProject);
+            }
+        }
+        let amount = vote
+            .amount
+            .to_fixed(checks.config.amount_scale)
+            .map_err(|_| RejectionReason::InvalidAmount)?;
+        amounts.push(amount);
+    }
+    Ok((address, ballot, amounts))
+}
@@ -0,0 +1,151 @@
+use rpgf_ballots_core::{Amount, Ballot, BallotError, ProjectId};
+
+const PROJECT_A: &str = "0x27296535734d2a0ed74c252553aec63c94a0a99f035b07b1b80e8a3fe0a830db";
+const PROJECT_B: &str = "0xa20ade372da3150ca8720c7dc096768f273c60d5d9fddd5c82bd0954126009df";
+
+fn parse(json: &str) -> Result<Ballot, BallotError> {
+    Ballot::parse(json.as_bytes().to_vec())
+}
+
+#[test]
+fn parses_string_and_numeric_amounts() {
+    let json = format!(
+        r#"[{{"projectId":"{}","amount":"12345.67"}},{{"projectId":"{}","amount":42}}]"#,
+        PROJECT_A, PROJECT_B
+    );
+    let ballot = parse(&json).unwrap();
+    assert_eq!(ballot.raw(), json.as_bytes());
+    assert_eq!(ballot.votes().len(), 2);
+    assert_eq!(ballot.votes()[0].amount.as_str(), "12345.67");
+    assert_eq!(ballot.votes()[1].amount.as_str(), "42");
+
+    let project_b: ProjectId = PROJECT_B.parse().unwrap();
+    assert_eq!(project_b.to_string(), PROJECT_B);
+    assert_eq!(ballot.vote_for(&project_b).unwrap().amount.as_str(), "42");
+}
+
+#[test]
+fn keeps_every_digit_of_numeric_amounts() {
+    // beyond the 2^53 integers and ~15 significant digits an f64 represents exactly
+    let json = format!(
+        r#"[{{"projectId":"{}","amount":12345678901234567891}},{{"projectId":"{}","amount":0.123456789012345678}}]"#,
+        PROJECT_A, PROJECT_B
+    );
+    let ballot = parse(&json).unwrap();
+    assert_eq!(ballot.votes()[0].amount.as_str(), "12345678901234567891");
+    assert_eq!(
+        ballot.votes()[0].amount.to_fixed(18),
+        Ok(12_345_678_901_234_567_891_000_000_000_000_000_000)
+    );
+    assert_eq!(ballot.votes()[1].amount.as_str(), "0.123456789012345678");
+    assert_eq!(
+        ballot.votes()[1].amount.to_fixed(18),
+        Ok(123_456_789_012_345_678)
+    );
+
+    assert!(matches!(
+        parse(&format!(
+            r#"[{{"projectId":"{}","amount":1e21}}]"#,
+            PROJECT_A
+        )),
+        Err(BallotError::InvalidAmount { index: Some(0), .. })
+    ));
+}
+
+#[test]
+fn rejects_malformed_ballots() {
+    assert_eq!(parse("{}"), Err(BallotError::NotAnArray));
+    assert!(matches!(parse("[{"), Err(BallotError::Json(_))));
+    assert_eq!(
+        parse(r#"[{"projectId":"0x1234","amount":"1"}]"#),
+        Err(BallotError::InvalidProjectId {
+            index: Some(0),
+            value: "0x1234".to_string()
+        })
+    );
+    assert_eq!(
+        parse(&format!(
+            r#"[{{"projectId":"{}","amount":"-1"}}]"#,
+            PROJECT_A
+        )),
+        Err(BallotError::InvalidAmount {
+            index: Some(0),
+            value: "-1".to_string()
+        })
+    );
+    assert_eq!(
+        parse(&format!(
+            r#"[{{"projectId":"{}","amount":"1","memo":"hi"}}]"#,
+            PROJECT_A
+        )),
+        Err(BallotError::UnknownField {
+            index: 0,
+            field: "memo".to_string()
+        })
+    );
+    assert_eq!(
+        parse(&format!(r#"[{{"projectId":"{}"}}]"#, PROJECT_A)),
+        Err(BallotError::MissingField {
+            index: 0,
+            field: "amount"
+        })
+    );
+    assert!(matches!(
+        parse(&format!(
+            r#"[{{"projectId":"{0}","amount":"1"}},{{"projectId":"{0}","amount":"2"}}]"#,
+            PROJECT_A
+        )),
+        Err(BallotError::DuplicateProject { index: 1, .. })
+    ));
+}
+
+#[test]
+fn converts_amounts_to_fixed_point_exactly() {
+    let amount = |text: &str| text.parse::<Amount>().unwrap();
+    assert_eq!(amount("12345.67").to_fixed(2), Ok(1234567));
+    assert_eq!(
+        amount("12345.67").to_fixed(18),
+        Ok(12_345_670_000_000_000_000_000)
+    );
+    assert_eq!(amount("7.50").to_fixed(1), Ok(75));
+    assert_eq!(amount("30000000").to_fixed(0), Ok(30_000_000));
+    assert_eq!(
+        amount("12345.67").to_fixed(1),
+        Err(BallotError::AmountOutOfRange {
+            value: "12345.67".to_string(),
+            scale: 1
+        })
+    );
+    assert!(amount("1000000000000000000000").to_fixed(18).is_err());
+}
+
+#[test]
+fn parses_nonced_ballots() {
+    let votes = format!(r#"[{{"projectId":"{}","amount":"1"}}]"#, PROJECT_A);
+    assert_eq!(parse(&votes).unwrap().nonce(), 0);
+    let ballot = parse(&format!(r#"{{"nonce":1700000000,"votes":{}}}"#, votes)).unwrap();
+    assert_eq!(ballot.nonce(), 1_700_000_000);
+    assert_eq!(ballot.votes().len(), 1);
+    assert_eq!(
+        parse(&format!(
+            r#"{{"nonce":"18446744073709551615","votes":{}}}"#,
+            votes
+        ))
+        .unwrap()
+        .nonce(),
+        u64::MAX
+    );
+
+    assert_eq!(
+        parse(&format!(r#"{{"votes":{}}}"#, votes)),
+        Err(BallotError::MissingNonce)
+    );
+    assert_eq!(
+        parse(&format!(r#"{{"nonce":-1,"votes":{}}}"#, votes)),
+        Err(BallotError::InvalidNonce("-1".to_string()))
+    );
+    assert_eq!(
+        parse(&format!(r#"{{"nonce":1,"votes":{},"memo":"hi"}}"#, votes)),
+        Err(BallotError::UnknownBallotField("memo".to_string()))
+    );
+}
@@ -0,0 +1,68 @@
+use rpgf_ballots_core::{ballot_digest, Ballot, Eip712Domain};
+
+const BALLOT: &str = r#"[{"projectId":"0x27296535734d2a0ed74c252553aec63c94a0a99f035b07b1b80e8a3fe0a830db","amount":"12345.67"},{"projectId":"0xa20ade372da3150ca8720c7dc096768f273c60d5d9fddd5c82bd0954126009df","amount":"42"}]"#;
+
+fn ballot(json: &str) -> Ballot {
+    Ballot::parse(json.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn domain_separator_binds_the_chain_contract_and_round() {
+    let domain = Eip712Domain::default();
+    let separator = domain.separator(4);
+    assert_eq!(separator, Eip712Domain::default().separator(4));
+    assert_ne!(separator, domain.separator(5));
+    assert_ne!(
+        separator,
+        Eip712Domain {
+            chain_id: 1,
+            ..Eip712Domain::default()
+        }
+        .separator(4)
+    );
+    assert_ne!(
+        separator,
+        Eip712Domain {
+            verifying_contract: [0xcc; 20],
+            ..Eip712Domain::default()
+        }
+        .separator(4)
+    );
+}
+
+#[test]
+fn ballot_digest_binds_every_vote() {
+    let separator = Eip712Domain::default().separator(4);
+    let digest = ballot_digest(&separator, &ballot(BALLOT));
+    // whitespace in the json isn't signed
+    assert_eq!(
+        digest,
+        ballot_digest(
+            &separator,
+            &ballot(&BALLOT.replace(",\"amount", ", \"amount"))
+        )
+    );
+    assert_ne!(
+        digest,
+        ballot_digest(&separator, &ballot(&BALLOT.replace("\"42\"", "\"43\"")))
+    );
+    assert_ne!(
+        digest,
+        ballot_digest(&Eip712Domain::default().separator(5), &ballot(BALLOT))
+    );
+    // plain arrays of votes sign a nonce of 0
+    assert_eq!(
+        digest,
+        ballot_digest(
+            &separator,
+            &ballot(&format!(r#"{{"nonce":0,"votes":{}}}"#, BALLOT))
+        )
+    );
+    assert_ne!(
+        digest,
+        ballot_digest(
+            &separator,
+            &ballot(&format!(r#"{{"nonce":1,"votes":{}}}"#, BALLOT))
+        )
+    );
+}
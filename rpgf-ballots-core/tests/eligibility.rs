@@ -0,0 +1,36 @@
+use rpgf_ballots_core::{Address, Allowlist};
+use sha3::{Digest, Keccak256};
+
+const ALICE: Address = [0x11; 20];
+const BOB: Address = [0x22; 20];
+const CAROL: Address = [0x33; 20];
+
+fn keccak(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    keccak(&[first, second].concat())
+}
+
+#[test]
+fn root_is_independent_of_listing_order() {
+    let allowlist = Allowlist::new(vec![CAROL, ALICE, BOB, ALICE]);
+    assert_eq!(allowlist.addresses(), &[ALICE, BOB, CAROL]);
+    assert_eq!(allowlist, Allowlist::new(vec![BOB, CAROL, ALICE]));
+    assert!(allowlist.contains(&BOB));
+    assert!(!allowlist.contains(&[0x44; 20]));
+
+    let expected = hash_pair(hash_pair(keccak(&ALICE), keccak(&BOB)), keccak(&CAROL));
+    assert_eq!(allowlist.root(), expected);
+    assert_eq!(Allowlist::new(vec![ALICE]).root(), keccak(&ALICE));
+    assert_eq!(Allowlist::default().root(), [0; 32]);
+}
+
+#[test]
+fn deserializing_restores_the_canonical_order() {
+    let json = serde_json::to_string(&vec![BOB, ALICE]).unwrap();
+    let allowlist: Allowlist = serde_json::from_str(&json).unwrap();
+    assert_eq!(allowlist.addresses(), &[ALICE, BOB]);
+}
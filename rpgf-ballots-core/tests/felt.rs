@@ -0,0 +1,11 @@
+use rpgf_ballots_core::Felt;
+
+#[test]
+fn felts_round_trip_through_hex() {
+    let felt = Felt([1, 2, 3, u64::MAX]);
+    let hex = felt.to_hex();
+    assert_eq!(&hex[..18], "0x0100000000000000");
+    assert_eq!(Felt::from_hex(&hex), Ok(felt));
+    assert_eq!(Felt::from_hex(&hex[2..]), Ok(felt));
+    assert!(Felt::from_hex("0x01").is_err());
+}
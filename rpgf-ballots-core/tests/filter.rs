@@ -0,0 +1,105 @@
+use rpgf_ballots_core::{Comparison, FilterError, FilterExpr, ProjectFilter, ProjectId};
+use std::collections::BTreeMap;
+
+fn project(byte: u8) -> ProjectId {
+    ProjectId([byte; 32])
+}
+
+#[test]
+fn matches_the_vote_count_range_and_project_ids() {
+    assert!(ProjectFilter::default().matches(&project(1), &[]));
+
+    let range = ProjectFilter {
+        expr: FilterExpr::VoteCountRange { min: 2, max: 3 },
+        categories: BTreeMap::new(),
+    };
+    assert!(!range.matches(&project(1), &[1]));
+    assert!(range.matches(&project(1), &[1, 2]));
+    assert!(range.matches(&project(1), &[1, 2, 3]));
+    assert!(!range.matches(&project(1), &[1, 2, 3, 4]));
+
+    let both = ProjectFilter {
+        expr: FilterExpr::VoteCountRange { min: 2, max: 3 }
+            .and(FilterExpr::Projects(vec![project(1)])),
+        categories: BTreeMap::new(),
+    };
+    assert!(both.matches(&project(1), &[1, 2]));
+    assert!(!both.matches(&project(2), &[1, 2]));
+}
+
+#[test]
+fn parses_and_evaluates_filter_expressions() {
+    let defi = project(1).to_string();
+    let expr = FilterExpr::parse(
+        &format!(
+            "votes in 2..10 and (median >= 1.5 or project in [{}]) and not category in [tooling]",
+            defi
+        ),
+        2,
+    )
+    .unwrap();
+    assert_eq!(
+        expr,
+        FilterExpr::And(
+            Box::new(FilterExpr::And(
+                Box::new(FilterExpr::VoteCountRange { min: 2, max: 10 }),
+                Box::new(FilterExpr::Or(
+                    Box::new(FilterExpr::Median {
+                        comparison: Comparison::Ge,
+                        amount: 150,
+                    }),
+                    Box::new(FilterExpr::Projects(vec![project(1)])),
+                )),
+            )),
+            Box::new(FilterExpr::Not(Box::new(FilterExpr::Categories(vec![
+                "tooling".to_string()
+            ])))),
+        )
+    );
+    assert_eq!(expr.categories(), vec!["tooling"]);
+
+    let filter = ProjectFilter {
+        expr,
+        categories: BTreeMap::from([("tooling".to_string(), vec![project(3)])]),
+    };
+    // a median of 1.5 is twice 150 over the two middle amounts
+    assert!(filter.matches(&project(2), &[100, 200]));
+    assert!(!filter.matches(&project(2), &[100, 199]));
+    assert!(filter.matches(&project(1), &[0, 0]));
+    assert!(!filter.matches(&project(1), &[0]));
+    assert!(!filter.matches(&project(3), &[500, 500]));
+
+    let total = FilterExpr::parse("total > 10 or votes == 0", 0).unwrap();
+    assert!(total.matches(&project(1), &[5, 6], &BTreeMap::new()));
+    assert!(!total.matches(&project(1), &[5, 5], &BTreeMap::new()));
+    assert!(total.matches(&project(1), &[], &BTreeMap::new()));
+}
+
+#[test]
+fn rejects_malformed_filter_expressions() {
+    assert_eq!(FilterExpr::parse("", 0), Err(FilterError::UnexpectedEnd));
+    assert_eq!(
+        FilterExpr::parse("votes in 2..", 0),
+        Err(FilterError::UnexpectedEnd)
+    );
+    assert_eq!(
+        FilterExpr::parse("votes >= -1", 0),
+        Err(FilterError::InvalidCount("-1".to_string()))
+    );
+    assert_eq!(
+        FilterExpr::parse("total >= 1.005", 2),
+        Err(FilterError::InvalidAmount("1.005".to_string()))
+    );
+    assert_eq!(
+        FilterExpr::parse("project in [0x12]", 0),
+        Err(FilterError::InvalidProjectId("0x12".to_string()))
+    );
+    assert_eq!(
+        FilterExpr::parse("all all", 0),
+        Err(FilterError::UnexpectedToken("all".to_string()))
+    );
+    assert_eq!(
+        FilterExpr::parse("votes != 1", 0),
+        Err(FilterError::UnexpectedCharacter('!'))
+    );
+}
@@ -0,0 +1,71 @@
+use rpgf_ballots_core::poseidon::vote_amounts_params;
+use rpgf_ballots_core::{
+    IncrementalJournal, JournalHeader, PoseidonConfig, ProjectFilter, ProjectsJournal,
+    UnexpectedImageId, JOURNAL_VERSION,
+};
+
+const SHARD_ID: [u32; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+const INCREMENT_ID: [u32; 8] = [8, 7, 6, 5, 4, 3, 2, 1];
+
+fn projects_journal(shard_image_id: Option<[u32; 8]>) -> ProjectsJournal {
+    ProjectsJournal {
+        header: JournalHeader {
+            version: JOURNAL_VERSION,
+            round_id: 1,
+            inputs_hash: [0; 32],
+            verifying_keys_hash: [0; 32],
+            project_registry_hash: [0; 32],
+            safe_owners_hash: None,
+            eligibility_root: None,
+            eip712_domain_separator: None,
+            amount_scale: 0,
+            poseidon: vote_amounts_params(&PoseidonConfig::default()),
+            salt_commitment: None,
+            ballot_count: 0,
+            accepted_count: 0,
+            superseded_count: 0,
+            rejections: vec![],
+        },
+        filter: ProjectFilter::default(),
+        projects: vec![],
+        tally: None,
+        shard_image_id,
+    }
+}
+
+#[test]
+fn checks_the_shard_image_id() {
+    let sharded = projects_journal(Some(SHARD_ID));
+    assert_eq!(sharded.check_shard_image_id(Some(&SHARD_ID)), Ok(()));
+    assert_eq!(
+        sharded.check_shard_image_id(Some(&[0; 8])),
+        Err(UnexpectedImageId(Some(SHARD_ID)))
+    );
+    // a receipt of the aggregate guest can't carry a shard image id, nor one of the shards
+    // guest lack it
+    assert_eq!(
+        sharded.check_shard_image_id(None),
+        Err(UnexpectedImageId(Some(SHARD_ID)))
+    );
+    let unsharded = projects_journal(None);
+    assert_eq!(unsharded.check_shard_image_id(None), Ok(()));
+    assert_eq!(
+        unsharded.check_shard_image_id(Some(&SHARD_ID)),
+        Err(UnexpectedImageId(None))
+    );
+}
+
+#[test]
+fn checks_the_incremental_image_id() {
+    let journal = IncrementalJournal {
+        journal: projects_journal(None),
+        image_id: INCREMENT_ID,
+        config_hash: [0; 32],
+        input_chain: vec![],
+    };
+    assert_eq!(journal.check_image_id(&INCREMENT_ID), Ok(()));
+    assert_eq!(
+        journal.check_image_id(&SHARD_ID),
+        Err(UnexpectedImageId(Some(INCREMENT_ID)))
+    );
+}
@@ -0,0 +1,136 @@
+use rpgf_ballots_core::merkle::{
+    badgeholder_commitment, inclusion_path, vote_leaf, votes_root, InclusionProof,
+};
+use rpgf_ballots_core::{leaf_nonce, votes_root_salt, Felt, ProjectId};
+
+const PROJECT_ID: ProjectId = ProjectId([7; 32]);
+
+fn proof(leaf_count: usize, leaf_index: usize) -> (InclusionProof, Felt) {
+    salted_proof(leaf_count, leaf_index, None)
+}
+
+fn salted_proof(
+    leaf_count: usize,
+    leaf_index: usize,
+    secret: Option<[u8; 32]>,
+) -> (InclusionProof, Felt) {
+    let badgeholders: Vec<[u8; 20]> = (0..leaf_count).map(|i| [i as u8 + 1; 20]).collect();
+    let nonce = |badgeholder| secret.map(|secret| leaf_nonce(&secret, badgeholder));
+    let leaves: Vec<_> = badgeholders
+        .iter()
+        .enumerate()
+        .map(|(i, badgeholder)| {
+            vote_leaf(
+                badgeholder_commitment(badgeholder, nonce(badgeholder)),
+                i as i128 * 10,
+            )
+        })
+        .collect();
+    let salt = secret.map(|secret| votes_root_salt(&secret, &PROJECT_ID));
+    let proof = InclusionProof {
+        project_id: PROJECT_ID,
+        badgeholder: badgeholders[leaf_index],
+        amount: leaf_index as i128 * 10,
+        leaf_index: leaf_index as u32,
+        leaf_count: leaf_count as u32,
+        siblings: inclusion_path(&leaves, leaf_index)
+            .into_iter()
+            .map(Felt::from)
+            .collect(),
+        salt,
+        nonce: nonce(&badgeholders[leaf_index]),
+    };
+    (proof, votes_root(&leaves, salt).into())
+}
+
+#[test]
+fn every_vote_has_an_inclusion_proof() {
+    for leaf_count in 1..=9 {
+        for leaf_index in 0..leaf_count {
+            let (proof, root) = proof(leaf_count, leaf_index);
+            assert!(proof.verify(&root), "{} of {}", leaf_index, leaf_count);
+        }
+    }
+}
+
+#[test]
+fn rejects_tampered_proofs() {
+    let (proof, root) = proof(5, 2);
+    assert!(!InclusionProof {
+        amount: proof.amount + 1,
+        ..proof.clone()
+    }
+    .verify(&root));
+    assert!(!InclusionProof {
+        badgeholder: [0xff; 20],
+        ..proof.clone()
+    }
+    .verify(&root));
+    assert!(!InclusionProof {
+        leaf_index: 3,
+        ..proof.clone()
+    }
+    .verify(&root));
+    // the path of a 5 leaf tree doesn't fit a 4 leaf one
+    assert_eq!(
+        InclusionProof {
+            leaf_count: 4,
+            ..proof.clone()
+        }
+        .root(),
+        None
+    );
+}
+
+#[test]
+fn binds_the_leaf_count_into_the_root() {
+    // the first leaf of a 5 and of a 6 leaf tree have paths of the same shape
+    let (first, root) = proof(5, 0);
+    let resized = InclusionProof {
+        leaf_count: 6,
+        ..first
+    };
+    assert!(resized.root().is_some());
+    assert!(!resized.verify(&root));
+
+    let (single, root) = proof(1, 0);
+    let leaf = vote_leaf(badgeholder_commitment(&single.badgeholder, None), 0);
+    assert_ne!(root, Felt::from(leaf));
+}
+
+#[test]
+fn salts_the_root_with_the_votes_root_salt() {
+    let (salted, root) = salted_proof(3, 1, Some([1; 32]));
+    assert!(salted.verify(&root));
+    assert_eq!(salted.salt, Some(votes_root_salt(&[1; 32], &PROJECT_ID)));
+
+    // the same votes under another secret, or unsalted, have another root
+    let (_, other_root) = salted_proof(3, 1, Some([2; 32]));
+    let (_, unsalted_root) = proof(3, 1);
+    assert_ne!(root, other_root);
+    assert_ne!(root, unsalted_root);
+    assert!(!InclusionProof {
+        salt: None,
+        ..salted
+    }
+    .verify(&root));
+}
+
+#[test]
+fn blinds_the_leaves_with_the_badgeholder_nonce() {
+    let (salted, root) = salted_proof(4, 2, Some([1; 32]));
+    let (unsalted, _) = proof(4, 2);
+    // the siblings of a salted tree hide the other badgeholders' commitments
+    assert_ne!(salted.siblings[0], unsalted.siblings[0]);
+    assert!(!InclusionProof {
+        nonce: None,
+        ..salted.clone()
+    }
+    .verify(&root));
+    // another badgeholder's nonce doesn't open the leaf
+    assert!(!InclusionProof {
+        nonce: Some(leaf_nonce(&[1; 32], &[1; 20])),
+        ..salted
+    }
+    .verify(&root));
+}
@@ -0,0 +1,245 @@
+use ff::{Field, PrimeField};
+use halo2curves::bn256::Fr as Fp;
+use rpgf_ballots_core::poseidon::{
+    hash_vote_amounts, i128_to_felt, length_binding_tree_hash, permute, vote_amounts_params,
+    ConstantLength, Hash, OptimizedConstants, PoseidonSpec, PoseidonSpecW3, Spec,
+    POSEIDON_LEN_GRAPH, POSEIDON_RATE, POSEIDON_W3_RATE, POSEIDON_W3_WIDTH, POSEIDON_WIDTH,
+};
+use rpgf_ballots_core::{
+    project_salt, Felt, PoseidonConfig, PoseidonConfigError, PoseidonSpecId, ProjectId,
+    TreeHashMode,
+};
+use std::marker::PhantomData;
+
+// one chunk of the tree hash, zero padded as the guests pad it
+fn chunk_hash(felts: &[Fp]) -> Fp {
+    let mut message = [Fp::ZERO; POSEIDON_LEN_GRAPH];
+    message[..felts.len()].copy_from_slice(felts);
+    Hash::<_, PoseidonSpec, ConstantLength<POSEIDON_LEN_GRAPH>, POSEIDON_WIDTH, POSEIDON_RATE>::init()
+        .hash(message)
+}
+
+// the hash of earlier receipts, which the chunk hashes above recompute
+fn hash(amounts: &[i128]) -> Felt {
+    let config = PoseidonConfig {
+        tree_hash: TreeHashMode::Legacy,
+        ..PoseidonConfig::default()
+    };
+    hash_vote_amounts(amounts, None, &config).unwrap()
+}
+
+#[test]
+fn projects_without_votes_hash_to_zero() {
+    assert_eq!(hash(&[]), Felt([0; 4]));
+}
+
+#[test]
+fn vote_amounts_are_tree_hashed_in_chunks() {
+    let amounts: Vec<i128> = (0..POSEIDON_LEN_GRAPH as i128 + 1)
+        .map(|i| i * 1_000 - 5_000)
+        .collect();
+    let felts: Vec<Fp> = amounts.iter().map(|amount| i128_to_felt(*amount)).collect();
+
+    // a single chunk is hashed once, even with a single amount
+    assert_eq!(hash(&amounts[..1]), chunk_hash(&felts[..1]).into());
+    assert_eq!(
+        hash(&amounts[..POSEIDON_LEN_GRAPH]),
+        chunk_hash(&felts[..POSEIDON_LEN_GRAPH]).into()
+    );
+
+    // more amounts are hashed chunk by chunk, then the chunk hashes are
+    let chunks = [
+        chunk_hash(&felts[..POSEIDON_LEN_GRAPH]),
+        chunk_hash(&felts[POSEIDON_LEN_GRAPH..]),
+    ];
+    assert_eq!(hash(&amounts), chunk_hash(&chunks).into());
+    assert_ne!(hash(&amounts), hash(&amounts[..POSEIDON_LEN_GRAPH]));
+}
+
+#[test]
+fn width_3_spec_matches_the_reference_permutation() {
+    // the standard BN254 width 3 Poseidon: its permutation of [1, 2, 2^65], the initial state of
+    // a constant length 2 hash of [1, 2]
+    let hash =
+        Hash::<_, PoseidonSpecW3, ConstantLength<2>, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>::init()
+            .hash([Fp::from(1), Fp::from(2)]);
+    assert_eq!(
+        hash,
+        Fp::from_str_vartime(
+            "17511561920568818047375383126863909709162025744044992313169224160250628017268"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn vote_amounts_are_hashed_with_the_configured_spec_and_chunk_len() {
+    let config = PoseidonConfig {
+        spec: PoseidonSpecId::W3R2,
+        chunk_len: 8,
+        tree_hash: TreeHashMode::Legacy,
+    };
+    let amounts: Vec<i128> = (1..=9).collect();
+    let chunk_hash = |felts: &[Fp]| {
+        let mut message = [Fp::ZERO; 8];
+        message[..felts.len()].copy_from_slice(felts);
+        Hash::<_, PoseidonSpecW3, ConstantLength<8>, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>::init()
+            .hash(message)
+    };
+    let felts: Vec<Fp> = amounts.iter().map(|amount| i128_to_felt(*amount)).collect();
+    let chunks = [chunk_hash(&felts[..8]), chunk_hash(&felts[8..])];
+    assert_eq!(
+        hash_vote_amounts(&amounts, None, &config).unwrap(),
+        chunk_hash(&chunks).into()
+    );
+    assert_ne!(
+        hash_vote_amounts(&amounts, None, &config).unwrap(),
+        hash(&amounts)
+    );
+
+    let params = vote_amounts_params(&config);
+    assert_eq!((params.width, params.rate, params.chunk_len), (3, 2, 8));
+    assert_eq!(params.config(), config);
+
+    let config = PoseidonConfig {
+        chunk_len: 12,
+        ..config
+    };
+    assert_eq!(
+        hash_vote_amounts(&amounts, None, &config),
+        Err(PoseidonConfigError::UnsupportedChunkLen(12))
+    );
+}
+
+#[test]
+fn length_binding_tree_hash_binds_the_number_of_amounts() {
+    let config = PoseidonConfig::default();
+    let bound_hash = |amounts: &[i128]| hash_vote_amounts(amounts, None, &config).unwrap();
+
+    // trailing zeros collide in legacy mode only
+    assert_eq!(hash(&[5]), hash(&[5, 0]));
+    assert_ne!(bound_hash(&[5]), bound_hash(&[5, 0]));
+    // a zero vote isn't no vote
+    assert_ne!(bound_hash(&[0]), bound_hash(&[]));
+    assert_eq!(bound_hash(&[]), Felt([0; 4]));
+
+    // a full chunk isn't the same as the next level over its hash
+    let amounts: Vec<i128> = (1..=POSEIDON_LEN_GRAPH as i128 + 1).collect();
+    let felts: Vec<Fp> = amounts.iter().map(|amount| i128_to_felt(*amount)).collect();
+    let tree_hash =
+        length_binding_tree_hash::<POSEIDON_LEN_GRAPH, PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>;
+    assert_eq!(bound_hash(&amounts), tree_hash(&felts).into());
+    assert_ne!(
+        tree_hash(&felts),
+        tree_hash(&[
+            tree_hash(&felts[..POSEIDON_LEN_GRAPH]),
+            tree_hash(&felts[POSEIDON_LEN_GRAPH..])
+        ])
+    );
+}
+
+#[test]
+fn salts_are_hashed_ahead_of_the_amounts() {
+    let config = PoseidonConfig::default();
+    let salt = project_salt(&[7; 32], &ProjectId([1; 32]));
+    let amounts = [5, 0];
+    let felts = [Fp::from(salt), i128_to_felt(5), i128_to_felt(0)];
+    assert_eq!(
+        hash_vote_amounts(&amounts, Some(salt), &config).unwrap(),
+        length_binding_tree_hash::<POSEIDON_LEN_GRAPH, PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>(
+            &felts
+        )
+        .into()
+    );
+    assert_ne!(
+        hash_vote_amounts(&amounts, Some(salt), &config).unwrap(),
+        hash_vote_amounts(&amounts, None, &config).unwrap()
+    );
+    // projects without votes still commit a zero hash
+    assert_eq!(
+        hash_vote_amounts(&[], Some(salt), &config).unwrap(),
+        Felt([0; 4])
+    );
+}
+
+// the spec `S` with the dense permutation, which the optimized one has to match
+#[derive(Debug)]
+struct Dense<S>(PhantomData<S>);
+
+impl<S: Spec<Fp, T, RATE>, const T: usize, const RATE: usize> Spec<Fp, T, RATE> for Dense<S> {
+    fn full_rounds() -> usize {
+        S::full_rounds()
+    }
+
+    fn partial_rounds() -> usize {
+        S::partial_rounds()
+    }
+
+    fn sbox(val: Fp) -> Fp {
+        S::sbox(val)
+    }
+
+    fn secure_mds() -> usize {
+        S::secure_mds()
+    }
+
+    fn constants() -> (Vec<[Fp; T]>, [[Fp; T]; T], [[Fp; T]; T]) {
+        S::constants()
+    }
+
+    fn optimized_constants() -> OptimizedConstants<Fp, T> {
+        S::optimized_constants()
+    }
+
+    fn permute(state: &mut [Fp; T]) {
+        let (round_constants, mds, _) = S::constants();
+        permute::<Fp, S, T, RATE>(state, &mds, &round_constants);
+    }
+}
+
+// large field elements, for every round to overflow the modulus
+fn message<const L: usize>() -> [Fp; L] {
+    let scale = Fp::from(1 << 40).square();
+    std::array::from_fn(|i| scale * i128_to_felt::<Fp>(i as i128 * 7_919 - 100_000))
+}
+
+#[test]
+fn optimized_permutation_matches_the_dense_one() {
+    for state in [[Fp::ZERO; 2], [Fp::ONE, -Fp::ONE], message()] {
+        let mut optimized = state;
+        PoseidonSpec::permute(&mut optimized);
+        let mut dense = state;
+        Dense::<PoseidonSpec>::permute(&mut dense);
+        assert_eq!(optimized, dense);
+    }
+    for state in [[Fp::ZERO; 3], [Fp::ONE, -Fp::ONE, Fp::ONE], message()] {
+        let mut optimized = state;
+        PoseidonSpecW3::permute(&mut optimized);
+        let mut dense = state;
+        Dense::<PoseidonSpecW3>::permute(&mut dense);
+        assert_eq!(optimized, dense);
+    }
+}
+
+type ChunkHash<S, const T: usize, const RATE: usize> =
+    Hash<Fp, S, ConstantLength<POSEIDON_LEN_GRAPH>, T, RATE>;
+
+#[test]
+fn hashes_match_the_dense_permutation() {
+    let message = message::<POSEIDON_LEN_GRAPH>();
+    assert_eq!(
+        ChunkHash::<PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>::init().hash(message),
+        ChunkHash::<Dense<PoseidonSpec>, POSEIDON_WIDTH, POSEIDON_RATE>::init().hash(message)
+    );
+    assert_eq!(
+        ChunkHash::<PoseidonSpecW3, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>::init().hash(message),
+        ChunkHash::<Dense<PoseidonSpecW3>, POSEIDON_W3_WIDTH, POSEIDON_W3_RATE>::init()
+            .hash(message)
+    );
+
+    let amounts: Vec<Fp> = message.iter().chain(message.iter()).copied().collect();
+    assert_eq!(
+        length_binding_tree_hash::<8, PoseidonSpec, POSEIDON_WIDTH, POSEIDON_RATE>(&amounts),
+        length_binding_tree_hash::<8, Dense<PoseidonSpec>, POSEIDON_WIDTH, POSEIDON_RATE>(&amounts)
+    );
+}
@@ -0,0 +1,54 @@
+use rpgf_ballots_core::{find_safe, parse_safe_owners, SafeOwnersError};
+
+const SAFE: &str = "0x5afe000000000000000000000000000000000001";
+const ALICE: &str = "0x2222222222222222222222222222222222222222";
+const BOB: &str = "0x1111111111111111111111111111111111111111";
+const SAFE_ADDRESS: [u8; 20] = [
+    0x5a, 0xfe, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+];
+
+fn owner_sets(threshold: u32, owners: &[&str]) -> String {
+    format!(
+        r#"[{{"safe":"{}","threshold":{},"owners":{:?}}}]"#,
+        SAFE, threshold, owners
+    )
+}
+
+#[test]
+fn parses_sorted_owner_sets() {
+    let sets = parse_safe_owners(owner_sets(2, &[ALICE, BOB]).as_bytes()).unwrap();
+    let set = find_safe(&sets, &SAFE_ADDRESS).unwrap();
+    assert_eq!(set.threshold, 2);
+    assert_eq!(set.owners, vec![[0x11; 20], [0x22; 20]]);
+    assert!(set.is_owner(&[0x22; 20]));
+    assert!(!set.is_owner(&[0x33; 20]));
+    assert!(find_safe(&sets, &[0; 20]).is_none());
+}
+
+#[test]
+fn rejects_invalid_owner_sets() {
+    assert!(matches!(
+        parse_safe_owners(owner_sets(3, &[ALICE, BOB]).as_bytes()),
+        Err(SafeOwnersError::InvalidThreshold { threshold: 3, .. })
+    ));
+    assert!(matches!(
+        parse_safe_owners(owner_sets(0, &[ALICE]).as_bytes()),
+        Err(SafeOwnersError::InvalidThreshold { threshold: 0, .. })
+    ));
+    assert_eq!(
+        parse_safe_owners(owner_sets(1, &[ALICE, ALICE]).as_bytes()),
+        Err(SafeOwnersError::DuplicateOwner(SAFE.to_string()))
+    );
+    assert_eq!(
+        parse_safe_owners(owner_sets(1, &["0x1234"]).as_bytes()),
+        Err(SafeOwnersError::InvalidAddress("0x1234".to_string()))
+    );
+    let twice = format!(
+        r#"[{{"safe":"{0}","threshold":1,"owners":["{1}"]}},{{"safe":"{0}","threshold":1,"owners":["{2}"]}}]"#,
+        SAFE, ALICE, BOB
+    );
+    assert_eq!(
+        parse_safe_owners(twice.as_bytes()),
+        Err(SafeOwnersError::DuplicateSafe(SAFE.to_string()))
+    );
+}
@@ -0,0 +1,34 @@
+use rpgf_ballots_core::{leaf_nonce, project_salt, salt_commitment, votes_root_salt, ProjectId};
+
+#[test]
+fn project_salts_are_field_elements_bound_to_the_secret_and_project() {
+    let secret = [7; 32];
+    let salt = project_salt(&secret, &ProjectId([1; 32]));
+    // below 2^248, so less than the BN254 modulus
+    assert_eq!(salt.0[3] >> 56, 0);
+    assert_ne!(salt.0, [0; 4]);
+
+    assert_eq!(salt, project_salt(&secret, &ProjectId([1; 32])));
+    assert_ne!(salt, project_salt(&secret, &ProjectId([2; 32])));
+    assert_ne!(salt, project_salt(&[8; 32], &ProjectId([1; 32])));
+    // the votes root salt a badgeholder gets doesn't reveal the salt of the project's hash
+    assert_ne!(salt, votes_root_salt(&secret, &ProjectId([1; 32])));
+}
+
+#[test]
+fn leaf_nonces_are_bound_to_the_secret_and_badgeholder() {
+    let secret = [7; 32];
+    let nonce = leaf_nonce(&secret, &[1; 20]);
+    assert_eq!(nonce.0[3] >> 56, 0);
+    assert_eq!(nonce, leaf_nonce(&secret, &[1; 20]));
+    assert_ne!(nonce, leaf_nonce(&secret, &[2; 20]));
+    assert_ne!(nonce, leaf_nonce(&[8; 32], &[1; 20]));
+}
+
+#[test]
+fn salt_commitments_differ_from_the_salts() {
+    let secret = [7; 32];
+    assert_eq!(salt_commitment(&secret), salt_commitment(&secret));
+    assert_ne!(salt_commitment(&secret), salt_commitment(&[8; 32]));
+    assert_ne!(salt_commitment(&secret), secret);
+}
@@ -0,0 +1,59 @@
+use rpgf_ballots_core::sha256::{bincode_seq_elements, bincode_seq_state};
+use rpgf_ballots_core::{HashSpan, Sha256State};
+use sha2::{Digest, Sha256};
+
+fn bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7 + 3) as u8).collect()
+}
+
+#[test]
+fn hashes_like_sha256_in_one_go() {
+    for len in [0, 1, 55, 56, 63, 64, 65, 119, 128, 300] {
+        let bytes = bytes(len);
+        let mut state = Sha256State::default();
+        state.update(&bytes);
+        let expected: [u8; 32] = Sha256::digest(&bytes).into();
+        assert_eq!(state.finalize(), expected, "{}", len);
+    }
+}
+
+#[test]
+fn hashes_like_sha256_in_pieces() {
+    let bytes = bytes(300);
+    let expected: [u8; 32] = Sha256::digest(&bytes).into();
+    for split in [0, 1, 63, 64, 65, 130, 299, 300] {
+        for second_split in [split, split + (300 - split) / 2, 300] {
+            let mut state = Sha256State::default();
+            state.update(&bytes[..split]);
+            state.update(&bytes[split..second_split]);
+            state.update(&bytes[second_split..]);
+            assert_eq!(state.finalize(), expected, "{} {}", split, second_split);
+        }
+    }
+}
+
+// the bincode encoding of a sequence of byte strings: its length, then each string prefixed
+// with its length, as little endian u64s
+fn encode(strings: &[Vec<u8>]) -> Vec<u8> {
+    let mut encoded = (strings.len() as u64).to_le_bytes().to_vec();
+    for string in strings {
+        encoded.extend_from_slice(&(string.len() as u64).to_le_bytes());
+        encoded.extend_from_slice(string);
+    }
+    encoded
+}
+
+#[test]
+fn chained_shards_hash_the_whole_sequence() {
+    let strings: Vec<Vec<u8>> = (0..10).map(|i| bytes(i * 13)).collect();
+    let expected: [u8; 32] = Sha256::digest(encode(&strings)).into();
+    for shard_len in 1..=10 {
+        let mut state = bincode_seq_state(strings.len());
+        for shard in strings.chunks(shard_len) {
+            let span = HashSpan::new(state.clone(), bincode_seq_elements(&encode(shard)));
+            assert_eq!(span.before, state);
+            state = span.after;
+        }
+        assert_eq!(state.finalize(), expected, "{}", shard_len);
+    }
+}
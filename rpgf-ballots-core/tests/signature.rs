@@ -0,0 +1,61 @@
+use k256::ecdsa::SigningKey;
+use rpgf_ballots_core::signature::{
+    hash_message, keccak256, message_hashes, recover_signer, signer_address, verify_signature,
+};
+use rpgf_ballots_core::{Ballot, RejectionReason};
+
+const BALLOT: &str = r#"[{"projectId":"0x27296535734d2a0ed74c252553aec63c94a0a99f035b07b1b80e8a3fe0a830db","amount":"1.5"}]"#;
+
+fn key(byte: u8) -> SigningKey {
+    SigningKey::from_slice(&[byte; 32]).unwrap()
+}
+
+// a 65 byte `r || s || v` signature of `prehash`, as wallets produce them
+fn sign(key: &SigningKey, prehash: &[u8; 32]) -> Vec<u8> {
+    let (signature, recovery_id) = key.sign_prehash_recoverable(prehash).unwrap();
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(recovery_id.to_byte() + 27);
+    bytes
+}
+
+#[test]
+fn signer_address_is_the_ethereum_address() {
+    let mut one = [0; 32];
+    one[31] = 1;
+    let key = SigningKey::from_slice(&one).unwrap();
+    assert_eq!(
+        hex::encode(signer_address(key.verifying_key())),
+        "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+    );
+}
+
+#[test]
+fn ballots_are_signed_as_json_or_as_their_keccak_hash() {
+    let ballot = Ballot::parse(BALLOT.as_bytes().to_vec()).unwrap();
+    let khash = format!("0x{}", hex::encode(keccak256(BALLOT.as_bytes())));
+    assert_eq!(
+        message_hashes(&ballot, None),
+        vec![
+            hash_message(BALLOT.as_bytes()),
+            hash_message(khash.as_bytes())
+        ]
+    );
+
+    let signer = key(7);
+    for message_hash in message_hashes(&ballot, None) {
+        let signature = sign(&signer, &message_hash);
+        assert_eq!(
+            recover_signer(&signature, &message_hash),
+            Some(*signer.verifying_key())
+        );
+        let signature = signature[..64].try_into().unwrap();
+        assert_eq!(
+            verify_signature(signer.verifying_key(), &signature, &ballot, None),
+            Ok(())
+        );
+        assert_eq!(
+            verify_signature(key(8).verifying_key(), &signature, &ballot, None),
+            Err(RejectionReason::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use rpgf_ballots_core::superseded;
+
+#[test]
+fn keeps_the_latest_ballot_of_each_signer() {
+    let (a, b, c) = ([0xaa; 20], [0xbb; 20], [0xcc; 20]);
+    assert_eq!(
+        superseded(&[(a, 1), (b, 5), (a, 2), (c, 0), (b, 4)]),
+        vec![true, false, false, false, true]
+    );
+    // the last of equal nonces wins
+    assert_eq!(superseded(&[(a, 3), (a, 3)]), vec![true, false]);
+    assert!(superseded(&[]).is_empty());
+}
@@ -0,0 +1,137 @@
+use rpgf_ballots_core::tally::mul_div;
+use rpgf_ballots_core::{
+    allocate, tally, BuiltinRule, Mean, ProjectId, Quadratic, Sum, TallyConfig, TallyRule,
+    TrimmedMean,
+};
+
+fn project(byte: u8) -> ProjectId {
+    ProjectId([byte; 32])
+}
+
+#[test]
+fn allocates_the_pool_by_median_above_quorum() {
+    let projects = vec![
+        (project(1), vec![10, 30, 20]),
+        // below the quorum
+        (project(2), vec![1_000]),
+        // median of an even number of votes is the mean of the middle two
+        (project(3), vec![5, 10, 40, 50]),
+        (project(4), vec![0, 0]),
+    ];
+    let outcome = tally(
+        &projects,
+        TallyConfig {
+            quorum: 2,
+            pool: 100,
+            rule: BuiltinRule::Median,
+        },
+    )
+    .unwrap();
+    let amounts: Vec<_> = outcome
+        .allocations
+        .iter()
+        .map(|allocation| {
+            (
+                allocation.project_id,
+                allocation.ballot_count,
+                allocation.amount,
+            )
+        })
+        .collect();
+    // medians 20 and 25: 44.44.. and 55.55.., the leftover unit goes to the largest remainder
+    assert_eq!(
+        amounts,
+        vec![(project(1), 3, 44), (project(3), 4, 56), (project(4), 2, 0)]
+    );
+}
+
+#[test]
+fn rounds_to_the_exact_pool() {
+    let projects: Vec<_> = (0..7).map(|i| (project(i), vec![1])).collect();
+    let outcome = tally(
+        &projects,
+        TallyConfig {
+            quorum: 1,
+            pool: 100,
+            rule: BuiltinRule::Median,
+        },
+    )
+    .unwrap();
+    let amounts: Vec<_> = outcome.allocations.iter().map(|a| a.amount).collect();
+    // equal remainders go to the earlier projects
+    assert_eq!(amounts, vec![15, 15, 14, 14, 14, 14, 14]);
+
+    let pool = 30_000_000 * 10u128.pow(18);
+    let projects = vec![
+        (project(1), vec![i128::MAX / 3]),
+        (project(2), vec![i128::MAX / 7]),
+        (project(3), vec![1]),
+    ];
+    let outcome = tally(
+        &projects,
+        TallyConfig {
+            quorum: 1,
+            pool,
+            rule: BuiltinRule::Median,
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        outcome.allocations.iter().map(|a| a.amount).sum::<u128>(),
+        pool
+    );
+}
+
+#[test]
+fn mul_div_uses_a_wide_product() {
+    assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), (u128::MAX, 0));
+    assert_eq!(mul_div(u128::MAX, 3, 7), (u128::MAX / 7 * 3 + 1, 2));
+    assert_eq!(mul_div(10, 1, 3), (3, 1));
+}
+
+#[test]
+fn scores_with_the_builtin_rules() {
+    let amounts = [1, 4, 9, 16, 100];
+    assert_eq!(Sum.score(&amounts), Ok(130));
+    assert_eq!(Mean.score(&amounts), Ok(26));
+    assert_eq!(BuiltinRule::Median.score(&amounts), Ok(18));
+    assert_eq!(TrimmedMean { trim: 1 }.score(&amounts), Ok(9));
+    assert_eq!(TrimmedMean { trim: 3 }.score(&amounts), Ok(0));
+    assert_eq!(Quadratic.score(&amounts), Ok(20 * 20));
+    assert_eq!(Quadratic.score(&[u128::MAX]), Ok((u64::MAX as u128).pow(2)));
+
+    for rule in ["sum", "mean", "median", "trimmed_mean:2", "quadratic"] {
+        assert_eq!(rule.parse::<BuiltinRule>().unwrap().to_string(), rule);
+    }
+    assert!("trimmed_mean".parse::<BuiltinRule>().is_err());
+
+    let outcome = tally(
+        &[(project(1), vec![1, 4]), (project(2), vec![9])],
+        TallyConfig {
+            quorum: 1,
+            pool: 10,
+            rule: BuiltinRule::Quadratic,
+        },
+    )
+    .unwrap();
+    assert_eq!(outcome.rule_id, Quadratic.id());
+    // scores of 9 and 9
+    assert_eq!(
+        outcome.allocations,
+        allocate(
+            &[(project(1), vec![1, 4]), (project(2), vec![9])],
+            1,
+            10,
+            &Quadratic
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        outcome
+            .allocations
+            .iter()
+            .map(|a| a.amount)
+            .collect::<Vec<_>>(),
+        vec![5, 5]
+    );
+}
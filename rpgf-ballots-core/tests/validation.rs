@@ -0,0 +1,133 @@
+use k256::ecdsa::SigningKey;
+use rpgf_ballots_core::signature::{hash_message, signer_address};
+use rpgf_ballots_core::{
+    validate_ballot, Allowlist, BallotChecks, GuestConfig, ProjectId, RejectionPolicy,
+    RejectionReason, SafeOwners,
+};
+
+const PROJECT: &str = "0x27296535734d2a0ed74c252553aec63c94a0a99f035b07b1b80e8a3fe0a830db";
+const SAFE: [u8; 20] = [0x5a; 20];
+
+fn key(byte: u8) -> SigningKey {
+    SigningKey::from_slice(&[byte; 32]).unwrap()
+}
+
+fn ballot(amount: &str) -> Vec<u8> {
+    format!(r#"[{{"projectId":"{}","amount":"{}"}}]"#, PROJECT, amount).into_bytes()
+}
+
+// a 65 byte `r || s || v` EIP-191 signature of the ballot json
+fn sign(key: &SigningKey, ballot: &[u8]) -> Vec<u8> {
+    let (signature, recovery_id) = key.sign_prehash_recoverable(&hash_message(ballot)).unwrap();
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(recovery_id.to_byte() + 27);
+    bytes
+}
+
+fn sec1(key: &SigningKey) -> Vec<u8> {
+    key.verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec()
+}
+
+#[test]
+fn rejects_one_ballot_for_each_reason() {
+    let project_ids: Vec<ProjectId> = vec![PROJECT.parse().unwrap()];
+    let mut owners = vec![
+        signer_address(key(1).verifying_key()),
+        signer_address(key(2).verifying_key()),
+    ];
+    owners.sort_unstable();
+    let safe_owners = vec![SafeOwners {
+        safe: SAFE,
+        threshold: 2,
+        owners,
+    }];
+    let config = GuestConfig {
+        rejection_policy: RejectionPolicy::Skip,
+        amount_scale: 0,
+        eligibility: Some(Allowlist::new(vec![
+            signer_address(key(1).verifying_key()),
+            SAFE,
+        ])),
+        ..GuestConfig::default()
+    };
+    let checks = BallotChecks::new(Some(&project_ids), &safe_owners, &config);
+    let check = |ballot: Vec<u8>, signature: &[u8], verifying_key: Option<&[u8]>| {
+        validate_ballot(ballot, signature, verifying_key, &checks)
+            .map(|(signer, _, amounts)| (signer, amounts))
+    };
+
+    let signer = key(1);
+    let valid = ballot("15");
+    let signature = sign(&signer, &valid);
+    assert_eq!(
+        check(valid.clone(), &signature[..64], Some(&sec1(&signer))),
+        Ok((signer_address(signer.verifying_key()), vec![15]))
+    );
+
+    let rejections = [
+        (
+            check(b"[{".to_vec(), &signature[..64], Some(&sec1(&signer))),
+            RejectionReason::MalformedBallot,
+        ),
+        (
+            check(valid.clone(), &signature[..64], Some(&sec1(&key(2)))),
+            RejectionReason::InvalidSignature,
+        ),
+        (
+            check(valid.clone(), &signature[..64], Some(&[7; 33])),
+            RejectionReason::InvalidVerifyingKey,
+        ),
+        (
+            check(valid.clone(), &signature[..64], None),
+            RejectionReason::MissingVerifyingKey,
+        ),
+        {
+            let ballot = format!(r#"[{{"projectId":"0x{}","amount":"1"}}]"#, "11".repeat(32));
+            let signature = sign(&signer, ballot.as_bytes());
+            (
+                check(ballot.into_bytes(), &signature[..64], Some(&sec1(&signer))),
+                RejectionReason::UnknownProject,
+            )
+        },
+        {
+            let ballot = ballot("1.5");
+            let signature = sign(&signer, &ballot);
+            (
+                check(ballot, &signature[..64], Some(&sec1(&signer))),
+                RejectionReason::InvalidAmount,
+            )
+        },
+        {
+            let signature = sign(&key(3), &valid);
+            (
+                check(valid.clone(), &signature[..64], Some(&sec1(&key(3)))),
+                RejectionReason::NotEligible,
+            )
+        },
+        (
+            check(valid.clone(), &signature, Some(&SAFE)),
+            RejectionReason::InsufficientSignatures,
+        ),
+        (
+            check(valid.clone(), &signature, Some(&[0x5b; 20])),
+            RejectionReason::UnknownSafe,
+        ),
+        (
+            check(valid.clone(), &signature[..64], Some(&[])),
+            RejectionReason::UnrecoveredSigner,
+        ),
+    ];
+    for (code, (result, reason)) in (1..).zip(rejections) {
+        assert_eq!(reason.code(), code);
+        assert_eq!(result, Err(reason));
+    }
+
+    // a malformed ballot is reported as such whatever the host recovered
+    assert_eq!(
+        check(b"{}".to_vec(), &[], Some(&[])),
+        Err(RejectionReason::MalformedBallot)
+    );
+}
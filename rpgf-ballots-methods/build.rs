@@ -12,6 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use risc0_build::GuestOptions;
+use std::collections::HashMap;
+use std::env;
+
 fn main() {
-    risc0_build::embed_methods();
+    // the guests run Poseidon on the zkVM's bigint accelerator, unless RPGF_SOFTWARE_FIELD is set
+    // to benchmark them against the software field arithmetic
+    println!("cargo:rerun-if-env-changed=RPGF_SOFTWARE_FIELD");
+    let features = match env::var_os("RPGF_SOFTWARE_FIELD") {
+        Some(_) => vec![],
+        None => vec!["bigint".to_string()],
+    };
+    risc0_build::embed_methods_with_options(HashMap::from([(
+        "rpgf-ballots",
+        GuestOptions {
+            features,
+            ..Default::default()
+        },
+    )]));
 }
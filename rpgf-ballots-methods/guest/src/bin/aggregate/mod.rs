@@ -0,0 +1,122 @@
+use crate::round::{self, RoundHashes};
+use crate::validation::Validated;
+use halo2curves::bn256::Fr as Fp;
+use rpgf_ballots_core::merkle::{badgeholder_commitment, vote_leaf, votes_root};
+use rpgf_ballots_core::poseidon::{hash_vote_amounts, vote_amounts_params};
+use rpgf_ballots_core::{
+    leaf_nonce, project_salt, tally, votes_root_salt, Felt, GuestConfig, ProjectFilter,
+    ProjectHash, ProjectId, ProjectsJournal,
+};
+
+// Counting shared by the aggregate (projects.rs), shards (shards.rs) and incremental
+// (increment.rs) guests
+
+/// Builds the aggregate journal of the `ballot_count` ballots of the round once validated.
+///
+/// The hashes and votes roots are committed for the projects the filter selects.
+pub fn journal(
+    hashes: &RoundHashes,
+    project_ids_fixed: &[ProjectId],
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    ballot_count: usize,
+    validated: &Validated,
+    shard_image_id: Option<[u32; 8]>,
+) -> ProjectsJournal {
+    // Initialize the project_votes vector. The vector length is equal to the number of projects
+    let mut project_votes: Vec<Vec<i128>> = vec![vec![]; project_ids_fixed.len()];
+    // and the leaves of the votes tree of each project, in the same order
+    let mut project_leaves: Vec<Vec<Fp>> = vec![vec![]; project_ids_fixed.len()];
+
+    // Process the ballots to populate the project_votes vector
+    for valid in validated.ballots.iter() {
+        // the badgeholder's commitment is the same in the leaves of each of their votes
+        let nonce = config
+            .salt_secret
+            .map(|secret| leaf_nonce(&secret, &valid.signer));
+        let commitment = badgeholder_commitment(&valid.signer, nonce);
+        for (project_id, amount) in valid.votes.iter() {
+            populate_project_votes(
+                project_ids_fixed,
+                &mut project_votes,
+                &mut project_leaves,
+                project_id,
+                commitment,
+                *amount,
+            );
+        }
+    }
+
+    // The allocations are computed over every project of the registry, regardless of the filter
+    let tally = config.tally.map(|tally_config| {
+        let project_amounts: Vec<(ProjectId, Vec<i128>)> = project_ids_fixed
+            .iter()
+            .copied()
+            .zip(project_votes.iter().cloned())
+            .collect();
+        tally(&project_amounts, tally_config).unwrap()
+    });
+
+    // projects without votes get zero hashes, as in the single project guest (project.rs)
+    let projects: Vec<ProjectHash> = project_ids_fixed
+        .iter()
+        .zip(project_votes.iter().zip(project_leaves.iter()))
+        .filter(|(project_id, (vote_amounts, _))| filter.matches(project_id, vote_amounts))
+        .map(|(project_id, (vote_amounts, leaves))| {
+            let (hash, votes_root) = if vote_amounts.is_empty() {
+                (Felt([0; 4]), Felt([0; 4]))
+            } else {
+                let salt = config
+                    .salt_secret
+                    .map(|secret| project_salt(&secret, project_id));
+                let root_salt = config
+                    .salt_secret
+                    .map(|secret| votes_root_salt(&secret, project_id));
+                (
+                    hash_vote_amounts(vote_amounts, salt, &config.poseidon).unwrap(),
+                    votes_root(leaves, root_salt).into(),
+                )
+            };
+            ProjectHash {
+                project_id: *project_id,
+                vote_count: vote_amounts.len() as u32,
+                hash,
+                votes_root,
+            }
+        })
+        .collect();
+
+    ProjectsJournal {
+        header: round::header(
+            hashes,
+            config,
+            ballot_count,
+            validated,
+            vote_amounts_params(&config.poseidon),
+        ),
+        filter: filter.clone(),
+        projects,
+        tally,
+        shard_image_id,
+    }
+}
+
+//inline
+#[inline]
+pub fn populate_project_votes(
+    project_ids_fixed: &[ProjectId],
+    project_votes: &mut [Vec<i128>],
+    project_leaves: &mut [Vec<Fp>],
+    project_id: &ProjectId,
+    commitment: Fp,
+    vote_amount: i128,
+) {
+    // validation rejects ballots voting for projects outside of project_ids_fixed
+    let project_index = project_ids_fixed
+        .iter()
+        .position(|x| x == project_id)
+        .unwrap();
+
+    project_votes[project_index].push(vote_amount);
+    project_leaves[project_index].push(vote_leaf(commitment, vote_amount));
+}
@@ -0,0 +1,105 @@
+use risc0_zkvm::guest::env;
+use risc0_zkvm::serde::to_vec;
+use rpgf_ballots_core::{GuestConfig, IncrementalJournal, InputCommitment, ProjectFilter};
+
+mod aggregate;
+mod round;
+mod validation;
+
+// Extends the receipt of the previous step, verified as an assumption, with the ballots appended
+// to the processed inputs since, committing the journal the aggregate guest (projects.rs) would
+// for every ballot so far
+
+fn main() {
+    let round = round::read();
+
+    // The projects to commit the hashes of, committed to the journal
+    let filter: ProjectFilter = env::read();
+
+    let config: GuestConfig = env::read();
+
+    // this guest's image id, which the previous step was proven with, and the journal of the
+    // previous step's receipt, which the host adds as an assumption. None for the first step
+    let image_id: [u32; 8] = env::read();
+    let previous: Option<IncrementalJournal> = env::read();
+
+    let config_hash = round::config_hash(&config);
+    let (mut ballots, mut rejections, mut input_chain) = (vec![], vec![], vec![]);
+    let mut checked = 0;
+    if let Some(previous) = previous {
+        env::verify(
+            image_id,
+            bytemuck::cast_slice::<u32, u8>(&to_vec(&previous).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(previous.image_id, image_id);
+        assert_eq!(previous.config_hash, config_hash, "The config changed");
+
+        // the previous inputs and verifying keys are a prefix of the current ones, and the
+        // ballots were validated against the same round data
+        let header = previous.journal.header;
+        checked = header.ballot_count as usize;
+        let vks = &round.vks[..checked.min(round.vks.len())];
+        assert_eq!(
+            header.inputs_hash,
+            round::sha256(&bincode::serialize(&round.inputs[..checked]).unwrap()),
+            "The previous ballots changed"
+        );
+        assert_eq!(
+            header.verifying_keys_hash,
+            round::sha256(&bincode::serialize(vks).unwrap()),
+            "The previous verifying keys changed"
+        );
+        // the registry is a fixed round input (private_processing --project_registry), not
+        // derived from the votes so far
+        assert_eq!(
+            header.project_registry_hash, round.hashes.project_registry_hash,
+            "The project registry changed"
+        );
+        assert_eq!(header.safe_owners_hash, round.hashes.safe_owners_hash);
+
+        ballots =
+            validation::recover_ballots(&round.inputs[..checked], vks, &header.rejections, &config);
+        rejections = header.rejections;
+        input_chain = previous.input_chain;
+    }
+
+    // only the new ballots have their signatures checked
+    let ballot_count = round.inputs.len();
+    let vks = &round.vks[checked.min(round.vks.len())..];
+    let (new_ballots, new_rejections) = validation::check_ballots(
+        round.inputs[checked..].to_vec(),
+        vks,
+        checked,
+        Some(&round.project_ids),
+        &round.safe_owners,
+        &config,
+    );
+    ballots.extend(new_ballots);
+    rejections.extend(new_rejections);
+    let validated = validation::supersede(ballots, rejections);
+
+    input_chain.push(InputCommitment {
+        inputs_hash: round.hashes.inputs_hash,
+        ballot_count: ballot_count as u32,
+    });
+    env::commit(&IncrementalJournal {
+        journal: aggregate::journal(
+            &round.hashes,
+            &round.project_ids,
+            &filter,
+            &config,
+            ballot_count,
+            &validated,
+            None,
+        ),
+        image_id,
+        config_hash,
+        input_chain,
+    });
+
+    println!(
+        "Total cycles for guest code execution: {}",
+        env::cycle_count()
+    );
+}
@@ -0,0 +1,137 @@
+// each guest only uses part of the shared modules
+#![allow(dead_code)]
+
+use crate::validation::Validated;
+use risc0_zkvm::guest::env;
+use risc0_zkvm::serde::to_vec;
+use risc0_zkvm::sha::{Impl, Sha256};
+use rpgf_ballots_core::{
+    parse_safe_owners, salt_commitment, GuestConfig, JournalHeader, PoseidonParams, ProjectId,
+    SafeOwners, JOURNAL_VERSION,
+};
+
+// Round data shared by the single project (project.rs), aggregate (projects.rs), shard
+// (shard.rs), shards (shards.rs) and incremental (increment.rs) guests
+
+/// The private round data read by the guests, with the sha256 hashes committed to the journal.
+///
+/// The shard guest reads the ballots of its shard and their verifying keys in place of the
+/// whole files, and the shards guest only reads the registry (see [`read_registry`]).
+///
+/// The host passes the raw bytes of the processed inputs, verifying keys, project registry and
+/// optional Safe owner sets files so that the image ids don't depend on the ballot set, and so that verifiers can check
+/// the hashes against the files with `sha256sum`.
+pub struct Round {
+    pub inputs: Vec<(Vec<u8>, Vec<u8>)>,
+    pub vks: Vec<Vec<u8>>,
+    pub project_ids: Vec<ProjectId>,
+    /// Owner sets of the Safe multisig badgeholders, empty if none were given.
+    pub safe_owners: Vec<SafeOwners>,
+    pub hashes: RoundHashes,
+}
+
+/// Sha256 hashes of the round data files.
+pub struct RoundHashes {
+    pub inputs_hash: [u8; 32],
+    pub verifying_keys_hash: [u8; 32],
+    pub project_registry_hash: [u8; 32],
+    pub safe_owners_hash: Option<[u8; 32]>,
+}
+
+/// The project registry and Safe owner sets of the round, with their sha256 hashes.
+pub struct Registry {
+    pub project_ids: Vec<ProjectId>,
+    pub safe_owners: Vec<SafeOwners>,
+    pub project_registry_hash: [u8; 32],
+    pub safe_owners_hash: Option<[u8; 32]>,
+}
+
+/// Reads the round data, in the order the host writes it.
+pub fn read() -> Round {
+    let inputs_bytes: Vec<u8> = env::read();
+    let vks_bytes: Vec<u8> = env::read();
+    let registry = read_registry();
+
+    Round {
+        inputs: bincode::deserialize(&inputs_bytes).unwrap(),
+        vks: bincode::deserialize(&vks_bytes).unwrap(),
+        project_ids: registry.project_ids,
+        safe_owners: registry.safe_owners,
+        hashes: RoundHashes {
+            inputs_hash: sha256(&inputs_bytes),
+            verifying_keys_hash: sha256(&vks_bytes),
+            project_registry_hash: registry.project_registry_hash,
+            safe_owners_hash: registry.safe_owners_hash,
+        },
+    }
+}
+
+/// Reads the project registry and Safe owner sets of the round, which the shards guest reads
+/// without the ballots.
+pub fn read_registry() -> Registry {
+    let project_ids_bytes: Vec<u8> = env::read();
+    let safe_owners_bytes: Option<Vec<u8>> = env::read();
+
+    let project_ids: Vec<String> = serde_json::from_slice(&project_ids_bytes).unwrap();
+    Registry {
+        project_ids: project_ids
+            .iter()
+            .map(|project_id| project_id.parse().unwrap())
+            .collect(),
+        safe_owners: safe_owners_bytes
+            .as_ref()
+            .map(|bytes| parse_safe_owners(bytes).unwrap())
+            .unwrap_or_default(),
+        project_registry_hash: sha256(&project_ids_bytes),
+        safe_owners_hash: safe_owners_bytes.as_deref().map(sha256),
+    }
+}
+
+/// Builds the journal header of the round once its `ballot_count` ballots were validated.
+pub fn header(
+    hashes: &RoundHashes,
+    config: &GuestConfig,
+    ballot_count: usize,
+    validated: &Validated,
+    poseidon: PoseidonParams,
+) -> JournalHeader {
+    JournalHeader {
+        version: JOURNAL_VERSION,
+        round_id: config.round_id,
+        inputs_hash: hashes.inputs_hash,
+        verifying_keys_hash: hashes.verifying_keys_hash,
+        project_registry_hash: hashes.project_registry_hash,
+        safe_owners_hash: hashes.safe_owners_hash,
+        eligibility_root: config
+            .eligibility
+            .as_ref()
+            .map(|allowlist| allowlist.root()),
+        eip712_domain_separator: config.eip712_domain_separator(),
+        amount_scale: config.amount_scale,
+        poseidon,
+        salt_commitment: config.salt_secret.as_ref().map(salt_commitment),
+        ballot_count: ballot_count as u32,
+        accepted_count: validated.ballots.len() as u32,
+        superseded_count: validated.superseded_count as u32,
+        rejections: validated.rejections.clone(),
+    }
+}
+
+/// The sha256 hash of the guest config, which the shards guest checks every shard was
+/// validated with, and the incremental guest every step was proven with.
+///
+/// The hash is committed to the journals, so the salt secret is hashed as its commitment, the
+/// one the journal header publishes.
+pub fn config_hash(config: &GuestConfig) -> [u8; 32] {
+    let public = GuestConfig {
+        salt_secret: None,
+        ..config.clone()
+    };
+    let commitment = config.salt_secret.as_ref().map(salt_commitment);
+    let words = to_vec(&(public, commitment)).unwrap();
+    sha256(bytemuck::cast_slice(&words))
+}
+
+pub fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Impl::hash_bytes(bytes).as_bytes().try_into().unwrap()
+}
@@ -0,0 +1,58 @@
+use risc0_zkvm::guest::env;
+use rpgf_ballots_core::sha256::bincode_seq_elements;
+use rpgf_ballots_core::{GuestConfig, HashSpan, Sha256State, ShardJournal};
+
+mod round;
+mod validation;
+
+// Validates a shard of the ballots for the shards guest (shards.rs), which verifies its receipt
+// as an assumption
+
+fn main() {
+    // the ballots of the shard and their verifying keys, with the rest of the round data
+    let round = round::read();
+    // the index of the first ballot of the shard in the processed inputs
+    let offset: u32 = env::read();
+    let config: GuestConfig = env::read();
+    // the states the hashes of the processed inputs and verifying keys files were left in by
+    // the previous shards, which the shards guest chains
+    let inputs_state: Sha256State = env::read();
+    let vks_state: Sha256State = env::read();
+
+    let ballot_count = round.inputs.len();
+    let verifying_key_count = round.vks.len();
+    let inputs = HashSpan::new(
+        inputs_state,
+        bincode_seq_elements(&bincode::serialize(&round.inputs).unwrap()),
+    );
+    let verifying_keys = HashSpan::new(
+        vks_state,
+        bincode_seq_elements(&bincode::serialize(&round.vks).unwrap()),
+    );
+    let (ballots, rejections) = validation::check_ballots(
+        round.inputs,
+        &round.vks,
+        offset as usize,
+        Some(&round.project_ids),
+        &round.safe_owners,
+        &config,
+    );
+
+    env::commit(&ShardJournal {
+        offset,
+        ballot_count: ballot_count as u32,
+        verifying_key_count: verifying_key_count as u32,
+        inputs,
+        verifying_keys,
+        project_registry_hash: round.hashes.project_registry_hash,
+        safe_owners_hash: round.hashes.safe_owners_hash,
+        config_hash: round::config_hash(&config),
+        ballots,
+        rejections,
+    });
+
+    println!(
+        "Total cycles for guest code execution: {}",
+        env::cycle_count()
+    );
+}
@@ -0,0 +1,93 @@
+use risc0_zkvm::guest::env;
+use risc0_zkvm::serde::to_vec;
+use rpgf_ballots_core::sha256::bincode_seq_state;
+use rpgf_ballots_core::{GuestConfig, ProjectFilter, ShardJournal};
+
+mod aggregate;
+mod round;
+mod validation;
+
+// Aggregates the receipts of the shard guest (shard.rs) into the journal the aggregate guest
+// (projects.rs) commits for the whole round, without checking a single signature itself. It
+// reads the shard journals in place of the ballots, and commits the hashes of the processed
+// inputs and verifying keys files the shards hashed in turn
+
+fn main() {
+    let registry = round::read_registry();
+
+    // The projects to commit the hashes of, committed to the journal
+    let filter: ProjectFilter = env::read();
+
+    let config: GuestConfig = env::read();
+
+    // the shard guest's image id, committed to the journal for verifiers to check, and the
+    // journals of its receipts, which the host adds as assumptions
+    let shard_image_id: [u32; 8] = env::read();
+    let shards: Vec<ShardJournal> = env::read();
+
+    let ballot_count: usize = shards.iter().map(|shard| shard.ballot_count as usize).sum();
+    let verifying_key_count: usize = shards
+        .iter()
+        .map(|shard| shard.verifying_key_count as usize)
+        .sum();
+    let mut inputs_state = bincode_seq_state(ballot_count);
+    let mut vks_state = bincode_seq_state(verifying_key_count);
+
+    let config_hash = round::config_hash(&config);
+    let mut ballots = vec![];
+    let mut rejections = vec![];
+    let mut offset = 0;
+    for shard in shards {
+        env::verify(
+            shard_image_id,
+            bytemuck::cast_slice::<u32, u8>(&to_vec(&shard).unwrap()),
+        )
+        .unwrap();
+
+        // the shards cover the processed inputs in order, each hashing its ballots on from
+        // where the previous one left the hashes of the files, and were validated against the
+        // same round data and config
+        assert_eq!(shard.offset as usize, offset, "Shard out of order");
+        assert_eq!(
+            shard.inputs.before, inputs_state,
+            "Shard inputs out of order"
+        );
+        assert_eq!(
+            shard.verifying_keys.before, vks_state,
+            "Shard verifying keys out of order"
+        );
+        assert_eq!(shard.project_registry_hash, registry.project_registry_hash);
+        assert_eq!(shard.safe_owners_hash, registry.safe_owners_hash);
+        assert_eq!(shard.config_hash, config_hash);
+
+        inputs_state = shard.inputs.after;
+        vks_state = shard.verifying_keys.after;
+        ballots.extend(shard.ballots);
+        rejections.extend(shard.rejections);
+        offset += shard.ballot_count as usize;
+    }
+
+    // a badgeholder's ballots may be in different shards
+    let validated = validation::supersede(ballots, rejections);
+
+    let hashes = round::RoundHashes {
+        inputs_hash: inputs_state.finalize(),
+        verifying_keys_hash: vks_state.finalize(),
+        project_registry_hash: registry.project_registry_hash,
+        safe_owners_hash: registry.safe_owners_hash,
+    };
+    env::commit(&aggregate::journal(
+        &hashes,
+        &registry.project_ids,
+        &filter,
+        &config,
+        ballot_count,
+        &validated,
+        Some(shard_image_id),
+    ));
+
+    println!(
+        "Total cycles for guest code execution: {}",
+        env::cycle_count()
+    );
+}
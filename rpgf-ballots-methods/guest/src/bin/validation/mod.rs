@@ -0,0 +1,143 @@
+// each guest only uses part of the shared modules
+#![allow(dead_code)]
+
+use k256::ecdsa::VerifyingKey;
+use rpgf_ballots_core::signature::signer_address;
+use rpgf_ballots_core::{
+    superseded, validate_ballot, Address, Ballot, BallotChecks, GuestConfig, ProjectId, Rejection,
+    RejectionPolicy, SafeOwners, ValidBallot,
+};
+
+// Ballot validation shared by the single project (project.rs), aggregate (projects.rs), shard
+// (shard.rs) and incremental (increment.rs) guests
+
+/// The ballots that passed validation and the rejections of the ones that didn't.
+pub struct Validated {
+    /// The latest valid ballot of every signer, in input order.
+    pub ballots: Vec<ValidBallot>,
+    pub rejections: Vec<Rejection>,
+    /// Number of valid ballots superseded by a later ballot of their signer.
+    pub superseded_count: usize,
+}
+
+/// Validates every `(ballot, signature)` input against the verifying key at the same index, then
+/// keeps the latest valid ballot of every signer.
+pub fn validate_ballots(
+    inputs: Vec<(Vec<u8>, Vec<u8>)>,
+    vks: &[Vec<u8>],
+    project_ids: Option<&[ProjectId]>,
+    safe_owners: &[SafeOwners],
+    config: &GuestConfig,
+) -> Validated {
+    let (ballots, rejections) = check_ballots(inputs, vks, 0, project_ids, safe_owners, config);
+    supersede(ballots, rejections)
+}
+
+/// Validates every `(ballot, signature)` input against the verifying key at the same index, see
+/// [`validate_ballot`].
+///
+/// The inputs are the ballots from `first_index` on of the processed inputs, which is what
+/// valid ballots and rejections are indexed by.
+///
+/// Invalid ballots either abort the guest or are reported as rejections according to the
+/// config's rejection policy.
+pub fn check_ballots(
+    inputs: Vec<(Vec<u8>, Vec<u8>)>,
+    vks: &[Vec<u8>],
+    first_index: usize,
+    project_ids: Option<&[ProjectId]>,
+    safe_owners: &[SafeOwners],
+    config: &GuestConfig,
+) -> (Vec<ValidBallot>, Vec<Rejection>) {
+    let mut ballots = Vec::with_capacity(inputs.len());
+    let mut rejections = vec![];
+    let checks = BallotChecks::new(project_ids, safe_owners, config);
+    for (position, (ballot, signature)) in inputs.into_iter().enumerate() {
+        let index = first_index + position;
+        let verifying_key = vks.get(position).map(Vec::as_slice);
+        match validate_ballot(ballot, &signature, verifying_key, &checks) {
+            Ok((signer, ballot, amounts)) => ballots.push(ValidBallot {
+                index: index as u32,
+                signer,
+                nonce: ballot.nonce(),
+                votes: ballot
+                    .votes()
+                    .iter()
+                    .map(|vote| vote.project_id)
+                    .zip(amounts)
+                    .collect(),
+            }),
+            Err(reason) => match config.rejection_policy {
+                RejectionPolicy::Abort => panic!("Ballot {} rejected: {}", index, reason),
+                RejectionPolicy::Skip => rejections.push(Rejection {
+                    index: index as u32,
+                    reason,
+                }),
+            },
+        }
+    }
+    (ballots, rejections)
+}
+
+/// Keeps the valid ballot with the highest nonce of each signer, out of the valid `ballots` of
+/// every input in input order.
+pub fn supersede(ballots: Vec<ValidBallot>, rejections: Vec<Rejection>) -> Validated {
+    let signers: Vec<(Address, u64)> = ballots
+        .iter()
+        .map(|valid| (valid.signer, valid.nonce))
+        .collect();
+    let superseded = superseded(&signers);
+    let superseded_count = superseded.iter().filter(|superseded| **superseded).count();
+    let ballots = ballots
+        .into_iter()
+        .zip(superseded)
+        .filter_map(|(valid, superseded)| (!superseded).then_some(valid))
+        .collect();
+    Validated {
+        ballots,
+        rejections,
+        superseded_count,
+    }
+}
+
+/// Recovers the valid ballots of `inputs` that a verified journal already checked, the ones
+/// it doesn't list in `rejections`, without checking their signatures again.
+pub fn recover_ballots(
+    inputs: &[(Vec<u8>, Vec<u8>)],
+    vks: &[Vec<u8>],
+    rejections: &[Rejection],
+    config: &GuestConfig,
+) -> Vec<ValidBallot> {
+    let mut rejected = rejections
+        .iter()
+        .map(|rejection| rejection.index)
+        .peekable();
+    let mut ballots = Vec::with_capacity(inputs.len());
+    for (index, ((ballot, _), verifying_key)) in inputs.iter().zip(vks.iter()).enumerate() {
+        // rejections are in input order
+        if rejected.next_if_eq(&(index as u32)).is_some() {
+            continue;
+        }
+        let signer = match Address::try_from(verifying_key.as_slice()) {
+            Ok(safe) => safe,
+            Err(_) => signer_address(&VerifyingKey::from_sec1_bytes(verifying_key).unwrap()),
+        };
+        let ballot = Ballot::parse(ballot.clone()).unwrap();
+        ballots.push(ValidBallot {
+            index: index as u32,
+            signer,
+            nonce: ballot.nonce(),
+            votes: ballot
+                .votes()
+                .iter()
+                .map(|vote| {
+                    (
+                        vote.project_id,
+                        vote.amount.to_fixed(config.amount_scale).unwrap(),
+                    )
+                })
+                .collect(),
+        });
+    }
+    ballots
+}
@@ -0,0 +1,160 @@
+use crate::inputs::VotesTable;
+use anyhow::{bail, Context, Result};
+use rpgf_ballots_core::poseidon::hash_vote_amounts;
+use rpgf_ballots_core::{
+    project_salt, salt_commitment, Felt, JournalHeader, ProjectId, ProjectJournal, ProjectsJournal,
+};
+use std::collections::BTreeMap;
+
+/// Salts of the project hashes revealed to an auditor, by project.
+pub type Salts = BTreeMap<ProjectId, Felt>;
+
+/// A project hash committed by a journal, next to the one recomputed from the votes table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashAudit {
+    pub project_id: ProjectId,
+    pub recomputed: Felt,
+    pub committed: Felt,
+}
+
+impl HashAudit {
+    pub fn matches(&self) -> bool {
+        self.recomputed == self.committed
+    }
+}
+
+/// Checks the operator's salt secret against the salt commitment of the journal `header`.
+pub fn check_salt_secret(secret: &[u8; 32], header: &JournalHeader) -> Result<()> {
+    match header.salt_commitment {
+        Some(commitment) if commitment == salt_commitment(secret) => Ok(()),
+        Some(_) => bail!("The salt secret doesn't match the journal's salt commitment"),
+        None => bail!("The journal's hashes aren't salted"),
+    }
+}
+
+/// Derives the salts of `project_ids` from the operator's secret, once checked against the salt
+/// commitment of the journal `header`.
+pub fn salts_from_secret(
+    secret: &[u8; 32],
+    header: &JournalHeader,
+    project_ids: impl IntoIterator<Item = ProjectId>,
+) -> Result<Salts> {
+    check_salt_secret(secret, header)?;
+    Ok(project_ids
+        .into_iter()
+        .map(|project_id| (project_id, project_salt(secret, &project_id)))
+        .collect())
+}
+
+/// Recomputes the hash of every project of the votes table with the Poseidon parameters of the
+/// journal `header`, in the table's order.
+///
+/// If the header commits to a salt secret, only the projects with a revealed salt are
+/// recomputed.
+pub fn recompute_hashes(
+    votes_table: &VotesTable,
+    header: &JournalHeader,
+    salts: &Salts,
+) -> Result<Vec<(ProjectId, Felt)>> {
+    if header.salt_commitment.is_none() && !salts.is_empty() {
+        bail!("The journal's hashes aren't salted");
+    }
+    let config = header.poseidon.config();
+    let mut hashes = vec![];
+    for (project_id, amounts) in parse_votes_table(votes_table)? {
+        let salt = match header.salt_commitment {
+            Some(_) => match salts.get(&project_id) {
+                Some(salt) => Some(*salt),
+                None => continue,
+            },
+            None => None,
+        };
+        hashes.push((project_id, hash_vote_amounts(amounts, salt, &config)?));
+    }
+    Ok(hashes)
+}
+
+/// Recomputes the hash committed by a single project guest (project.rs) journal, decoded from a
+/// receipt verified with [`crate::journal::verify_project_receipt`].
+///
+/// Returns `None` if the hash is salted and its salt wasn't revealed.
+pub fn audit_project_journal(
+    votes_table: &VotesTable,
+    journal: &ProjectJournal,
+    salts: &Salts,
+) -> Result<Option<HashAudit>> {
+    if journal.header.salt_commitment.is_some() && !salts.contains_key(&journal.project_id) {
+        return Ok(None);
+    }
+    let recomputed = recompute_hashes(votes_table, &journal.header, salts)?
+        .into_iter()
+        .find(|(project_id, _)| *project_id == journal.project_id)
+        .map(|(_, hash)| hash)
+        // the guest commits a zero hash for a project nobody voted for
+        .unwrap_or(Felt([0; 4]));
+    Ok(Some(HashAudit {
+        project_id: journal.project_id,
+        recomputed,
+        committed: journal.hash,
+    }))
+}
+
+/// Recomputes the hashes committed by an aggregate guest (projects.rs) journal, decoded from a
+/// receipt verified with [`crate::journal::verify_aggregate_receipt`], for each project it
+/// lists.
+///
+/// Fails if the votes table doesn't have as many votes for a project as the journal counts.
+/// Salted hashes whose salt wasn't revealed are left out.
+pub fn audit_projects_journal(
+    votes_table: &VotesTable,
+    journal: &ProjectsJournal,
+    salts: &Salts,
+) -> Result<Vec<HashAudit>> {
+    let hashes = recompute_hashes(votes_table, &journal.header, salts)?;
+    let vote_counts: BTreeMap<ProjectId, usize> = parse_votes_table(votes_table)?
+        .into_iter()
+        .map(|(project_id, amounts)| (project_id, amounts.len()))
+        .collect();
+    let mut audits = vec![];
+    for project in &journal.projects {
+        let vote_count = vote_counts.get(&project.project_id).copied().unwrap_or(0);
+        if vote_count != project.vote_count as usize {
+            bail!(
+                "The journal counts {} votes for project {}, the votes table has {}",
+                project.vote_count,
+                project.project_id,
+                vote_count
+            );
+        }
+        if journal.header.salt_commitment.is_some() && !salts.contains_key(&project.project_id) {
+            continue;
+        }
+        let recomputed = match vote_count {
+            // the guest commits a zero hash for a project nobody voted for
+            0 => Felt([0; 4]),
+            _ => hashes
+                .iter()
+                .find(|(project_id, _)| *project_id == project.project_id)
+                .map(|(_, hash)| *hash)
+                .with_context(|| format!("Missing hash of project {}", project.project_id))?,
+        };
+        audits.push(HashAudit {
+            project_id: project.project_id,
+            recomputed,
+            committed: project.hash,
+        });
+    }
+    Ok(audits)
+}
+
+fn parse_votes_table(votes_table: &VotesTable) -> Result<Vec<(ProjectId, &[i128])>> {
+    votes_table
+        .iter()
+        .map(|(project_id, amounts)| {
+            let project_id = project_id
+                .parse()
+                .with_context(|| format!("Invalid project id {} in votes table", project_id))?;
+            Ok((project_id, amounts.as_slice()))
+        })
+        .collect()
+}
@@ -0,0 +1,134 @@
+use anyhow::{bail, Context, Result};
+use clap::{App, Arg};
+use op_rpgf::audit::{
+    audit_project_journal, audit_projects_journal, salts_from_secret, HashAudit, Salts,
+};
+use op_rpgf::inputs::{load_salt_secret, load_salts};
+use op_rpgf::journal::{print_header, verify_aggregate_receipt, verify_project_receipt};
+use op_rpgf::load_votes_table;
+use risc0_zkvm::Receipt;
+use rpgf_ballots_core::{JournalHeader, ProjectId};
+use std::fs;
+
+/// Recomputes every project hash committed by a receipt, once verified against the published
+/// image id of its guest, from the votes table, and reports the projects whose hash doesn't
+/// match. Salted hashes are only recomputed given their salt.
+fn main() -> Result<()> {
+    let matches = App::new("Audit")
+        .version("1.0")
+        .about("Recomputes the project hashes committed by a receipt's journal from the votes table")
+        .arg(
+            Arg::with_name("receipt")
+                .long("receipt")
+                .takes_value(true)
+                .help("Specifies the file path of the receipt (defaults to ./browser-verify/receipt_aggr with --aggregate, ./browser-verify/receipts otherwise)"),
+        ).arg(
+            Arg::with_name("aggregate")
+                .long("aggregate")
+                .takes_value(false)
+                .help("If set, the receipt is an aggregate (projects.rs) receipt instead of single project (project.rs) receipts"),
+        ).arg(
+            Arg::with_name("votes_table")
+                .long("votes_table")
+                .takes_value(true)
+                .help("Specifies the file path of the votes table of (project) -> (votes amounts) (defaults to votes_table.json)"),
+        ).arg(
+            Arg::with_name("salts")
+                .long("salts")
+                .takes_value(true)
+                .help("Specifies the file path of the json object of project id -> salt revealed by the operator, to check salted hashes with"),
+        ).arg(
+            Arg::with_name("salt_secret")
+                .long("salt_secret")
+                .takes_value(true)
+                .help("Specifies the file path of the operator's salt secret, to check every salted hash with"),
+        )
+        .get_matches();
+
+    let votes_table = load_votes_table(
+        matches
+            .value_of("votes_table")
+            .unwrap_or("votes_table.json"),
+    )?;
+
+    // the salts of the hashes of a journal, derived from the secret if it is given
+    let salt_secret = matches
+        .value_of("salt_secret")
+        .map(load_salt_secret)
+        .transpose()?;
+    let revealed_salts = matches
+        .value_of("salts")
+        .map(load_salts)
+        .transpose()?
+        .unwrap_or_default();
+    let table_project_ids = votes_table
+        .keys()
+        .map(|project_id| project_id.parse())
+        .collect::<Result<Vec<ProjectId>, _>>()?;
+    let salts = |header: &JournalHeader| -> Result<Salts> {
+        match &salt_secret {
+            Some(secret) => salts_from_secret(secret, header, table_project_ids.iter().copied()),
+            None => Ok(revealed_salts.clone()),
+        }
+    };
+
+    let aggregate = matches.is_present("aggregate");
+    let receipt_path = matches.value_of("receipt").unwrap_or(if aggregate {
+        "./browser-verify/receipt_aggr"
+    } else {
+        "./browser-verify/receipts"
+    });
+    let receipt_bytes = fs::read(receipt_path)
+        .with_context(|| format!("Could not find receipt file {}", receipt_path))?;
+
+    // salted hashes without a revealed salt
+    let mut skipped = 0;
+    let audits: Vec<HashAudit> = if aggregate {
+        let receipt: Receipt = bincode::deserialize(&receipt_bytes)?;
+        let journal = verify_aggregate_receipt(&receipt)?;
+        print_header(&journal.header);
+
+        let audits = audit_projects_journal(&votes_table, &journal, &salts(&journal.header)?)?;
+        skipped = journal.projects.len() - audits.len();
+        audits
+    } else {
+        let receipts: Vec<Receipt> = bincode::deserialize(&receipt_bytes)?;
+        let mut audits = vec![];
+        for receipt in &receipts {
+            let journal = verify_project_receipt(receipt)?;
+            print_header(&journal.header);
+            match audit_project_journal(&votes_table, &journal, &salts(&journal.header)?)? {
+                Some(audit) => audits.push(audit),
+                None => skipped += 1,
+            }
+        }
+        audits
+    };
+
+    let mut mismatches = 0;
+    for audit in &audits {
+        if audit.matches() {
+            println!("{}: {}", audit.project_id, audit.committed.to_hex());
+        } else {
+            mismatches += 1;
+            println!(
+                "{}: committed {}, recomputed {}",
+                audit.project_id,
+                audit.committed.to_hex(),
+                audit.recomputed.to_hex()
+            );
+        }
+    }
+    if mismatches > 0 {
+        bail!(
+            "{} of {} project hashes don't match the votes table",
+            mismatches,
+            audits.len()
+        );
+    }
+    if skipped > 0 {
+        println!("Skipped {} salted hashes without a revealed salt", skipped);
+    }
+    println!("All {} project hashes match the votes table", audits.len());
+    Ok(())
+}
@@ -2,10 +2,13 @@ use clap::{App, Arg};
 use csv::Writer;
 use ethers::prelude::*;
 use ethers::utils::keccak256;
+use k256::ecdsa::SigningKey;
+use op_rpgf::inputs::parse_eip712_domain;
 use rand::{
     distributions::{Distribution, Uniform},
     thread_rng,
 };
+use rpgf_ballots_core::{ballot_digest, Ballot};
 use serde::{Deserialize, Serialize};
 use std::{error::Error, fs::File, vec};
 
@@ -16,6 +19,13 @@ struct BallotData {
     amount: String,
 }
 
+/// A ballot with a nonce, superseding the badgeholder's ballots with lower nonces.
+#[derive(Serialize, Deserialize)]
+struct NoncedBallot {
+    nonce: u64,
+    votes: Vec<BallotData>,
+}
+
 /// Generates fake ballot data of a given shape (number of ballots and number of projects voted on per ballot)
 /// and writes it to a CSV file. The format of the ballot data is the same as the one used in the real RPGF ballot data.
 #[tokio::main]
@@ -44,10 +54,56 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .takes_value(true)
                 .required(false),
         )
+        .arg(
+            Arg::with_name("resubmission_count")
+                .long("resubmission_count")
+                .help("Specifies the number of badgeholders that resubmit their ballot, in which case ballots are written with a nonce and the first submission is superseded (defaults to 0)")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("eip712")
+                .long("eip712")
+                .help("If set, ballots are signed as EIP-712 typed data instead of EIP-191 messages")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::with_name("round_id")
+                .long("round_id")
+                .help("Specifies the round id of the EIP-712 domain (defaults to 0)")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("chain_id")
+                .long("chain_id")
+                .help("Specifies the chain id of the EIP-712 domain (defaults to 10)")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name("verifying_contract")
+                .long("verifying_contract")
+                .help("Specifies the verifying contract of the EIP-712 domain (defaults to the zero address)")
+                .takes_value(true),
+        )
         .get_matches();
 
     let badgeholder_count: usize = matches.value_of("badgeholder_count").unwrap().parse()?;
     let ballot_count: usize = matches.value_of("ballot_count").unwrap().parse()?;
+    let resubmission_count: usize = matches
+        .value_of("resubmission_count")
+        .unwrap_or("0")
+        .parse()?;
+
+    // domain separator of the EIP-712 typed data signatures, if enabled
+    let domain_separator = if matches.is_present("eip712") {
+        let round_id: u32 = matches.value_of("round_id").unwrap_or("0").parse()?;
+        let domain = parse_eip712_domain(
+            matches.value_of("chain_id"),
+            matches.value_of("verifying_contract"),
+        )?;
+        Some(domain.separator(round_id))
+    } else {
+        None
+    };
 
     let mut project_ids = vec![];
 
@@ -64,39 +120,63 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     for i in 0..badgeholder_count {
         // Generate a new wallet from random private key
-        let wallet = LocalWallet::new(&mut rand::thread_rng());
-
-        // Generate ballot data
-        let ballots = generate_ballots(ballot_count, &project_ids)?;
-
-        // create json ballot data
-        let ballot_data_str = serde_json::to_string(&ballots)?;
-
-        // double hash for two specific records
-        let message = if i == 0 || i == 1 {
-            let k_hash_hash = keccak256(ballot_data_str.as_bytes());
-            let k_hash_as_h256 = H256::from(k_hash_hash);
-            format!("{:?}", k_hash_as_h256)
-        } else {
-            ballot_data_str.clone()
-        };
-
-        // Sign the hash
-        let signature = wallet.sign_message(message.clone()).await?;
-
-        // generate a random boolean value and add that as a column to the csv
-        let random_bool = rand::random::<bool>();
-
-        // convert the random bool to uppercase string
-        let random_bool_str = random_bool.to_string().to_uppercase();
-
-        // Write the data to the CSV file
-        wtr.write_record(&[
-            &format!("{:?}", wallet.address()),
-            &format!("0x{}", signature.to_string()),
-            &ballot_data_str,
-            &random_bool_str,
-        ])?;
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        let wallet: LocalWallet = hex::encode(signing_key.to_bytes()).parse()?;
+
+        // Resubmitting badgeholders cast a first ballot that their second one supersedes
+        let submission_count = if i < resubmission_count { 2 } else { 1 };
+        for nonce in 0..submission_count {
+            // Generate ballot data
+            let ballots = generate_ballots(ballot_count, &project_ids)?;
+
+            // create json ballot data
+            let ballot_data_str = if resubmission_count > 0 {
+                serde_json::to_string(&NoncedBallot {
+                    nonce,
+                    votes: ballots,
+                })?
+            } else {
+                serde_json::to_string(&ballots)?
+            };
+
+            let signature = if let Some(domain_separator) = &domain_separator {
+                // Sign the EIP-712 digest of the typed ballot
+                let ballot = Ballot::parse(ballot_data_str.as_bytes().to_vec())?;
+                let digest = ballot_digest(domain_separator, &ballot);
+                let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&digest)?;
+                format!(
+                    "{}{:02x}",
+                    hex::encode(signature.to_bytes()),
+                    27 + recovery_id.to_byte()
+                )
+            } else {
+                // double hash for two specific records
+                let message = if i == 0 || i == 1 {
+                    let k_hash_hash = keccak256(ballot_data_str.as_bytes());
+                    let k_hash_as_h256 = H256::from(k_hash_hash);
+                    format!("{:?}", k_hash_as_h256)
+                } else {
+                    ballot_data_str.clone()
+                };
+
+                // Sign the hash
+                wallet.sign_message(message.clone()).await?.to_string()
+            };
+
+            // generate a random boolean value and add that as a column to the csv
+            let random_bool = rand::random::<bool>();
+
+            // convert the random bool to uppercase string
+            let random_bool_str = random_bool.to_string().to_uppercase();
+
+            // Write the data to the CSV file
+            wtr.write_record(&[
+                &format!("{:?}", wallet.address()),
+                &format!("0x{}", signature),
+                &ballot_data_str,
+                &random_bool_str,
+            ])?;
+        }
     }
 
     wtr.flush()?;
@@ -0,0 +1,129 @@
+use anyhow::{Context, Result};
+use clap::{App, Arg};
+use op_rpgf::inclusion::inclusion_proofs;
+use op_rpgf::inputs::load_salt_secret;
+use op_rpgf::journal::{verify_aggregate_receipt, verify_project_receipt};
+use op_rpgf::load_processed_inputs;
+use risc0_zkvm::Receipt;
+use rpgf_ballots_core::{Felt, JournalHeader};
+use std::fs;
+use std::path::Path;
+
+/// Issues every badgeholder the inclusion proofs of their votes in the votes roots committed by a
+/// receipt, writing them to `<out_dir>/<address>.json`.
+fn main() -> Result<()> {
+    let matches = App::new("Inclusion Proofs")
+        .version("1.0")
+        .about("Issues each badgeholder the inclusion proofs of their votes in a receipt's journal")
+        .arg(
+            Arg::with_name("receipt")
+                .long("receipt")
+                .takes_value(true)
+                .help("Specifies the file path of the receipt (defaults to ./browser-verify/receipt_aggr with --aggregate, ./browser-verify/receipts otherwise)"),
+        ).arg(
+            Arg::with_name("aggregate")
+                .long("aggregate")
+                .takes_value(false)
+                .help("If set, the receipt is an aggregate (projects.rs) receipt instead of single project (project.rs) receipts"),
+        ).arg(
+            Arg::with_name("processed_inputs")
+                .long("processed_inputs")
+                .takes_value(true)
+                .help("Specifies the file path of the processed inputs the receipt was proven over (defaults to ./processed_inputs)"),
+        ).arg(
+            Arg::with_name("verifying_keys")
+                .long("verifying_keys")
+                .takes_value(true)
+                .help("Specifies the file path of the verifying keys the receipt was proven over (defaults to ./verifying_keys)"),
+        ).arg(
+            Arg::with_name("out_dir")
+                .long("out_dir")
+                .takes_value(true)
+                .help("Specifies the directory to write the proofs of each badgeholder to (defaults to ./inclusion_proofs)"),
+        ).arg(
+            Arg::with_name("salt_secret")
+                .long("salt_secret")
+                .takes_value(true)
+                .help("Specifies the file path of the salt secret the receipt was proven with, if its hashes are salted"),
+        )
+        .get_matches();
+
+    let aggregate = matches.is_present("aggregate");
+    let receipt_path = matches.value_of("receipt").unwrap_or(if aggregate {
+        "./browser-verify/receipt_aggr"
+    } else {
+        "./browser-verify/receipts"
+    });
+    let receipt_bytes = fs::read(receipt_path)
+        .with_context(|| format!("Could not find receipt file {}", receipt_path))?;
+
+    // the header of the journal and every votes root it commits
+    let (header, roots): (JournalHeader, Vec<Felt>) = if aggregate {
+        let receipt: Receipt = bincode::deserialize(&receipt_bytes)?;
+        let journal = verify_aggregate_receipt(&receipt)?;
+        let roots = journal
+            .projects
+            .iter()
+            .map(|project| project.votes_root)
+            .collect();
+        (journal.header, roots)
+    } else {
+        let receipts: Vec<Receipt> = bincode::deserialize(&receipt_bytes)?;
+        let journals = receipts
+            .iter()
+            .map(verify_project_receipt)
+            .collect::<Result<Vec<_>>>()?;
+        let header = journals
+            .first()
+            .context("The receipts file has no receipts")?
+            .header
+            .clone();
+        let roots = journals.iter().map(|journal| journal.votes_root).collect();
+        (header, roots)
+    };
+
+    let inputs = load_processed_inputs(
+        matches
+            .value_of("processed_inputs")
+            .unwrap_or("./processed_inputs"),
+    )?;
+    let verifying_keys_path = matches
+        .value_of("verifying_keys")
+        .unwrap_or("./verifying_keys");
+    let verifying_keys: Vec<Vec<u8>> =
+        bincode::deserialize(&fs::read(verifying_keys_path).with_context(|| {
+            format!("Could not find verifying keys file {}", verifying_keys_path)
+        })?)?;
+
+    let salt_secret = matches
+        .value_of("salt_secret")
+        .map(load_salt_secret)
+        .transpose()?;
+
+    let out_dir = Path::new(matches.value_of("out_dir").unwrap_or("./inclusion_proofs"));
+    fs::create_dir_all(out_dir)?;
+    let mut proof_count = 0;
+    for (badgeholder, proofs) in
+        inclusion_proofs(&inputs, &verifying_keys, &header, salt_secret.as_ref())?
+    {
+        // only the projects the receipt covers have a committed root
+        let proofs: Vec<_> = proofs
+            .into_iter()
+            .filter(|proof| matches!(proof.root(), Some(root) if roots.contains(&root)))
+            .collect();
+        if proofs.is_empty() {
+            continue;
+        }
+        proof_count += proofs.len();
+        fs::write(
+            out_dir.join(format!("0x{}.json", hex::encode(badgeholder))),
+            serde_json::to_vec_pretty(&proofs)?,
+        )?;
+    }
+    println!(
+        "Wrote {} inclusion proofs to {}",
+        proof_count,
+        out_dir.display()
+    );
+    Ok(())
+}
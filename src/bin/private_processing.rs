@@ -1,22 +1,12 @@
-// TODO: Move main function to here.
-use bytemuck::Pod;
-use bytemuck::Zeroable;
+use anyhow::{bail, Result};
 use clap::{App, Arg};
-use ethers::types::Signature as EthSig;
-use ethers::types::H160;
-use ethers::utils::hash_message;
-use ethers::utils::keccak256;
-use ethers::core::types::H256;
-use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
-use std::fs::File;
-use std::{fs, str::FromStr};
-use csv::Writer;
-#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, PartialEq)]
-#[repr(C)]
-struct Felt([u64; 4]);
-fn main() {
+use op_rpgf::inputs::{load_allowlist, parse_amount_scale, parse_eip712_domain, write_bincode};
+use op_rpgf::processing::{process_ballots_csv, write_votes_table_csv};
+use op_rpgf::Registry;
+use rpgf_ballots_core::{GuestConfig, RejectionPolicy, DEFAULT_AMOUNT_SCALE};
+use std::fs;
+
+fn main() -> Result<()> {
     let matches = App::new("zkrpgf")
         .version("1.0")
         .about("")
@@ -31,6 +21,51 @@ fn main() {
                 .long("votes_table")
                 .takes_value(true)
                 .help("Specifies the file path to write the votes table of (project) -> (votes amounts) to"),
+        ).arg(
+            Arg::with_name("amount_scale")
+                .long("amount_scale")
+                .takes_value(true)
+                .help("Specifies the number of decimals vote amounts are scaled by (defaults to 18)"),
+        ).arg(
+            Arg::with_name("skip_invalid_ballots")
+                .long("skip_invalid_ballots")
+                .takes_value(false)
+                .help("If set, invalid ballots are kept for the guest to reject instead of failing the processing"),
+        ).arg(
+            Arg::with_name("round_id")
+                .long("round_id")
+                .takes_value(true)
+                .help("Specifies the identifier of the RetroPGF round committed to the journal (defaults to 0)"),
+        ).arg(
+            Arg::with_name("eligibility")
+                .long("eligibility")
+                .takes_value(true)
+                .help("Specifies the file path of the json array of badgeholder addresses allowed to vote, ballots of other signers are rejected as in the guests"),
+        ).arg(
+            Arg::with_name("eip712")
+                .long("eip712")
+                .takes_value(false)
+                .help("If set, ballots signed as EIP-712 typed data in the domain of the round are accepted"),
+        ).arg(
+            Arg::with_name("chain_id")
+                .long("chain_id")
+                .takes_value(true)
+                .help("Specifies the chain id of the EIP-712 domain (defaults to 10)"),
+        ).arg(
+            Arg::with_name("verifying_contract")
+                .long("verifying_contract")
+                .takes_value(true)
+                .help("Specifies the verifying contract address of the EIP-712 domain (defaults to the zero address)"),
+        ).arg(
+            Arg::with_name("safe_owners")
+                .long("safe_owners")
+                .takes_value(true)
+                .help("Specifies the file path of the json owner sets of the Safe multisig badgeholders"),
+        ).arg(
+            Arg::with_name("project_registry")
+                .long("project_registry")
+                .takes_value(true)
+                .help("Specifies the file path of the json array of the round's project ids, written to ./project_ids as is instead of the projects voted for, so that it stays fixed across incremental steps"),
         )
         .get_matches();
 
@@ -39,155 +74,75 @@ fn main() {
         .unwrap_or("votes_table.csv");
 
     let record_count = matches.value_of("badgeholder_count").unwrap_or("0");
-    let record_count = record_count.parse::<usize>().unwrap();
-
-    // Path to your CSV file
-    let file_path = "./rpgf_ballots.csv";
-
-    // Open the CSV file
-    let file = File::open(file_path).expect("Could not find rpgf_ballots.csv, please run the ballot_generator binary to generate the file");
-
-    // Create a CSV reader
-    let mut rdr = csv::ReaderBuilder::new()
-        .has_headers(false)
-        .from_reader(file);
-
-    // Create an iterator over records
-    let records_iter = rdr.records();
-
-    // Apply `.take()` conditionally based on `record_count`
-    let records_boxed_iter: Box<dyn Iterator<Item = csv::Result<csv::StringRecord>>> =
-        if record_count > 0 {
-            Box::new(records_iter.take(record_count))
+    let record_count = record_count.parse::<usize>()?;
+
+    let amount_scale = match matches.value_of("amount_scale") {
+        Some(scale) => parse_amount_scale(scale)?,
+        None => DEFAULT_AMOUNT_SCALE,
+    };
+
+    let config = GuestConfig {
+        round_id: matches
+            .value_of("round_id")
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or(0),
+        amount_scale,
+        rejection_policy: if matches.is_present("skip_invalid_ballots") {
+            RejectionPolicy::Skip
         } else {
-            Box::new(records_iter)
-        };
-
-    let mut verifying_keys: Vec<Vec<u8>> = Vec::new();
-
-    // Iterate over each record
-    let tuples: Vec<(Vec<u8>, Vec<u8>)> = records_boxed_iter
-        .map(|result| {
-            // Deserialize each row into the Record struct
-            let record = result.unwrap();
-            // get the ethereum address, signature and json from the record
-            let address = &record[0];
-            let address: H160 = H160::from_str(&address[2..]).unwrap();
-
-            let signature = &record[1];
-
-            let ballot_data = record[2].to_owned();
-
-            // get eth sig to be used to ensure the derived address from the verifying key matches the address in the csv
-            let eth_signature = EthSig::from_str(signature).unwrap();
-
-            // strip the 0x prefix from the signature and the last byte
-            let signature = &signature[2..signature.len()];
-
-            let sig_bytes = hex::decode(signature).unwrap();
-
-            let sig_minus_rec = &sig_bytes[0..64];
-
-            let sig = Signature::try_from(sig_minus_rec).unwrap();
-
-            let recovery_id_byte: u8 = sig_bytes[64];
-
-            let recovery_id_byte = if recovery_id_byte == 27 { 0 } else { 1 };
-
-            let recid = RecoveryId::try_from(recovery_id_byte).unwrap();
-
-            let message_hash = hash_message(&ballot_data);
-
-            let mut verifying_key: VerifyingKey =
-                VerifyingKey::recover_from_prehash(message_hash.as_bytes(), &sig, recid).unwrap();
-
-            let address_derived = eth_signature.recover(message_hash).unwrap();
-            println!("{:?}, {:?}",&address_derived, &address);
-
-            if address_derived != address {
-                println!("ballot message hash: {:?}", message_hash);
-                let khash = keccak256(&ballot_data.as_bytes());
-                let khash_as_h256 = H256::from(khash); 
-                let message_hash = hash_message(format!("{:?}",khash_as_h256));
-
-                verifying_key =
-                    VerifyingKey::recover_from_prehash(message_hash.as_bytes(), &sig, recid)
-                        .unwrap();
+            RejectionPolicy::Abort
+        },
+        eligibility: matches
+            .value_of("eligibility")
+            .map(load_allowlist)
+            .transpose()?,
+        eip712: if matches.is_present("eip712") {
+            Some(parse_eip712_domain(
+                matches.value_of("chain_id"),
+                matches.value_of("verifying_contract"),
+            )?)
+        } else {
+            None
+        },
+        ..GuestConfig::default()
+    };
+
+    let mut registry = Registry::default();
+    if let Some(path) = matches.value_of("project_registry") {
+        registry = registry.with_project_ids(path)?;
+    }
+    if let Some(path) = matches.value_of("safe_owners") {
+        registry = registry.with_safe_owners(path)?;
+    }
+    let safe_owners = registry.safe_owner_sets()?;
 
-                let address_derived = eth_signature.recover(message_hash).unwrap();
+    // Path to your CSV file
+    let processed =
+        process_ballots_csv("./rpgf_ballots.csv", record_count, &config, &safe_owners)?;
 
-                assert!(
-                    address_derived == address,
-                    "Address {:?} derived from signature does not match the address {:?} in the record",&address_derived,&address
-                );
-            } else {
-                assert!(
-                    address_derived == address,
-                    "Address derived from signature does not match the address in the record"
-                );
-            }
-            verifying_keys.push(verifying_key.to_sec1_bytes().to_vec());
-            (ballot_data.as_bytes().to_owned(), sig_minus_rec.to_vec())
-        })
-        .collect();
+    // Write the votes_table hashmap to a file
+    fs::write("votes_table.json", serde_json::to_vec(&processed.votes_table)?)?;
 
-        // Initialize the hashmap to store project IDs and their associated vote amounts
-        let mut votes_table: BTreeMap<String, Vec<i128>> = BTreeMap::new();
+    write_votes_table_csv(votes_table_path, &processed.votes_table)?;
 
-        for (ballots, _) in tuples.iter() {
-            let data: serde_json::Value = serde_json::from_slice(&ballots).unwrap();
-            // Process the ballots to populate the votes_table hashmap
-            if let Some(array) = data.as_array() {
-                array.iter().for_each(|vote| {
-                    let project_id = vote["projectId"].as_str().unwrap();
-                    let amount_string = vote["amount"].as_str().unwrap();
-                    let vote_amount = amount_string.parse::<f64>().unwrap() as i128;
-                    let amounts_array = votes_table
-                        .entry(project_id.to_string())
-                        .or_insert_with(Vec::new);
-                    amounts_array.push(vote_amount);
-                });
+    // Write the project ids to a file serde json serialized, unless the registry is pinned
+    if matches.is_present("project_registry") {
+        let registered = registry.project_id_list()?;
+        for project_id in processed.project_ids() {
+            if !registered.contains(&project_id.parse()?) {
+                bail!("Project {} isn't in the project registry", project_id);
             }
         }
+        fs::write("project_ids", &registry.project_ids)?;
+    } else {
+        fs::write("project_ids", serde_json::to_vec(&processed.project_ids())?)?;
+    }
 
-        // Write the votes_table hashmap to a file
-        fs::write("votes_table.json", serde_json::to_vec(&votes_table).unwrap()).unwrap();
-
-        // Serialize the votes_table hashmap into CSV format and write to a file
-        let mut wtr = Writer::from_path(votes_table_path).expect("Unable to create CSV writer");
-
-        // Write CSV header
-        wtr.write_record(&["Project ID", "Vote Amounts"]).expect("Unable to write header");
-
-        // Iterate over the votes_table and write each entry as a CSV row
-        for (project_id, vote_amounts) in votes_table.iter() {
-            // Join all vote amounts with ";" to keep them in one column, or handle as needed
-            let amounts_str = vote_amounts.iter()
-                                            .map(|amount| amount.to_string())
-                                            .collect::<Vec<String>>().join(";");
-            wtr.write_record(&[project_id, &amounts_str]).expect("Unable to write record");
-        }
-
-        // Ensure all data is flushed to the file
-        wtr.flush().expect("Failed to flush CSV writer");
-    
-        // collect all the keys of the votes_table hashmap
-        let votes_table_keys: Vec<String> = votes_table.keys().cloned().collect();
-    
-        // Write the votes_table_keys to a file serde json serialized
-        fs::write(
-            "project_ids",
-            serde_json::to_vec(&votes_table_keys).unwrap(),
-        )
-        .unwrap();
-
-    
-    // serialize the tuples to a json string using bincode
-    let tuples_serialized = bincode::serialize(&tuples).unwrap();
     // write the tuples to a file
-    fs::write("processed_inputs", tuples_serialized).expect("Unable to write file");
+    write_bincode("processed_inputs", &processed.inputs)?;
 
-    let verifying_keys_serialized = bincode::serialize(&verifying_keys).unwrap();
     // write the verifying keys to a file
-    fs::write("verifying_keys", verifying_keys_serialized).expect("Unable to write file");
+    write_bincode("verifying_keys", &processed.verifying_keys)?;
+    Ok(())
 }
@@ -0,0 +1,55 @@
+use anyhow::{Context, Result};
+use clap::{App, Arg};
+use op_rpgf::inputs::load_salt_secret;
+use rpgf_ballots_core::{project_salt, ProjectId};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Writes the salts of the given projects, derived from the operator's salt secret, for an
+/// auditor to check their hashes with `audit --salts`.
+fn main() -> Result<()> {
+    let matches = App::new("Reveal Salts")
+        .version("1.0")
+        .about("Reveals the salts of the hashes of some projects to an auditor")
+        .arg(
+            Arg::with_name("salt_secret")
+                .long("salt_secret")
+                .takes_value(true)
+                .help("Specifies the file path of the salt secret the receipt was proven with (defaults to ./salt_secret)"),
+        ).arg(
+            Arg::with_name("project_ids")
+                .long("project_ids")
+                .takes_value(true)
+                .required(true)
+                .help("Specifies the comma separated ids of the projects to reveal the salts of"),
+        ).arg(
+            Arg::with_name("out")
+                .long("out")
+                .takes_value(true)
+                .help("Specifies the file path to write the json object of project id -> salt to (defaults to ./salts.json)"),
+        )
+        .get_matches();
+
+    let secret = load_salt_secret(matches.value_of("salt_secret").unwrap_or("./salt_secret"))?;
+    let salts = matches
+        .value_of("project_ids")
+        .unwrap()
+        .split(',')
+        .map(|project_id| {
+            let project_id: ProjectId = project_id
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid project id {}", project_id))?;
+            Ok((
+                project_id.to_string(),
+                project_salt(&secret, &project_id).to_hex(),
+            ))
+        })
+        .collect::<Result<BTreeMap<String, String>>>()?;
+
+    let out = matches.value_of("out").unwrap_or("./salts.json");
+    fs::write(out, serde_json::to_vec_pretty(&salts)?)
+        .with_context(|| format!("Unable to write {}", out))?;
+    println!("Wrote the salts of {} projects to {}", salts.len(), out);
+    Ok(())
+}
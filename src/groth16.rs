@@ -1,3 +1,5 @@
+use crate::prover::verify;
+use anyhow::{anyhow, Context};
 use risc0_zkvm::get_prover_server;
 use risc0_zkvm::recursion::identity_p254;
 use risc0_zkvm::seal_to_json;
@@ -11,29 +13,34 @@ use risc0_zkvm::ProverOpts;
 use risc0_zkvm::Receipt;
 use risc0_zkvm::VerifierContext;
 
-pub fn stark_to_groth16(env: ExecutorEnv, image_id: &[u32; 8], elf: &[u8]) -> Receipt {
-    let mut exec = ExecutorImpl::from_elf(env, elf).unwrap();
-    let session = exec.run().unwrap();
+pub fn stark_to_groth16(env: ExecutorEnv, image_id: &[u32; 8], elf: &[u8]) -> Result<Receipt> {
+    let mut exec = ExecutorImpl::from_elf(env, elf)?;
+    let session = exec.run()?;
     let opts = ProverOpts::default();
     let ctx = VerifierContext::default();
-    let prover = get_prover_server(&opts).unwrap();
+    let prover = get_prover_server(&opts)?;
     let time = std::time::Instant::now();
-    let receipt = prover.prove_session(&ctx, &session).unwrap();
+    let receipt = prover.prove_session(&ctx, &session)?;
     let proving_time = time.elapsed();
     println!("Proving time: {:?}", proving_time);
-    let claim = receipt.get_claim().unwrap();
-    let composite_receipt = receipt.inner.composite().unwrap();
-    let succinct_receipt = prover.compress(composite_receipt).unwrap();
-    let journal = session.journal.unwrap().bytes;
-    let ident_receipt = identity_p254(&succinct_receipt).unwrap();
+    let claim = receipt
+        .get_claim()
+        .map_err(|e| anyhow!("Could not get receipt claim: {:?}", e))?;
+    let composite_receipt = receipt
+        .inner
+        .composite()
+        .map_err(|e| anyhow!("Receipt is not a composite receipt: {:?}", e))?;
+    let succinct_receipt = prover.compress(composite_receipt)?;
+    let journal = session.journal.context("Session has no journal")?.bytes;
+    let ident_receipt = identity_p254(&succinct_receipt)?;
     let seal_bytes = ident_receipt.get_seal_bytes();
-    let seal = stark_to_snark(&seal_bytes).unwrap().to_vec();
+    let seal = stark_to_snark(&seal_bytes)?.to_vec();
     let receipt = Receipt::new(
         InnerReceipt::Compact(CompactReceipt { seal, claim }),
         journal,
     );
-    receipt.verify(*image_id).unwrap();
-    receipt
+    verify(&receipt, image_id)?;
+    Ok(receipt)
 }
 
 use std::{
@@ -75,7 +82,7 @@ fn stark_to_snark(identity_p254_seal_bytes: &[u8]) -> Result<Groth16Seal> {
         .arg("risc0-groth16-prover")
         .status()?;
     if !status.success() {
-        panic!("docker returned failure exit code: {:?}", status.code());
+        bail!("docker returned failure exit code: {:?}", status.code());
     }
     let mut proof_file = File::open(proof_path)?;
     let mut contents = String::new();
@@ -0,0 +1,139 @@
+use crate::audit::check_salt_secret;
+use crate::inputs::ProcessedInputs;
+use anyhow::{bail, Context, Result};
+use halo2curves::bn256::Fr as Fp;
+use k256::ecdsa::VerifyingKey;
+use rpgf_ballots_core::merkle::{
+    badgeholder_commitment, inclusion_path, vote_leaf, InclusionProof,
+};
+use rpgf_ballots_core::signature::signer_address;
+use rpgf_ballots_core::{
+    leaf_nonce, superseded, votes_root_salt, Address, Ballot, JournalHeader, ProjectId,
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Builds the inclusion proof of every counted vote, grouped by badgeholder.
+///
+/// The counted ballots are recovered the way the guests count them: the ballots the journal
+/// `header` lists as rejected are skipped, and so are the ones superseded by a later ballot of
+/// the same badgeholder. Fails if the inputs don't match the header's ballot counts.
+///
+/// The votes roots of a journal with a salt commitment are salted, so their proofs need the
+/// operator's salt secret.
+pub fn inclusion_proofs(
+    inputs: &ProcessedInputs,
+    verifying_keys: &[Vec<u8>],
+    header: &JournalHeader,
+    salt_secret: Option<&[u8; 32]>,
+) -> Result<BTreeMap<Address, Vec<InclusionProof>>> {
+    if inputs.len() != header.ballot_count as usize {
+        bail!(
+            "The journal covers {} ballots, the processed inputs have {}",
+            header.ballot_count,
+            inputs.len()
+        );
+    }
+    let rejected: BTreeSet<u32> = header
+        .rejections
+        .iter()
+        .map(|rejection| rejection.index)
+        .collect();
+
+    let mut accepted = vec![];
+    for (index, (ballot, _)) in inputs.iter().enumerate() {
+        if rejected.contains(&(index as u32)) {
+            continue;
+        }
+        let verifying_key = verifying_keys
+            .get(index)
+            .with_context(|| format!("Missing verifying key of ballot {}", index))?;
+        let signer = ballot_signer(verifying_key)
+            .with_context(|| format!("Invalid verifying key of ballot {}", index))?;
+        let ballot = Ballot::parse(ballot.clone())
+            .with_context(|| format!("Invalid ballot {} that the journal counts", index))?;
+        accepted.push((signer, ballot));
+    }
+
+    let signers: Vec<(Address, u64)> = accepted
+        .iter()
+        .map(|(signer, ballot)| (*signer, ballot.nonce()))
+        .collect();
+    let counted: Vec<(Address, Ballot)> = accepted
+        .into_iter()
+        .zip(superseded(&signers))
+        .filter_map(|(ballot, superseded)| (!superseded).then_some(ballot))
+        .collect();
+    if counted.len() != header.accepted_count as usize {
+        bail!(
+            "The journal counts {} ballots, the processed inputs have {}",
+            header.accepted_count,
+            counted.len()
+        );
+    }
+
+    // the votes for each project in ballot order, as the guests build the votes trees
+    let mut project_votes: BTreeMap<ProjectId, Vec<(Address, i128)>> = BTreeMap::new();
+    for (signer, ballot) in &counted {
+        for vote in ballot.votes() {
+            let amount = vote.amount.to_fixed(header.amount_scale)?;
+            project_votes
+                .entry(vote.project_id)
+                .or_default()
+                .push((*signer, amount));
+        }
+    }
+
+    let salt_secret = match (header.salt_commitment, salt_secret) {
+        (Some(_), None) => bail!("The journal's votes roots are salted, the salt secret is needed"),
+        (_, Some(secret)) => {
+            check_salt_secret(secret, header)?;
+            Some(secret)
+        }
+        (None, None) => None,
+    };
+
+    let mut proofs: BTreeMap<Address, Vec<InclusionProof>> = BTreeMap::new();
+    for (project_id, votes) in project_votes {
+        // never the project's salt, which blinds its amounts hash
+        let salt = salt_secret.map(|secret| votes_root_salt(secret, &project_id));
+        // each badgeholder only gets their own nonce
+        let nonces: Vec<_> = votes
+            .iter()
+            .map(|(signer, _)| salt_secret.map(|secret| leaf_nonce(secret, signer)))
+            .collect();
+        let leaves: Vec<Fp> = votes
+            .iter()
+            .zip(&nonces)
+            .map(|((signer, amount), nonce)| {
+                vote_leaf(badgeholder_commitment(signer, *nonce), *amount)
+            })
+            .collect();
+        for (leaf_index, ((signer, amount), nonce)) in votes.into_iter().zip(nonces).enumerate() {
+            proofs.entry(signer).or_default().push(InclusionProof {
+                project_id,
+                badgeholder: signer,
+                amount,
+                leaf_index: leaf_index as u32,
+                leaf_count: leaves.len() as u32,
+                siblings: inclusion_path(&leaves, leaf_index)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                salt,
+                nonce,
+            });
+        }
+    }
+    Ok(proofs)
+}
+
+/// The address a verifying key of the processed inputs stands for: the Safe address itself for
+/// Safe ballots, or the Ethereum address of a sec1 encoded key.
+fn ballot_signer(verifying_key: &[u8]) -> Result<Address> {
+    if let Ok(safe) = Address::try_from(verifying_key) {
+        return Ok(safe);
+    }
+    Ok(signer_address(&VerifyingKey::from_sec1_bytes(
+        verifying_key,
+    )?))
+}
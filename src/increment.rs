@@ -0,0 +1,79 @@
+use crate::inputs::{ProcessedInputs, Registry};
+use crate::journal::decode_incremental_journal;
+use crate::prover::{prove, ReceiptKind};
+use anyhow::{bail, Context, Result};
+use risc0_zkvm::sha::{Impl, Sha256};
+use risc0_zkvm::{ExecutorEnv, Receipt};
+use rpgf_ballots_core::{GuestConfig, IncrementalJournal, ProjectFilter};
+use rpgf_ballots_methods::{INCREMENT_ELF, INCREMENT_ID};
+
+/// Builds the executor env for the incremental guest (increment.rs), adding the `previous`
+/// step's receipt as an assumption if there is one.
+pub fn incremental_env(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    previous: Option<Receipt>,
+) -> Result<ExecutorEnv<'static>> {
+    let previous_journal: Option<IncrementalJournal> = previous
+        .as_ref()
+        .map(decode_incremental_journal)
+        .transpose()?;
+
+    let mut builder = ExecutorEnv::builder();
+    builder
+        .write(&bincode::serialize(inputs)?)
+        .context("Failed to serialize inputs")?
+        .write(&registry.verifying_keys)
+        .context("Failed to serialize verifying_keys")?
+        .write(&registry.project_ids)
+        .context("Failed to serialize project registry")?
+        .write(&registry.safe_owners)
+        .context("Failed to serialize safe owners")?
+        .write(filter)
+        .context("Failed to serialize project filter")?
+        .write(config)
+        .context("Failed to serialize config")?
+        .write(&INCREMENT_ID)
+        .context("Failed to serialize image id")?
+        .write(&previous_journal)
+        .context("Failed to serialize the previous journal")?;
+    if let Some(receipt) = previous {
+        builder.add_assumption(receipt);
+    }
+    Ok(builder.build()?)
+}
+
+/// Proves the aggregate journal of every ballot of `inputs`, checking the signatures of the
+/// ballots appended since the `previous` step's receipt only.
+///
+/// Without a previous receipt, this is the first step of the chain and every ballot is checked.
+/// Fails if the project registry changed since the previous step, which the guest rejects: pin
+/// it with `private_processing --project_registry`.
+pub fn prove_increment(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    previous: Option<Receipt>,
+    kind: ReceiptKind,
+) -> Result<Receipt> {
+    if let Some(receipt) = &previous {
+        let journal = decode_incremental_journal(receipt)?;
+        if Impl::hash_bytes(&registry.project_ids).as_bytes()
+            != journal.journal.header.project_registry_hash
+        {
+            bail!("The project registry changed since the previous step, pin it with private_processing --project_registry");
+        }
+        println!(
+            "Extending the receipt of {} ballots with {} new ones",
+            journal.journal.header.ballot_count,
+            inputs
+                .len()
+                .saturating_sub(journal.journal.header.ballot_count as usize)
+        );
+    }
+    let env = incremental_env(inputs, registry, filter, config, previous)?;
+    prove(env, INCREMENT_ELF, &INCREMENT_ID, kind)
+}
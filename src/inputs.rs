@@ -0,0 +1,329 @@
+use anyhow::{bail, Context, Result};
+use rpgf_ballots_core::{
+    parse_safe_owners, Address, Allowlist, Amount, BuiltinRule, Eip712Domain, Felt, PoseidonConfig,
+    PoseidonSpecId, ProjectId, SafeOwners, TallyConfig, TreeHashMode, DEFAULT_POSEIDON_CHUNK_LEN,
+    MAX_AMOUNT_SCALE,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+
+/// `(ballot json bytes, 64 byte signature)` pairs written by the private processing step.
+pub type ProcessedInputs = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Mapping of (project id) -> (fixed point vote amounts) written by the private processing step.
+pub type VotesTable = BTreeMap<String, Vec<i128>>;
+
+/// Mapping of (project category name) -> (project ids), which project filters can refer to.
+pub type ProjectCategories = BTreeMap<String, Vec<ProjectId>>;
+
+/// Reads the bincode serialized processed inputs file.
+pub fn load_processed_inputs(path: impl AsRef<Path>) -> Result<ProcessedInputs> {
+    let path = path.as_ref();
+    let file = File::open(path)
+        .with_context(|| format!("Could not find processed inputs file {}", path.display()))?;
+    bincode::deserialize_from(file)
+        .with_context(|| format!("Could not deserialize processed inputs {}", path.display()))
+}
+
+/// Round data the guests validate ballots against, kept as the raw bytes of the files written
+/// by the private processing step so that the guests can commit their sha256 hashes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Registry {
+    /// Bincode serialized sec1 verifying keys, in processed inputs order.
+    pub verifying_keys: Vec<u8>,
+    /// Json serialized ids of the projects ballots may vote for.
+    pub project_ids: Vec<u8>,
+    /// Json owner sets of the Safe multisig badgeholders, if any vote from a Safe.
+    pub safe_owners: Option<Vec<u8>>,
+}
+
+impl Registry {
+    /// Reads the verifying keys and project registry files, checking that they deserialize.
+    pub fn load(
+        verifying_keys_path: impl AsRef<Path>,
+        project_ids_path: impl AsRef<Path>,
+    ) -> Result<Self> {
+        let verifying_keys_path = verifying_keys_path.as_ref();
+        let verifying_keys = fs::read(verifying_keys_path).with_context(|| {
+            format!(
+                "Could not find verifying keys file {}",
+                verifying_keys_path.display()
+            )
+        })?;
+        bincode::deserialize::<Vec<Vec<u8>>>(&verifying_keys).with_context(|| {
+            format!(
+                "Could not deserialize verifying keys {}",
+                verifying_keys_path.display()
+            )
+        })?;
+
+        let project_ids_path = project_ids_path.as_ref();
+        let project_ids = fs::read(project_ids_path).with_context(|| {
+            format!(
+                "Could not find project registry file {}",
+                project_ids_path.display()
+            )
+        })?;
+        serde_json::from_slice::<Vec<String>>(&project_ids).with_context(|| {
+            format!(
+                "Could not deserialize project registry {}",
+                project_ids_path.display()
+            )
+        })?;
+
+        Ok(Registry {
+            verifying_keys,
+            project_ids,
+            safe_owners: None,
+        })
+    }
+
+    /// Replaces the project registry with the json array of project ids at `path`, checking
+    /// that they parse.
+    pub fn with_project_ids(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .with_context(|| format!("Could not find project registry file {}", path.display()))?;
+        self.project_ids = bytes;
+        self.project_id_list()
+            .with_context(|| format!("Invalid project registry {}", path.display()))?;
+        Ok(self)
+    }
+
+    /// The parsed project ids of the registry.
+    pub fn project_id_list(&self) -> Result<Vec<ProjectId>> {
+        let project_ids: Vec<String> = serde_json::from_slice(&self.project_ids)?;
+        project_ids
+            .iter()
+            .map(|project_id| {
+                project_id
+                    .parse()
+                    .with_context(|| format!("Invalid project id {}", project_id))
+            })
+            .collect()
+    }
+
+    /// Adds the Safe owner sets file, a json array of
+    /// `{"safe": "0x..", "threshold": 2, "owners": ["0x..", ..]}` objects, checking that it
+    /// parses.
+    pub fn with_safe_owners(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path)
+            .with_context(|| format!("Could not find safe owners file {}", path.display()))?;
+        self.safe_owners = Some(bytes);
+        self.safe_owner_sets()
+            .with_context(|| format!("Invalid safe owners file {}", path.display()))?;
+        Ok(self)
+    }
+
+    /// The parsed Safe owner sets, none if the registry has no Safe owner sets file.
+    pub fn safe_owner_sets(&self) -> Result<Vec<SafeOwners>> {
+        match &self.safe_owners {
+            Some(bytes) => Ok(parse_safe_owners(bytes)?),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// Reads the json serialized votes table file.
+pub fn load_votes_table(path: impl AsRef<Path>) -> Result<VotesTable> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)
+        .with_context(|| format!("Could not find votes table file {}", path.display()))?;
+    serde_json::from_slice(&bytes)
+        .with_context(|| format!("Could not deserialize votes table {}", path.display()))
+}
+
+/// Reads a json object of project category name -> `0x` prefixed hex project ids.
+pub fn load_project_categories(path: impl AsRef<Path>) -> Result<ProjectCategories> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)
+        .with_context(|| format!("Could not find project categories file {}", path.display()))?;
+    let categories: BTreeMap<String, Vec<String>> =
+        serde_json::from_slice(&bytes).with_context(|| {
+            format!(
+                "Could not deserialize project categories file {}",
+                path.display()
+            )
+        })?;
+    categories
+        .into_iter()
+        .map(|(name, project_ids)| {
+            let project_ids = project_ids
+                .iter()
+                .map(|project_id| {
+                    project_id.parse().with_context(|| {
+                        format!("Invalid project id {} in category {}", project_id, name)
+                    })
+                })
+                .collect::<Result<_>>()?;
+            Ok((name, project_ids))
+        })
+        .collect()
+}
+
+/// Reads a json array of `0x` prefixed hex badgeholder addresses.
+pub fn load_allowlist(path: impl AsRef<Path>) -> Result<Allowlist> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)
+        .with_context(|| format!("Could not find eligibility file {}", path.display()))?;
+    let addresses: Vec<String> = serde_json::from_slice(&bytes)
+        .with_context(|| format!("Could not deserialize eligibility file {}", path.display()))?;
+    let addresses = addresses
+        .iter()
+        .map(|address| {
+            let bytes = hex::decode(address.trim_start_matches("0x"))
+                .with_context(|| format!("Invalid address {}", address))?;
+            Address::try_from(bytes.as_slice())
+                .with_context(|| format!("Expected a 20 byte address, got {}", address))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if addresses.is_empty() {
+        bail!("Eligibility file {} is empty", path.display());
+    }
+    Ok(Allowlist::new(addresses))
+}
+
+/// Reads the operator's salt secret, 32 hex encoded bytes.
+pub fn load_salt_secret(path: impl AsRef<Path>) -> Result<[u8; 32]> {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Could not find salt secret file {}", path.display()))?;
+    let mut secret = [0u8; 32];
+    hex::decode_to_slice(contents.trim().trim_start_matches("0x"), &mut secret).with_context(
+        || {
+            format!(
+                "Expected 32 hex bytes in salt secret file {}",
+                path.display()
+            )
+        },
+    )?;
+    Ok(secret)
+}
+
+/// Reads a json object of project id -> revealed salt, in the hex format of hashes.
+pub fn load_salts(path: impl AsRef<Path>) -> Result<BTreeMap<ProjectId, Felt>> {
+    let path = path.as_ref();
+    let bytes =
+        fs::read(path).with_context(|| format!("Could not find salts file {}", path.display()))?;
+    let salts: BTreeMap<String, String> = serde_json::from_slice(&bytes)
+        .with_context(|| format!("Could not deserialize salts file {}", path.display()))?;
+    salts
+        .iter()
+        .map(|(project_id, salt)| {
+            Ok((
+                project_id
+                    .parse()
+                    .with_context(|| format!("Invalid project id {}", project_id))?,
+                Felt::from_hex(salt)?,
+            ))
+        })
+        .collect()
+}
+
+/// Parses the EIP-712 domain of typed data ballot signatures, defaulting to the OP mainnet chain
+/// id and the zero address as verifying contract.
+pub fn parse_eip712_domain(
+    chain_id: Option<&str>,
+    verifying_contract: Option<&str>,
+) -> Result<Eip712Domain> {
+    let mut domain = Eip712Domain::default();
+    if let Some(chain_id) = chain_id {
+        domain.chain_id = chain_id
+            .parse()
+            .with_context(|| format!("Invalid chain id {}", chain_id))?;
+    }
+    if let Some(verifying_contract) = verifying_contract {
+        let bytes = hex::decode(verifying_contract.trim_start_matches("0x"))
+            .with_context(|| format!("Invalid verifying contract {}", verifying_contract))?;
+        domain.verifying_contract = Address::try_from(bytes.as_slice()).with_context(|| {
+            format!(
+                "Expected a 20 byte verifying contract address, got {}",
+                verifying_contract
+            )
+        })?;
+    }
+    Ok(domain)
+}
+
+/// Parses the allocation parameters of the aggregate guest: the decimal total `pool`, scaled to
+/// a fixed point integer with `amount_scale` decimals like the vote amounts, the minimum number
+/// of ballots per project, defaulting to 1, and the tally rule, defaulting to the median.
+pub fn parse_tally_config(
+    pool: &str,
+    quorum: Option<&str>,
+    rule: Option<&str>,
+    amount_scale: u32,
+) -> Result<TallyConfig> {
+    let pool = pool
+        .parse::<Amount>()
+        .and_then(|pool| pool.to_fixed(amount_scale))
+        .with_context(|| format!("Invalid pool {}", pool))?;
+    let quorum = match quorum {
+        Some(quorum) => quorum
+            .parse()
+            .with_context(|| format!("Invalid quorum {}", quorum))?,
+        None => 1,
+    };
+    let rule = rule
+        .map(str::parse::<BuiltinRule>)
+        .transpose()?
+        .unwrap_or_default();
+    Ok(TallyConfig {
+        quorum,
+        // amounts are non negative
+        pool: pool as u128,
+        rule,
+    })
+}
+
+/// Bincode serializes `value` and writes it to `path`.
+pub fn write_bincode<T: Serialize + ?Sized>(path: impl AsRef<Path>, value: &T) -> Result<()> {
+    let path = path.as_ref();
+    let bytes = bincode::serialize(value)?;
+    fs::write(path, bytes).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+/// Parses the number of decimals vote amounts are scaled by.
+pub fn parse_amount_scale(scale: &str) -> Result<u32> {
+    let scale = scale.parse::<u32>()?;
+    if scale > MAX_AMOUNT_SCALE {
+        bail!(
+            "Amount scale {} is larger than the maximum of {}",
+            scale,
+            MAX_AMOUNT_SCALE
+        );
+    }
+    Ok(scale)
+}
+
+/// Parses the Poseidon spec (`w2r1` by default) and chunk length (32 by default) the vote
+/// amounts are hashed with, with the legacy tree hash if `legacy_tree_hash` is set.
+pub fn parse_poseidon_config(
+    spec: Option<&str>,
+    chunk_len: Option<&str>,
+    legacy_tree_hash: bool,
+) -> Result<PoseidonConfig> {
+    let config = PoseidonConfig {
+        spec: spec
+            .map(str::parse::<PoseidonSpecId>)
+            .transpose()?
+            .unwrap_or_default(),
+        chunk_len: match chunk_len {
+            Some(chunk_len) => chunk_len
+                .parse()
+                .with_context(|| format!("Invalid Poseidon chunk length {}", chunk_len))?,
+            None => DEFAULT_POSEIDON_CHUNK_LEN,
+        },
+        tree_hash: if legacy_tree_hash {
+            TreeHashMode::Legacy
+        } else {
+            TreeHashMode::LengthBinding
+        },
+    };
+    config.check()?;
+    Ok(config)
+}
@@ -0,0 +1,152 @@
+use crate::prover::verify;
+use anyhow::{Context, Result};
+use risc0_zkvm::Receipt;
+use rpgf_ballots_core::{
+    FilterExpr, IncrementalJournal, JournalHeader, ProjectJournal, ProjectsJournal, Tally,
+};
+use rpgf_ballots_methods::{INCREMENT_ID, PROJECTS_ID, PROJECT_ID, SHARDS_ID, SHARD_ID};
+
+/// Decodes the journal of a single project guest (project.rs) receipt, checking its version.
+pub fn decode_project_journal(receipt: &Receipt) -> Result<ProjectJournal> {
+    let journal: ProjectJournal = receipt
+        .journal
+        .decode()
+        .context("Failed to decode the single project journal")?;
+    journal.header.check_version()?;
+    Ok(journal)
+}
+
+/// Verifies a single project guest (project.rs) receipt against the published image id of the
+/// guest and decodes its journal.
+pub fn verify_project_receipt(receipt: &Receipt) -> Result<ProjectJournal> {
+    verify(receipt, &PROJECT_ID)?;
+    decode_project_journal(receipt)
+}
+
+/// Decodes the journal of an aggregate guest (projects.rs) receipt, checking its version.
+pub fn decode_projects_journal(receipt: &Receipt) -> Result<ProjectsJournal> {
+    let journal: ProjectsJournal = receipt
+        .journal
+        .decode()
+        .context("Failed to decode the aggregate journal")?;
+    journal.header.check_version()?;
+    Ok(journal)
+}
+
+/// Verifies an aggregate receipt against `image_id`, the image id of the aggregate guest
+/// (projects.rs) or of the shards guest (shards.rs), and decodes its journal, checking that the
+/// ballots of a shards guest receipt were validated by the published shard guest (shard.rs).
+pub fn verify_projects_receipt(receipt: &Receipt, image_id: &[u32; 8]) -> Result<ProjectsJournal> {
+    verify(receipt, image_id)?;
+    let journal = decode_projects_journal(receipt)?;
+    journal.check_shard_image_id((*image_id == SHARDS_ID).then_some(&SHARD_ID))?;
+    Ok(journal)
+}
+
+/// Verifies an aggregate receipt against the published image id of the guest that proved it:
+/// the shards guest if its journal has a shard image id, the aggregate guest otherwise.
+pub fn verify_aggregate_receipt(receipt: &Receipt) -> Result<ProjectsJournal> {
+    let image_id = match decode_projects_journal(receipt)?.shard_image_id {
+        Some(_) => SHARDS_ID,
+        None => PROJECTS_ID,
+    };
+    verify_projects_receipt(receipt, &image_id)
+}
+
+/// Decodes the journal of an incremental guest (increment.rs) receipt, checking its version.
+pub fn decode_incremental_journal(receipt: &Receipt) -> Result<IncrementalJournal> {
+    let journal: IncrementalJournal = receipt
+        .journal
+        .decode()
+        .context("Failed to decode the incremental journal")?;
+    journal.journal.header.check_version()?;
+    Ok(journal)
+}
+
+/// Verifies an incremental guest (increment.rs) receipt against the published image id of the
+/// guest and decodes its journal, checking that its previous steps were verified against the
+/// same image id.
+pub fn verify_incremental_receipt(receipt: &Receipt) -> Result<IncrementalJournal> {
+    verify(receipt, &INCREMENT_ID)?;
+    let journal = decode_incremental_journal(receipt)?;
+    journal.check_image_id(&INCREMENT_ID)?;
+    Ok(journal)
+}
+
+/// Prints the processed inputs of every step of an incremental receipt, oldest first.
+pub fn print_input_chain(journal: &IncrementalJournal) {
+    for (step, commitment) in journal.input_chain.iter().enumerate() {
+        println!(
+            "Step {}: {} ballots, inputs hash 0x{}",
+            step,
+            commitment.ballot_count,
+            hex::encode(commitment.inputs_hash)
+        );
+    }
+}
+
+/// Prints what a receipt covers.
+pub fn print_header(header: &JournalHeader) {
+    println!("Journal version: {}", header.version);
+    println!("Round id: {}", header.round_id);
+    println!("Inputs hash: 0x{}", hex::encode(header.inputs_hash));
+    println!(
+        "Verifying keys hash: 0x{}",
+        hex::encode(header.verifying_keys_hash)
+    );
+    println!(
+        "Project registry hash: 0x{}",
+        hex::encode(header.project_registry_hash)
+    );
+    if let Some(root) = header.eligibility_root {
+        println!("Eligibility root: 0x{}", hex::encode(root));
+    }
+    println!(
+        "Poseidon: {} (width {}, rate {}), {} tree hash over chunks of {}",
+        header.poseidon.spec,
+        header.poseidon.width,
+        header.poseidon.rate,
+        header.poseidon.tree_hash,
+        header.poseidon.chunk_len
+    );
+    if let Some(commitment) = header.salt_commitment {
+        println!("Salt commitment: 0x{}", hex::encode(commitment));
+    }
+    println!(
+        "Accepted ballots: {} of {}",
+        header.accepted_count, header.ballot_count
+    );
+    println!("Superseded ballots: {}", header.superseded_count);
+    for rejection in &header.rejections {
+        println!("Rejected ballot {}: {}", rejection.index, rejection.reason);
+    }
+}
+
+/// Prints the project filter of an aggregate journal and the hashes of the projects it selects.
+pub fn print_projects(journal: &ProjectsJournal) {
+    if journal.filter.expr != FilterExpr::All {
+        println!("Project filter: {:?}", journal.filter.expr);
+    }
+    for project in &journal.projects {
+        println!(
+            "Project {}: {} votes, hash {}",
+            project.project_id,
+            project.vote_count,
+            project.hash.to_hex()
+        );
+    }
+}
+
+/// Prints the allocations of the pool committed by the aggregate guest.
+pub fn print_tally(tally: &Tally) {
+    println!(
+        "Pool: {} by {} (rule id {}, quorum of {} ballots)",
+        tally.config.pool, tally.config.rule, tally.rule_id, tally.config.quorum
+    );
+    for allocation in &tally.allocations {
+        println!(
+            "Allocation of {}: {} ({} ballots)",
+            allocation.project_id, allocation.amount, allocation.ballot_count
+        );
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2024 Zkonduit Inc.,
+// based on examples which are
+// Copyright 2023 RISC Zero, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Host side of the zk RetroPGF ballot pipeline.
+//!
+//! The binaries in `src/bin` and `src/main.rs` are thin wrappers around this library:
+//! load the processed inputs, build the [`risc0_zkvm::ExecutorEnv`] for one of the guests,
+//! prove it with the chosen [`ReceiptKind`] and verify the resulting receipt.
+
+pub mod audit;
+pub mod groth16;
+pub mod inclusion;
+pub mod increment;
+pub mod inputs;
+pub mod journal;
+pub mod processing;
+pub mod project;
+pub mod projects;
+pub mod prover;
+pub mod shards;
+
+pub use inputs::{load_processed_inputs, load_votes_table, ProcessedInputs, Registry, VotesTable};
+pub use prover::{prove, verify, ReceiptKind};
@@ -13,27 +13,27 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use bytemuck::Pod;
-use bytemuck::Zeroable;
+use anyhow::{Context, Result};
 use clap::{App, Arg};
-use risc0_zkvm::default_prover;
-use rpgf_ballots_methods::{PROJECTS_ELF, PROJECTS_ID, PROJECT_ELF, PROJECT_ID};
-use serde::{Deserialize, Serialize};
-use serde_json;
-use std::collections::BTreeMap;
-use std::fs;
-use std::fs::File;
-use std::time::Instant;
-use std::vec;
-#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, PartialEq)]
-#[repr(C)]
-struct Felt([u64; 4]);
+use op_rpgf::inputs::{
+    load_allowlist, load_project_categories, load_salt_secret, parse_amount_scale,
+    parse_eip712_domain, parse_poseidon_config, parse_tally_config, write_bincode,
+};
+use op_rpgf::journal::{
+    decode_project_journal, print_header, print_input_chain, print_projects, print_tally,
+    verify_incremental_receipt, verify_projects_receipt,
+};
+use op_rpgf::{
+    increment, load_processed_inputs, load_votes_table, project, projects, shards, ReceiptKind,
+    Registry,
+};
+use risc0_zkvm::sha::Digest;
+use risc0_zkvm::Receipt;
+use rpgf_ballots_core::{FilterExpr, GuestConfig, RejectionPolicy, DEFAULT_AMOUNT_SCALE};
+use rpgf_ballots_methods::{INCREMENT_ID, PROJECTS_ID, PROJECT_ID, SHARDS_ID, SHARD_ID};
+use std::{fs, thread};
 
-mod groth16;
-mod project;
-mod projects;
-
-fn main() {
+fn main() -> Result<()> {
     let matches = App::new("zkrpgf")
         .version("1.0")
         .about("")
@@ -44,6 +44,25 @@ fn main() {
                 .takes_value(true)
                 .help("Specifies the project id that will be hashed)"),
         )
+        .arg(
+            Arg::with_name("project_ids")
+                .long("project_ids")
+                .takes_value(true)
+                .conflicts_with("project_id")
+                .help("Specifies the comma separated project ids, or the file path of a json array of them, to hash one receipt each for, or to restrict the aggregate journal to with --aggregate"),
+        )
+        .arg(
+            Arg::with_name("workers")
+                .long("workers")
+                .takes_value(true)
+                .help("Specifies the number of projects proven in parallel, each by its own prover (defaults to the available parallelism)"),
+        )
+        .arg(
+            Arg::with_name("manifest")
+                .long("manifest")
+                .takes_value(true)
+                .help("Specifies the file path to write the json manifest of the project id of each receipt to (defaults to ./browser-verify/receipts_manifest.json)"),
+        )
         .arg(
             Arg::with_name("receipt")
                 .short('R')
@@ -79,7 +98,119 @@ fn main() {
             Arg::with_name("filter_by_amounts")
                 .long("filter_by_amounts")
                 .takes_value(true)
-                .help("Specifies the ballot count range to filter the projects by"),
+                .help("Specifies the `min,max` ballot count range to filter the projects by, like --filter \"votes in min..max\""),
+        ).arg(
+            Arg::with_name("filter")
+                .long("filter")
+                .takes_value(true)
+                .help("Specifies the expression to filter the projects by, e.g. \"votes >= 2 and (median > 1000 or category in [defi])\" (defaults to every project)"),
+        ).arg(
+            Arg::with_name("project_categories")
+                .long("project_categories")
+                .takes_value(true)
+                .help("Specifies the file path of the json object of category name -> project ids the filter's categories refer to"),
+        ).arg(
+            Arg::with_name("amount_scale")
+                .long("amount_scale")
+                .takes_value(true)
+                .help("Specifies the number of decimals vote amounts are scaled by (defaults to 18)"),
+        ).arg(
+            Arg::with_name("skip_invalid_ballots")
+                .long("skip_invalid_ballots")
+                .takes_value(false)
+                .help("If set, invalid ballots are left out of the tally and reported in the journal instead of aborting the proof"),
+        ).arg(
+            Arg::with_name("round_id")
+                .long("round_id")
+                .takes_value(true)
+                .help("Specifies the identifier of the RetroPGF round committed to the journal (defaults to 0)"),
+        ).arg(
+            Arg::with_name("verifying_keys")
+                .long("verifying_keys")
+                .takes_value(true)
+                .help("Specifies the file path of the verifying keys written by private_processing (defaults to ./verifying_keys)"),
+        ).arg(
+            Arg::with_name("project_registry")
+                .long("project_registry")
+                .takes_value(true)
+                .help("Specifies the file path of the project ids written by private_processing (defaults to ./project_ids)"),
+        ).arg(
+            Arg::with_name("eligibility")
+                .long("eligibility")
+                .takes_value(true)
+                .help("Specifies the file path of the json array of badgeholder addresses allowed to vote"),
+                ).arg(
+            Arg::with_name("eip712")
+                .long("eip712")
+                .takes_value(false)
+                .help("If set, ballots signed as EIP-712 typed data in the domain of the round are accepted"),
+        ).arg(
+            Arg::with_name("chain_id")
+                .long("chain_id")
+                .takes_value(true)
+                .help("Specifies the chain id of the EIP-712 domain (defaults to 10)"),
+        ).arg(
+            Arg::with_name("verifying_contract")
+                .long("verifying_contract")
+                .takes_value(true)
+                .help("Specifies the verifying contract address of the EIP-712 domain (defaults to the zero address)"),
+        ).arg(
+            Arg::with_name("safe_owners")
+                .long("safe_owners")
+                .takes_value(true)
+                .help("Specifies the file path of the json owner sets of the Safe multisig badgeholders"),
+        ).arg(
+            Arg::with_name("pool")
+                .long("pool")
+                .takes_value(true)
+                .help("If set, the aggregate guest also commits the allocations of this decimal total pool to the projects"),
+        ).arg(
+            Arg::with_name("quorum")
+                .long("quorum")
+                .takes_value(true)
+                .help("Specifies the minimum number of ballots a project needs to receive an allocation (defaults to 1)"),
+        ).arg(
+            Arg::with_name("tally_rule")
+                .long("tally_rule")
+                .takes_value(true)
+                .help("Specifies how vote amounts are aggregated into allocations: sum, mean, median, trimmed_mean:<trim> or quadratic (defaults to median)"),
+        ).arg(
+            Arg::with_name("poseidon_spec")
+                .long("poseidon_spec")
+                .takes_value(true)
+                .help("Specifies the Poseidon permutation the vote amounts are hashed with: w2r1 (width 2, rate 1) or w3r2 (width 3, rate 2) (defaults to w2r1)"),
+        ).arg(
+            Arg::with_name("poseidon_chunk_len")
+                .long("poseidon_chunk_len")
+                .takes_value(true)
+                .help("Specifies the number of vote amounts hashed together at each level of the tree hash: 8, 16, 32 or 64 (defaults to 32)"),
+        ).arg(
+            Arg::with_name("legacy_tree_hash")
+                .long("legacy_tree_hash")
+                .takes_value(false)
+                .help("If set, the vote amounts are hashed with the legacy tree hash, which doesn't bind their number"),
+        ).arg(
+            Arg::with_name("salt_secret")
+                .long("salt_secret")
+                .takes_value(true)
+                .help("Specifies the file path of a 32 byte hex secret to salt the project hashes with, committing to it in the journal"),
+        ).arg(
+            Arg::with_name("shards")
+                .long("shards")
+                .takes_value(true)
+                .help("With --aggregate, validates the ballots in this number of shards proven in parallel and aggregates their receipts (defaults to no sharding)"),
+        ).arg(
+            Arg::with_name("incremental")
+                .long("incremental")
+                .takes_value(false)
+                .conflicts_with("shards")
+                .help("With --aggregate, proves with the incremental guest, which only checks the ballots appended since --previous_receipt"),
+        ).arg(
+            Arg::with_name("previous_receipt")
+                .long("previous_receipt")
+                .takes_value(true)
+                .requires("incremental")
+                .help("Specifies the file path of the incremental receipt of the previous ballots to extend (defaults to proving every ballot)"),
         )
         .get_matches();
 
@@ -87,67 +218,200 @@ fn main() {
         .value_of("votes_table")
         .unwrap_or("votes_table.json");
 
-    // Open the processed inputs file without hardcoding the path
-    let file = File::open("./processed_inputs").expect("Could not find processed_inputs file");
+    let inputs = load_processed_inputs("./processed_inputs")?;
 
-    // Read the processed inputs file
-    let inputs: Vec<(Vec<u8>, Vec<u8>)> = bincode::deserialize_from(file).unwrap();
+    let votes_table = load_votes_table(votes_table_path)?;
 
-    let votes_table: BTreeMap<String, Vec<i128>> =
-        serde_json::from_slice(&fs::read(votes_table_path).unwrap()).unwrap();
+    let mut registry = Registry::load(
+        matches
+            .value_of("verifying_keys")
+            .unwrap_or("./verifying_keys"),
+        matches
+            .value_of("project_registry")
+            .unwrap_or("./project_ids"),
+    )?;
+    if let Some(path) = matches.value_of("safe_owners") {
+        registry = registry.with_safe_owners(path)?;
+    }
+
+    let amount_scale = match matches.value_of("amount_scale") {
+        Some(scale) => parse_amount_scale(scale)?,
+        None => DEFAULT_AMOUNT_SCALE,
+    };
+    let config = GuestConfig {
+        round_id: matches
+            .value_of("round_id")
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or(0),
+        amount_scale,
+        rejection_policy: if matches.is_present("skip_invalid_ballots") {
+            RejectionPolicy::Skip
+        } else {
+            RejectionPolicy::Abort
+        },
+        eligibility: matches
+            .value_of("eligibility")
+            .map(load_allowlist)
+            .transpose()?,
+        eip712: if matches.is_present("eip712") {
+            Some(parse_eip712_domain(
+                matches.value_of("chain_id"),
+                matches.value_of("verifying_contract"),
+            )?)
+        } else {
+            None
+        },
+        tally: matches
+            .value_of("pool")
+            .map(|pool| {
+                parse_tally_config(
+                    pool,
+                    matches.value_of("quorum"),
+                    matches.value_of("tally_rule"),
+                    amount_scale,
+                )
+            })
+            .transpose()?,
+        poseidon: parse_poseidon_config(
+            matches.value_of("poseidon_spec"),
+            matches.value_of("poseidon_chunk_len"),
+            matches.is_present("legacy_tree_hash"),
+        )?,
+        salt_secret: matches
+            .value_of("salt_secret")
+            .map(load_salt_secret)
+            .transpose()?,
+    };
+
+    // If the groth16 flag is set, convert the receipt to a groth16 proof
+    let kind = if matches.is_present("groth16") {
+        ReceiptKind::Groth16
+    } else {
+        ReceiptKind::Stark
+    };
 
     // Determine which ELF to use
     if matches.is_present("aggregate") {
-        let env = projects::aggregate_project_votes(&matches, votes_table, inputs);
-        // If the groth16 flag is set, convert the receipt to a groth16 proof
-        let receipt = if matches.is_present("groth16") {
-            groth16::stark_to_groth16(env, &PROJECTS_ID, &PROJECTS_ELF)
+        let mut expr = match matches.value_of("filter") {
+            Some(expr) => FilterExpr::parse(expr, amount_scale)
+                .with_context(|| format!("Invalid filter {}", expr))?,
+            None => FilterExpr::All,
+        };
+        if let Some(range) = matches.value_of("filter_by_amounts") {
+            expr = expr.and(projects::parse_ballot_count_range(range)?);
+        }
+        if let Some(project_ids) = matches.value_of("project_ids") {
+            expr = expr.and(FilterExpr::Projects(project::parse_project_ids(
+                project_ids,
+            )?));
+        }
+        let categories = matches
+            .value_of("project_categories")
+            .map(load_project_categories)
+            .transpose()?
+            .unwrap_or_default();
+        let filter = projects::project_filter(expr, &categories)?;
+        let selected = projects::filter_votes_table(&votes_table, &filter)?;
+        println!(
+            "The filter selects {} projects of the votes table",
+            selected.len()
+        );
+        let shard_count = matches
+            .value_of("shards")
+            .map(str::parse::<usize>)
+            .transpose()?;
+        let incremental = matches.is_present("incremental");
+        let receipt = if incremental {
+            let previous = matches
+                .value_of("previous_receipt")
+                .map(|path| -> Result<Receipt> {
+                    let bytes = fs::read(path)
+                        .with_context(|| format!("Could not find previous receipt {}", path))?;
+                    Ok(bincode::deserialize(&bytes)?)
+                })
+                .transpose()?;
+            increment::prove_increment(&inputs, &registry, &filter, &config, previous, kind)?
         } else {
-            prove_default_prover(env, &PROJECTS_ELF, &PROJECTS_ID)
+            match shard_count {
+                Some(shard_count) => shards::sharded_aggregate_project_votes(
+                    &inputs,
+                    &registry,
+                    &filter,
+                    &config,
+                    shard_count,
+                    kind,
+                )?,
+                None => {
+                    projects::aggregate_project_votes(&inputs, &registry, &filter, &config, kind)?
+                }
+            }
         };
-        let receipt_ser = bincode::serialize(&receipt).unwrap();
-        let image_id = bincode::serialize(&PROJECTS_ID).unwrap();
+        let journal = if incremental {
+            let journal = verify_incremental_receipt(&receipt)?;
+            print_input_chain(&journal);
+            journal.journal
+        } else if shard_count.is_some() {
+            verify_projects_receipt(&receipt, &SHARDS_ID)?
+        } else {
+            verify_projects_receipt(&receipt, &PROJECTS_ID)?
+        };
+        print_header(&journal.header);
+        print_projects(&journal);
+        if let Some(tally) = &journal.tally {
+            print_tally(tally);
+        }
         let receipt_path = matches
             .value_of("receipt")
             .unwrap_or("./browser-verify/receipt_aggr");
         let image_id_path = matches
             .value_of("image_id")
             .unwrap_or("./browser-verify/image_id_aggr");
-        fs::write(receipt_path, receipt_ser).unwrap();
-        fs::write(image_id_path, image_id).unwrap();
-    } else {
-        let env = project::single_project_votes(&matches, inputs);
-        // If the groth16 flag is set, convert the receipt to a groth16 proof
-        let receipt = if matches.is_present("groth16") {
-            groth16::stark_to_groth16(env, &PROJECT_ID, &PROJECT_ELF)
+        write_bincode(receipt_path, &receipt)?;
+        if incremental {
+            // verify_incremental_receipt checks the journal's image id against this one
+            write_bincode(image_id_path, &INCREMENT_ID)?;
+        } else if shard_count.is_some() {
+            // verify_projects_receipt checks the journal's shard image id against this one
+            println!("Shard image id: {}", Digest::from(SHARD_ID));
+            write_bincode(image_id_path, &SHARDS_ID)?;
         } else {
-            prove_default_prover(env, &PROJECT_ELF, &PROJECT_ID)
+            write_bincode(image_id_path, &PROJECTS_ID)?;
+        }
+    } else {
+        let project_ids = match matches.value_of("project_ids") {
+            Some(project_ids) => project::parse_project_ids(project_ids)?,
+            None => vec![project::resolve_project_id(
+                &inputs,
+                matches.value_of("project_id"),
+            )?],
         };
-        let receipts_vec = vec![receipt.clone()];
-        // store the reciept and image id in a file
-        let receipts_ser = bincode::serialize(&receipts_vec).unwrap();
-        let image_id = bincode::serialize(&PROJECT_ID).unwrap();
+        let workers = match matches.value_of("workers") {
+            Some(workers) => workers.parse()?,
+            None => thread::available_parallelism()?.get(),
+        };
+        let receipts_vec =
+            project::batch_project_votes(&inputs, &registry, &project_ids, &config, workers, kind)?;
+        if let Some(receipt) = receipts_vec.first() {
+            print_header(&decode_project_journal(receipt)?.header);
+        }
+        // store the receipts, their manifest and the image id in a file
         let receipt_path = matches
             .value_of("receipt")
             .unwrap_or("./browser-verify/receipts");
+        let manifest_path = matches
+            .value_of("manifest")
+            .unwrap_or("./browser-verify/receipts_manifest.json");
         let image_id_path = matches
             .value_of("image_id")
             .unwrap_or("./browser-verify/image_id");
-        fs::write(receipt_path, receipts_ser).unwrap();
-        fs::write(image_id_path, image_id).unwrap();
+        write_bincode(receipt_path, &receipts_vec)?;
+        fs::write(
+            manifest_path,
+            serde_json::to_vec_pretty(&project::receipts_manifest(&receipts_vec)?)?,
+        )
+        .with_context(|| format!("Unable to write {}", manifest_path))?;
+        write_bincode(image_id_path, &PROJECT_ID)?;
     };
-}
-
-fn prove_default_prover(
-    env: risc0_zkvm::ExecutorEnv,
-    elf: &[u8],
-    image_id: &[u32; 8],
-) -> risc0_zkvm::Receipt {
-    let prover = default_prover();
-    let start_time = Instant::now();
-    let receipt = prover.prove(env, elf).unwrap();
-    let proving_time = start_time.elapsed();
-    println!("Proving time: {:?}", proving_time);
-    receipt.verify(*image_id).unwrap();
-    receipt
+    Ok(())
 }
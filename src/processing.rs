@@ -0,0 +1,336 @@
+use crate::inputs::{ProcessedInputs, VotesTable};
+use anyhow::{anyhow, bail, Context, Result};
+use csv::Writer;
+use ethers::types::H160;
+use k256::ecdsa::VerifyingKey;
+use rpgf_ballots_core::signature::{
+    message_hashes, recover_signer, signer_address, verify_safe_signatures,
+};
+use rpgf_ballots_core::{
+    find_safe, superseded, Address, Allowlist, Ballot, GuestConfig, RejectionPolicy, SafeOwners,
+};
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Output of the private processing step.
+#[derive(Debug, Default)]
+pub struct ProcessedBallots {
+    /// `(ballot json bytes, 64 byte signature or Safe packed signatures)` pairs passed to the
+    /// guests.
+    pub inputs: ProcessedInputs,
+    /// Sec1 encoded verifying keys recovered from each signature, or the address of the Safe
+    /// multisig that cast the ballot, in `inputs` order. Empty for the invalid records whose
+    /// signer couldn't be recovered.
+    pub verifying_keys: Vec<Vec<u8>>,
+    /// Mapping of (project id) -> (vote amounts) of the latest valid ballot of every signer.
+    pub votes_table: VotesTable,
+    /// Indices of the invalid records kept for the guests to reject.
+    pub rejected: Vec<usize>,
+    /// Indices of the valid records superseded by a later ballot of the same signer.
+    pub superseded: Vec<usize>,
+    /// Every project voted for by a valid ballot, superseded ones included since the guests
+    /// check their votes against the registry too.
+    voted_projects: BTreeSet<String>,
+}
+
+/// The verifying key (or Safe address) and signature passed to the guests for a record, and
+/// its ballot if the record is valid.
+struct ProcessedRecord {
+    verifying_key: Vec<u8>,
+    signature: Vec<u8>,
+    valid: Result<ValidRecord>,
+}
+
+/// A record whose signature checked out.
+struct ValidRecord {
+    /// The record's address, the signer or the Safe that cast the ballot.
+    signer: Address,
+    nonce: u64,
+    votes: Vec<(String, i128)>,
+}
+
+impl ProcessedBallots {
+    /// The sorted ids of every project that received a vote.
+    pub fn project_ids(&self) -> Vec<String> {
+        self.voted_projects.iter().cloned().collect()
+    }
+}
+
+/// Reads the `address, signature, ballot json, ..` records of the ballots csv, recovering the
+/// verifying key of each signer. Only the first `record_count` records are processed if it
+/// is non zero. Vote amounts are scaled to fixed point integers with the config's amount scale,
+/// and EIP-712 signatures are recovered if the config has an EIP-712 domain.
+///
+/// Records whose address is a Safe in `safe_owners` carry the packed signatures of its owners,
+/// which must reach the Safe's threshold. If the config has an eligibility allowlist, records of
+/// signers (or Safes) outside of it are invalid, as the guests reject them.
+///
+/// Invalid records either fail the processing or, with [`RejectionPolicy::Skip`], are kept with
+/// whatever verifying key was recovered so that the guests report them as rejected for the
+/// reason they are invalid. Records superseded by a later ballot of the same signer are kept
+/// too, but left out of the votes table like the guests leave them out of the tally.
+pub fn process_ballots_csv(
+    path: impl AsRef<Path>,
+    record_count: usize,
+    config: &GuestConfig,
+    safe_owners: &[SafeOwners],
+) -> Result<ProcessedBallots> {
+    let path = path.as_ref();
+    // Open the CSV file
+    let file = File::open(path).with_context(|| {
+        format!(
+            "Could not find {}, please run the ballot_generator binary to generate the file",
+            path.display()
+        )
+    })?;
+
+    // Create a CSV reader
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(file);
+
+    // Create an iterator over records
+    let records_iter = rdr.records();
+
+    // Apply `.take()` conditionally based on `record_count`
+    let records_boxed_iter: Box<dyn Iterator<Item = csv::Result<csv::StringRecord>>> =
+        if record_count > 0 {
+            Box::new(records_iter.take(record_count))
+        } else {
+            Box::new(records_iter)
+        };
+
+    let mut processed = ProcessedBallots::default();
+    let mut valid_records = vec![];
+    let domain_separator = config.eip712_domain_separator();
+
+    // Iterate over each record
+    for (index, result) in records_boxed_iter.enumerate() {
+        let record = result?;
+        let ballot_data = record[2].to_owned();
+
+        let processed_record = process_record(
+            &record,
+            config.amount_scale,
+            domain_separator.as_ref(),
+            safe_owners,
+            config.eligibility.as_ref(),
+        );
+        match processed_record.valid {
+            Ok(valid) => {
+                processed
+                    .voted_projects
+                    .extend(valid.votes.iter().map(|(project_id, _)| project_id.clone()));
+                valid_records.push((index, valid.signer, valid.nonce, valid.votes));
+            }
+            Err(e) => match config.rejection_policy {
+                RejectionPolicy::Abort => {
+                    return Err(e.context(format!("Invalid ballot in record {}", index)))
+                }
+                RejectionPolicy::Skip => {
+                    println!("Skipping invalid ballot in record {}: {:#}", index, e);
+                    processed.rejected.push(index);
+                }
+            },
+        }
+        processed
+            .verifying_keys
+            .push(processed_record.verifying_key);
+        processed.inputs.push((
+            ballot_data.as_bytes().to_owned(),
+            processed_record.signature,
+        ));
+    }
+
+    let signers: Vec<(Address, u64)> = valid_records
+        .iter()
+        .map(|(_, signer, nonce, _)| (*signer, *nonce))
+        .collect();
+    for ((index, _, _, votes), superseded) in valid_records.into_iter().zip(superseded(&signers)) {
+        if superseded {
+            println!("Superseded ballot in record {}", index);
+            processed.superseded.push(index);
+            continue;
+        }
+        // Process the ballots to populate the votes_table hashmap
+        for (project_id, amount) in votes {
+            processed
+                .votes_table
+                .entry(project_id)
+                .or_insert_with(Vec::new)
+                .push(amount);
+        }
+    }
+
+    Ok(processed)
+}
+
+/// Recovers the sec1 verifying key of a `address, signature, ballot json, ..` record, or checks
+/// the owner signatures of a Safe record, checks its address is eligible and parses its
+/// `(project id, fixed point amount)` votes.
+///
+/// Invalid records keep what was recovered before they failed, so that the guests run into the
+/// same fault: the verifying key of a signer, or the address and owner signatures of a Safe.
+/// Signers that couldn't be recovered get an empty verifying key.
+fn process_record(
+    record: &csv::StringRecord,
+    amount_scale: u32,
+    domain_separator: Option<&[u8; 32]>,
+    safe_owners: &[SafeOwners],
+    eligibility: Option<&Allowlist>,
+) -> ProcessedRecord {
+    let (verifying_key, signature, signer, ballot) =
+        match recover_record(record, domain_separator, safe_owners) {
+            Ok(recovered) => recovered,
+            Err(e) => {
+                let mut signature =
+                    hex::decode(record[1].trim_start_matches("0x")).unwrap_or_default();
+                let safe = H160::from_str(record[0].trim_start_matches("0x"))
+                    .ok()
+                    .and_then(|address| find_safe(safe_owners, &address.0));
+                let verifying_key = match safe {
+                    Some(owners) => owners.safe.to_vec(),
+                    None => {
+                        signature.truncate(64);
+                        vec![]
+                    }
+                };
+                return ProcessedRecord {
+                    verifying_key,
+                    signature,
+                    valid: Err(e),
+                };
+            }
+        };
+
+    let votes = match eligibility {
+        // checked before the amounts, in the guests' order
+        Some(eligibility) if !eligibility.contains(&signer) => Err(anyhow!(
+            "Address {:?} isn't in the eligibility allowlist",
+            H160::from(signer)
+        )),
+        _ => ballot
+            .votes()
+            .iter()
+            .map(|vote| {
+                Ok((
+                    vote.project_id.to_string(),
+                    vote.amount.to_fixed(amount_scale)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>(),
+    };
+    ProcessedRecord {
+        verifying_key,
+        signature,
+        valid: votes.map(|votes| ValidRecord {
+            signer,
+            nonce: ballot.nonce(),
+            votes,
+        }),
+    }
+}
+
+/// Recovers the verifying key (or Safe address) of a record, the signature passed to the guests
+/// alongside it and the signer, and parses its ballot.
+fn recover_record(
+    record: &csv::StringRecord,
+    domain_separator: Option<&[u8; 32]>,
+    safe_owners: &[SafeOwners],
+) -> Result<(Vec<u8>, Vec<u8>, Address, Ballot)> {
+    // get the ethereum address, signature and json from the record
+    let address = H160::from_str(record[0].trim_start_matches("0x"))?;
+    let signature = &record[1];
+    let ballot = Ballot::parse(record[2].as_bytes().to_owned())?;
+
+    let (verifying_key, signature) = match find_safe(safe_owners, &address.0) {
+        Some(owners) => {
+            let signatures = hex::decode(signature.trim_start_matches("0x"))?;
+            check_safe_signatures(owners, &signatures, &ballot, domain_separator)?;
+            (owners.safe.to_vec(), signatures)
+        }
+        None => {
+            let (verifying_key, sig_minus_rec) =
+                recover_verifying_key(address, signature, &ballot, domain_separator)?;
+            (verifying_key.to_sec1_bytes().to_vec(), sig_minus_rec)
+        }
+    };
+    Ok((verifying_key, signature, address.0, ballot))
+}
+
+/// Recovers the verifying key of `address` from its signature of `ballot`, returning it
+/// alongside the 64 byte signature without the recovery id.
+///
+/// The ballot is either signed as EIP-712 typed data if a domain separator is given, or with
+/// EIP-191 by signing its json directly or the hex string of its keccak hash.
+pub fn recover_verifying_key(
+    address: H160,
+    signature: &str,
+    ballot: &Ballot,
+    domain_separator: Option<&[u8; 32]>,
+) -> Result<(VerifyingKey, Vec<u8>)> {
+    // strip the 0x prefix from the signature
+    let sig_bytes = hex::decode(signature.trim_start_matches("0x"))?;
+    if sig_bytes.len() != 65 {
+        bail!(
+            "Expected a 65 byte signature, got {} bytes",
+            sig_bytes.len()
+        );
+    }
+
+    // the derived address from the verifying key must match the address in the csv
+    let verifying_key = message_hashes(ballot, domain_separator)
+        .iter()
+        .filter_map(|message_hash| recover_signer(&sig_bytes, message_hash))
+        .find(|verifying_key| signer_address(verifying_key) == address.0)
+        .with_context(|| {
+            format!(
+                "Signature of the ballot doesn't recover the address {:?} in the record",
+                address
+            )
+        })?;
+    Ok((verifying_key, sig_bytes[..64].to_vec()))
+}
+
+/// Checks Safe style packed owner signatures of a ballot as the guests do, see
+/// [`verify_safe_signatures`].
+pub fn check_safe_signatures(
+    owners: &SafeOwners,
+    signatures: &[u8],
+    ballot: &Ballot,
+    domain_separator: Option<&[u8; 32]>,
+) -> Result<()> {
+    verify_safe_signatures(owners, signatures, ballot, domain_separator).map_err(|reason| {
+        anyhow!(
+            "Signatures of safe {:?} don't sign the ballot: {}",
+            H160::from(owners.safe),
+            reason
+        )
+    })
+}
+
+/// Writes the votes table as `Project ID, Vote Amounts` csv rows, joining the amounts with ";".
+pub fn write_votes_table_csv(path: impl AsRef<Path>, votes_table: &VotesTable) -> Result<()> {
+    // Serialize the votes_table hashmap into CSV format and write to a file
+    let mut wtr = Writer::from_path(path).context("Unable to create CSV writer")?;
+
+    // Write CSV header
+    wtr.write_record(["Project ID", "Vote Amounts"])?;
+
+    // Iterate over the votes_table and write each entry as a CSV row
+    for (project_id, vote_amounts) in votes_table.iter() {
+        // Join all vote amounts with ";" to keep them in one column, or handle as needed
+        let amounts_str = vote_amounts
+            .iter()
+            .map(|amount| amount.to_string())
+            .collect::<Vec<String>>()
+            .join(";");
+        wtr.write_record([project_id, &amounts_str])?;
+    }
+
+    // Ensure all data is flushed to the file
+    wtr.flush()?;
+    Ok(())
+}
@@ -1,37 +1,168 @@
-use bytemuck::Pod;
-use bytemuck::Zeroable;
-use clap::ArgMatches;
-use risc0_zkvm::ExecutorEnv;
+use crate::inputs::{ProcessedInputs, Registry};
+use crate::journal::decode_project_journal;
+use crate::prover::{prove, ReceiptKind};
+use anyhow::{anyhow, Context, Result};
+use risc0_zkvm::{ExecutorEnv, Receipt};
+use rpgf_ballots_core::{Ballot, GuestConfig, ProjectId};
+use rpgf_ballots_methods::{PROJECT_ELF, PROJECT_ID};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::{fs, thread};
 
-#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, PartialEq)]
-#[repr(C)]
-struct Felt([u64; 4]);
+/// The project of a receipt in a receipts bundle of single project receipts, by index.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub index: usize,
+    pub project_id: String,
+}
 
-pub fn single_project_votes(
-    matches: &ArgMatches,
-    inputs: Vec<(Vec<u8>, Vec<u8>)>,
-) -> ExecutorEnv<'_> {
-    let project_id = matches.value_of("project_id").unwrap_or("0");
+/// Returns `project_id`, or the first project voted on in the first ballot if none (or "0")
+/// is given.
+pub fn resolve_project_id(inputs: &ProcessedInputs, project_id: Option<&str>) -> Result<ProjectId> {
+    if let Some(project_id) = project_id.filter(|project_id| *project_id != "0") {
+        return project_id
+            .parse()
+            .with_context(|| format!("Invalid project id {}", project_id));
+    }
     // get the project id from the inputs
-    let project_id_hex = if project_id == "0" {
-        let ballot_data_bytes = inputs[0].0.clone();
+    let (ballot_data_bytes, _) = inputs.first().context("Processed inputs are empty")?;
 
-        let ballot_data = String::from_utf8(ballot_data_bytes).unwrap();
+    let ballot = Ballot::parse(ballot_data_bytes.clone())?;
 
-        let ballot_data: serde_json::Value = serde_json::from_str(&ballot_data).unwrap();
+    ballot
+        .votes()
+        .first()
+        .map(|vote| vote.project_id)
+        .context("First ballot has no votes")
+}
 
-        ballot_data[0]["projectId"].as_str().unwrap().to_owned()
+/// Parses a comma separated list of project ids, or reads them from a json array file like the
+/// project registry written by private_processing if `project_ids` is a path to one.
+pub fn parse_project_ids(project_ids: &str) -> Result<Vec<ProjectId>> {
+    let project_ids: Vec<String> = if Path::new(project_ids).is_file() {
+        let bytes = fs::read(project_ids)
+            .with_context(|| format!("Could not read project ids file {}", project_ids))?;
+        serde_json::from_slice(&bytes)
+            .with_context(|| format!("Could not deserialize project ids file {}", project_ids))?
     } else {
-        project_id.to_string()
+        project_ids.split(',').map(str::to_string).collect()
     };
+    project_ids
+        .iter()
+        .map(|project_id| {
+            project_id
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid project id {}", project_id))
+        })
+        .collect()
+}
+
+/// Builds the executor env for the single project guest (project.rs).
+pub fn single_project_env(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    project_id: &ProjectId,
+    config: &GuestConfig,
+) -> Result<ExecutorEnv<'static>> {
+    // the inputs are bincode encoded, as in the processed inputs file, for the guest to hash
+    let env = ExecutorEnv::builder()
+        .write(&bincode::serialize(inputs)?)
+        .context("Failed to serialize inputs")?
+        .write(&registry.verifying_keys)
+        .context("Failed to serialize verifying_keys")?
+        .write(&registry.project_ids)
+        .context("Failed to serialize project registry")?
+        .write(&registry.safe_owners)
+        .context("Failed to serialize safe owners")?
+        .write(project_id)
+        .context("Failed to serialize project_id")?
+        .write(config)
+        .context("Failed to serialize config")?
+        .build()?;
+    Ok(env)
+}
+
+/// Proves the vote amounts hash of a single project.
+pub fn single_project_votes(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    project_id: &ProjectId,
+    config: &GuestConfig,
+    kind: ReceiptKind,
+) -> Result<Receipt> {
+    let env = single_project_env(inputs, registry, project_id, config)?;
+    prove(env, PROJECT_ELF, &PROJECT_ID, kind)
+}
+
+/// Proves the vote amounts hash of each of `project_ids` on a pool of `workers` threads, each
+/// running its own prover, and returns the receipts in `project_ids` order.
+pub fn batch_project_votes(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    project_ids: &[ProjectId],
+    config: &GuestConfig,
+    workers: usize,
+    kind: ReceiptKind,
+) -> Result<Vec<Receipt>> {
+    let workers = workers.clamp(1, project_ids.len().max(1));
+    println!(
+        "Proving {} projects on {} workers",
+        project_ids.len(),
+        workers
+    );
+    let next = AtomicUsize::new(0);
+    let receipts: Mutex<Vec<Option<Receipt>>> = Mutex::new(vec![None; project_ids.len()]);
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| -> Result<()> {
+                    // each worker takes the next project until there are none left
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(project_id) = project_ids.get(index) else {
+                            return Ok(());
+                        };
+                        match single_project_votes(inputs, registry, project_id, config, kind) {
+                            Ok(receipt) => receipts.lock().unwrap()[index] = Some(receipt),
+                            Err(e) => {
+                                // the other workers stop after their current project
+                                next.store(project_ids.len(), Ordering::Relaxed);
+                                return Err(
+                                    e.context(format!("Failed to prove project {}", project_id))
+                                );
+                            }
+                        }
+                    }
+                })
+            })
+            .collect();
+        workers.into_iter().try_for_each(|worker| {
+            worker
+                .join()
+                .map_err(|_| anyhow!("A project prover panicked"))?
+        })
+    })?;
+    Ok(receipts
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|receipt| receipt.expect("every project is proven"))
+        .collect())
+}
 
-    let env: ExecutorEnv<'_> = ExecutorEnv::builder()
-        .write(&inputs)
-        .expect("Failed to deserialize inputs")
-        .write(&project_id_hex)
-        .expect("Failed to deserialize project_id")
-        .build()
-        .unwrap();
-    env
+/// Lists the project each receipt of a bundle was proven for, according to its journal.
+pub fn receipts_manifest(receipts: &[Receipt]) -> Result<Vec<ManifestEntry>> {
+    receipts
+        .iter()
+        .enumerate()
+        .map(|(index, receipt)| {
+            Ok(ManifestEntry {
+                index,
+                project_id: decode_project_journal(receipt)?.project_id.to_string(),
+            })
+        })
+        .collect()
 }
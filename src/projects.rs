@@ -1,60 +1,100 @@
-use bytemuck::Pod;
-use bytemuck::Zeroable;
-use clap::ArgMatches;
-use risc0_zkvm::ExecutorEnv;
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use crate::inputs::{ProcessedInputs, ProjectCategories, Registry, VotesTable};
+use crate::prover::{prove, ReceiptKind};
+use anyhow::{bail, Context, Result};
+use risc0_zkvm::{ExecutorEnv, Receipt};
+use rpgf_ballots_core::{FilterExpr, GuestConfig, ProjectFilter, ProjectId};
+use rpgf_ballots_methods::{PROJECTS_ELF, PROJECTS_ID};
 
-#[derive(Copy, Clone, Pod, Serialize, Deserialize, Zeroable, Debug, PartialEq)]
-#[repr(C)]
-struct Felt([u64; 4]);
-
-pub fn aggregate_project_votes(
-    matches: &ArgMatches,
-    votes_table: BTreeMap<String, Vec<i128>>,
-    inputs: Vec<(Vec<u8>, Vec<u8>)>,
-) -> ExecutorEnv<'_> {
+/// Parses a `min,max` ballot count range into the filter expression selecting the projects
+/// with a number of counted votes within it.
+pub fn parse_ballot_count_range(range: &str) -> Result<FilterExpr> {
     // filter by ballot count, with a "," as the delimiter
-    let filter_by_ballot_count = matches
-        .value_of("filter_by_amounts")
-        .map(|s| {
-            let mut iter = s.split(",");
-            let min = iter.next().unwrap().parse::<usize>().unwrap();
-            let max = iter.next().unwrap().parse::<usize>().unwrap();
-            (min, max)
-        })
-        .unwrap_or((0, 0));
+    let (min, max) = range
+        .split_once(',')
+        .with_context(|| format!("Expected a `min,max` ballot count range, got {}", range))?;
+    let min = min
+        .trim()
+        .parse::<u32>()
+        .with_context(|| format!("Invalid minimum ballot count {}", min))?;
+    let max = max
+        .trim()
+        .parse::<u32>()
+        .with_context(|| format!("Invalid maximum ballot count {}", max))?;
+    if min > max {
+        bail!("Empty ballot count range {}", range);
+    }
+    Ok(FilterExpr::VoteCountRange { min, max })
+}
 
-    // project ids to filter by in guest according to the filter_by_ballot_count votes range
-    let mut project_ids: Vec<String> = vec![];
+/// Builds the filter the guest applies and commits to the journal, with the projects of the
+/// categories the expression refers to out of `categories`.
+pub fn project_filter(expr: FilterExpr, categories: &ProjectCategories) -> Result<ProjectFilter> {
+    let categories = expr
+        .categories()
+        .into_iter()
+        .map(|name| {
+            let project_ids = categories
+                .get(name)
+                .with_context(|| format!("Unknown project category {}", name))?;
+            Ok((name.to_string(), project_ids.clone()))
+        })
+        .collect::<Result<_>>()?;
+    Ok(ProjectFilter { expr, categories })
+}
 
-    // determine which projects ids have a vote count within the specified range
-    if filter_by_ballot_count != (0, 0) {
-        let vote_table_length = votes_table.len();
-        let votes_table_filtered: BTreeMap<String, Vec<i128>> = votes_table
-            .into_iter()
-            .filter(|(_, amounts)| {
-                let count = amounts.len();
-                count >= filter_by_ballot_count.0 && count <= filter_by_ballot_count.1
-            })
-            .collect();
-        if votes_table_filtered.len() == 0 {
-            panic!("No projects have a vote count within the specified range")
+/// Returns the projects of the votes table the filter selects, evaluated over their vote
+/// amounts as the guest evaluates it over the counted ones.
+///
+/// Fails if the filter doesn't select any project of the votes table.
+pub fn filter_votes_table(votes_table: &VotesTable, filter: &ProjectFilter) -> Result<Vec<String>> {
+    let mut project_ids = vec![];
+    for (project_id, amounts) in votes_table {
+        let id: ProjectId = project_id
+            .parse()
+            .with_context(|| format!("Invalid project id {} in votes table", project_id))?;
+        if filter.matches(&id, amounts) {
+            project_ids.push(project_id.clone());
         }
-        // if the vote table length is equal to the post filter votes table length, then the filter is redundant and we should leave the
-        // project_ids array empty when passed to guest
-        if vote_table_length != votes_table_filtered.len() {
-            project_ids.extend(votes_table_filtered.keys().cloned());
-        }
-        println!("project_ids filter: {:?}", project_ids);
     }
+    if project_ids.is_empty() {
+        bail!("The filter doesn't select any project of the votes table")
+    }
+    Ok(project_ids)
+}
 
-    let env: ExecutorEnv<'_> = ExecutorEnv::builder()
-        .write(&inputs)
-        .expect("Failed to deserialize inputs")
-        .write(&project_ids)
-        .expect("Failed to deserialize project_ids")
-        .build()
-        .unwrap();
-    env
+/// Builds the executor env for the aggregate guest (projects.rs).
+pub fn aggregate_project_env(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+) -> Result<ExecutorEnv<'static>> {
+    // the inputs are bincode encoded, as in the processed inputs file, for the guest to hash
+    let env = ExecutorEnv::builder()
+        .write(&bincode::serialize(inputs)?)
+        .context("Failed to serialize inputs")?
+        .write(&registry.verifying_keys)
+        .context("Failed to serialize verifying_keys")?
+        .write(&registry.project_ids)
+        .context("Failed to serialize project registry")?
+        .write(&registry.safe_owners)
+        .context("Failed to serialize safe owners")?
+        .write(filter)
+        .context("Failed to serialize project filter")?
+        .write(config)
+        .context("Failed to serialize config")?
+        .build()?;
+    Ok(env)
+}
+
+/// Proves the vote amounts hashes of every project the filter selects.
+pub fn aggregate_project_votes(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    kind: ReceiptKind,
+) -> Result<Receipt> {
+    let env = aggregate_project_env(inputs, registry, filter, config)?;
+    prove(env, PROJECTS_ELF, &PROJECTS_ID, kind)
 }
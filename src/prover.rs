@@ -0,0 +1,44 @@
+use crate::groth16;
+use anyhow::{anyhow, Result};
+use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use std::time::Instant;
+
+/// The kind of receipt produced by [`prove`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReceiptKind {
+    /// Composite stark receipt from the default prover.
+    #[default]
+    Stark,
+    /// Stark receipt compressed to a groth16 proof. Requires docker on x86.
+    Groth16,
+}
+
+/// Proves `elf` against `env` and verifies the resulting receipt against `image_id`.
+pub fn prove(
+    env: ExecutorEnv<'_>,
+    elf: &[u8],
+    image_id: &[u32; 8],
+    kind: ReceiptKind,
+) -> Result<Receipt> {
+    match kind {
+        ReceiptKind::Stark => prove_default_prover(env, elf, image_id),
+        ReceiptKind::Groth16 => groth16::stark_to_groth16(env, image_id, elf),
+    }
+}
+
+/// Verifies `receipt` against `image_id`.
+pub fn verify(receipt: &Receipt, image_id: &[u32; 8]) -> Result<()> {
+    receipt
+        .verify(*image_id)
+        .map_err(|e| anyhow!("Receipt verification failed: {:?}", e))
+}
+
+fn prove_default_prover(env: ExecutorEnv<'_>, elf: &[u8], image_id: &[u32; 8]) -> Result<Receipt> {
+    let prover = default_prover();
+    let start_time = Instant::now();
+    let receipt = prover.prove(env, elf)?;
+    let proving_time = start_time.elapsed();
+    println!("Proving time: {:?}", proving_time);
+    verify(&receipt, image_id)?;
+    Ok(receipt)
+}
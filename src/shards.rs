@@ -0,0 +1,184 @@
+use crate::inputs::{ProcessedInputs, Registry};
+use crate::prover::{prove, ReceiptKind};
+use anyhow::{anyhow, bail, Context, Result};
+use risc0_zkvm::{ExecutorEnv, Receipt};
+use rpgf_ballots_core::sha256::{bincode_seq_elements, bincode_seq_state};
+use rpgf_ballots_core::{GuestConfig, ProjectFilter, Sha256State, ShardJournal};
+use rpgf_ballots_methods::{SHARDS_ELF, SHARDS_ID, SHARD_ELF, SHARD_ID};
+use std::ops::Range;
+use std::thread;
+
+/// Splits `ballot_count` ballots into at most `shard_count` contiguous ranges, as even as
+/// possible, for the shard guest (shard.rs) to validate.
+pub fn shard_ranges(ballot_count: usize, shard_count: usize) -> Vec<Range<usize>> {
+    let shard_count = shard_count.clamp(1, ballot_count.max(1));
+    let (size, remainder) = (ballot_count / shard_count, ballot_count % shard_count);
+    let mut start = 0;
+    (0..shard_count)
+        .map(|shard| {
+            let end = start + size + usize::from(shard < remainder);
+            let range = start..end;
+            start = end;
+            range
+        })
+        .collect()
+}
+
+/// The range of the verifying keys of the ballots of `range`. The keys may be fewer than the
+/// ballots, which the guest rejects.
+fn shard_keys(verifying_keys: &[Vec<u8>], range: Range<usize>) -> Range<usize> {
+    range.start.min(verifying_keys.len())..range.end.min(verifying_keys.len())
+}
+
+/// The states the shard guests of `ranges` start the hashes of the processed inputs and
+/// verifying keys files from, each left by the previous shard, for the shards guest to chain.
+pub fn shard_hash_states(
+    inputs: &ProcessedInputs,
+    verifying_keys: &[Vec<u8>],
+    ranges: &[Range<usize>],
+) -> Result<Vec<(Sha256State, Sha256State)>> {
+    // the shards only hash the keys of their ballots
+    if verifying_keys.len() > inputs.len() {
+        bail!("There are more verifying keys than ballots");
+    }
+    let mut inputs_state = bincode_seq_state(inputs.len());
+    let mut keys_state = bincode_seq_state(verifying_keys.len());
+    let mut states = vec![];
+    for range in ranges {
+        states.push((inputs_state.clone(), keys_state.clone()));
+        let keys = shard_keys(verifying_keys, range.clone());
+        inputs_state.update(bincode_seq_elements(&bincode::serialize(
+            &inputs[range.clone()],
+        )?));
+        keys_state.update(bincode_seq_elements(&bincode::serialize(
+            &verifying_keys[keys],
+        )?));
+    }
+    Ok(states)
+}
+
+/// Builds the executor env for the shard guest (shard.rs) of the ballots of `range`, starting
+/// the hashes of the files from the `states` of [`shard_hash_states`].
+pub fn shard_env(
+    inputs: &ProcessedInputs,
+    verifying_keys: &[Vec<u8>],
+    range: Range<usize>,
+    states: (Sha256State, Sha256State),
+    registry: &Registry,
+    config: &GuestConfig,
+) -> Result<ExecutorEnv<'static>> {
+    let keys = shard_keys(verifying_keys, range.clone());
+    let env = ExecutorEnv::builder()
+        .write(&bincode::serialize(&inputs[range.clone()])?)
+        .context("Failed to serialize inputs")?
+        .write(&bincode::serialize(&verifying_keys[keys])?)
+        .context("Failed to serialize verifying_keys")?
+        .write(&registry.project_ids)
+        .context("Failed to serialize project registry")?
+        .write(&registry.safe_owners)
+        .context("Failed to serialize safe owners")?
+        .write(&(range.start as u32))
+        .context("Failed to serialize shard offset")?
+        .write(config)
+        .context("Failed to serialize config")?
+        .write(&states.0)
+        .context("Failed to serialize the inputs hash state")?
+        .write(&states.1)
+        .context("Failed to serialize the verifying keys hash state")?
+        .build()?;
+    Ok(env)
+}
+
+/// Proves the shard guest over every range of [`shard_ranges`] in parallel, in range order.
+pub fn prove_shards(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    config: &GuestConfig,
+    shard_count: usize,
+) -> Result<Vec<Receipt>> {
+    let verifying_keys: Vec<Vec<u8>> = bincode::deserialize(&registry.verifying_keys)?;
+    let ranges = shard_ranges(inputs.len(), shard_count);
+    let states = shard_hash_states(inputs, &verifying_keys, &ranges)?;
+    println!("Proving {} shards of the ballots", ranges.len());
+    thread::scope(|scope| {
+        let shards: Vec<_> = ranges
+            .into_iter()
+            .zip(states)
+            .map(|(range, states)| {
+                let verifying_keys = &verifying_keys;
+                scope.spawn(move || {
+                    let env = shard_env(
+                        inputs,
+                        verifying_keys,
+                        range.clone(),
+                        states,
+                        registry,
+                        config,
+                    )?;
+                    // assumptions have to be stark receipts
+                    prove(env, SHARD_ELF, &SHARD_ID, ReceiptKind::Stark).with_context(|| {
+                        format!("Failed to prove the shard of ballots {:?}", range)
+                    })
+                })
+            })
+            .collect();
+        shards
+            .into_iter()
+            .map(|shard| {
+                shard
+                    .join()
+                    .map_err(|_| anyhow!("A shard prover panicked"))?
+            })
+            .collect()
+    })
+}
+
+/// Builds the executor env for the shards guest (shards.rs), which verifies the `shards`
+/// receipts as assumptions. It reads their journals in place of the ballots.
+pub fn sharded_aggregate_env(
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    shards: Vec<Receipt>,
+) -> Result<ExecutorEnv<'static>> {
+    let journals = shards
+        .iter()
+        .map(|receipt| receipt.journal.decode())
+        .collect::<Result<Vec<ShardJournal>, _>>()
+        .context("Failed to decode the shard journals")?;
+
+    let mut builder = ExecutorEnv::builder();
+    builder
+        .write(&registry.project_ids)
+        .context("Failed to serialize project registry")?
+        .write(&registry.safe_owners)
+        .context("Failed to serialize safe owners")?
+        .write(filter)
+        .context("Failed to serialize project filter")?
+        .write(config)
+        .context("Failed to serialize config")?
+        .write(&SHARD_ID)
+        .context("Failed to serialize shard image id")?
+        .write(&journals)
+        .context("Failed to serialize shard journals")?;
+    for receipt in shards {
+        builder.add_assumption(receipt);
+    }
+    Ok(builder.build()?)
+}
+
+/// Proves the journal of [`crate::projects::aggregate_project_votes`], validating the ballots in
+/// `shard_count` shards proven in parallel, with the image id of the shard guest as its
+/// `shard_image_id`.
+pub fn sharded_aggregate_project_votes(
+    inputs: &ProcessedInputs,
+    registry: &Registry,
+    filter: &ProjectFilter,
+    config: &GuestConfig,
+    shard_count: usize,
+    kind: ReceiptKind,
+) -> Result<Receipt> {
+    let shards = prove_shards(inputs, registry, config, shard_count)?;
+    let env = sharded_aggregate_env(registry, filter, config, shards)?;
+    prove(env, SHARDS_ELF, &SHARDS_ID, kind)
+}
@@ -23,7 +23,8 @@ mod benchmarking_tests {
         BENCHMARK_FILE.call_once(|| {
             let benchmark_structure = json!({
                 "rpgf_ballots": [],
-                "rpgf_ballots_aggr": []
+                "rpgf_ballots_aggr": [],
+                "poseidon_cycles": []
             });
 
             let mut file =
@@ -60,6 +61,10 @@ mod benchmarking_tests {
             fn run_rpgf_ballots_benchmarks_native_() {
                 run_rpgf_ballots_benchmarks(false);
             }
+            #[test]
+            fn run_poseidon_cycle_benchmarks_() {
+                run_poseidon_cycle_benchmarks();
+            }
         };
     }
 
@@ -71,8 +76,8 @@ mod benchmarking_tests {
         let time_cmd = TIME_CMD;
         generate_sample_ballot_data(badgeholder_count, ballot_count);
         run_private_pre_processing();
-        run_risc0_zk_vm(badgeholder_count, ballot_count, time_cmd, false);
-        run_risc0_zk_vm(badgeholder_count, ballot_count, time_cmd, true);
+        run_risc0_zk_vm(badgeholder_count, ballot_count, time_cmd, false, false);
+        run_risc0_zk_vm(badgeholder_count, ballot_count, time_cmd, true, false);
         if wasm_test {
             verify_in_browser();
         }
@@ -85,6 +90,103 @@ mod benchmarking_tests {
         );
     }
 
+    // Records the guest cycles of the aggregate proof with Poseidon on the software field
+    // arithmetic and on the zkVM's bigint accelerator. Switching between them rebuilds the
+    // guests (see RPGF_SOFTWARE_FIELD in rpgf-ballots-methods/build.rs), so every badgeholder
+    // count runs on one arithmetic before switching to the other: two rebuilds in all, ending on
+    // the default bigint build. The ballots of each badgeholder count are generated and processed
+    // once, and copied aside, so that both arithmetics prove the same inputs
+    fn run_poseidon_cycle_benchmarks() {
+        create_benchmark_json_file();
+        init_binary();
+        let ballot_count = 1;
+        let badgeholder_counts = [1, 10, 100];
+        for badgeholder_count in badgeholder_counts {
+            generate_sample_ballot_data(badgeholder_count, ballot_count);
+            run_private_pre_processing();
+            stash_processed_inputs(badgeholder_count);
+        }
+        let mut software_cycles = vec![];
+        let mut bigint_cycles = vec![];
+        for (software_field, cycles) in [(true, &mut software_cycles), (false, &mut bigint_cycles)]
+        {
+            for badgeholder_count in badgeholder_counts {
+                restore_processed_inputs(badgeholder_count);
+                cycles.push(run_risc0_zk_vm(
+                    badgeholder_count,
+                    ballot_count,
+                    TIME_CMD,
+                    true,
+                    software_field,
+                ));
+            }
+        }
+
+        for ((badgeholder_count, software_cycles), bigint_cycles) in badgeholder_counts
+            .into_iter()
+            .zip(software_cycles)
+            .zip(bigint_cycles)
+        {
+            let benchmarks_json = std::fs::read_to_string("./benchmarks.json").unwrap();
+            let mut benchmarks_json: serde_json::Value =
+                serde_json::from_str(&benchmarks_json).unwrap();
+            benchmarks_json["poseidon_cycles"]
+                .as_array_mut()
+                .unwrap()
+                .push(json!({
+                    "badgeholder_count": badgeholder_count,
+                    "ballot_count": ballot_count,
+                    "software_cycles": software_cycles,
+                    "bigint_cycles": bigint_cycles,
+                    "reduction": format!(
+                        "{:.1}%",
+                        100.0 * (1.0 - bigint_cycles as f64 / software_cycles as f64)
+                    )
+                }));
+            std::fs::write(
+                "./benchmarks.json",
+                serde_json::to_string_pretty(&benchmarks_json).unwrap(),
+            )
+            .unwrap();
+        }
+        let benchmarks_json = std::fs::read_to_string("./benchmarks.json").unwrap();
+        let benchmarks_json: serde_json::Value = serde_json::from_str(&benchmarks_json).unwrap();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&benchmarks_json["poseidon_cycles"]).unwrap()
+        );
+    }
+
+    // The files private_processing writes for the prover
+    const PROCESSED_FILES: [&str; 4] = [
+        "processed_inputs",
+        "verifying_keys",
+        "project_ids",
+        "votes_table.json",
+    ];
+
+    fn stashed_inputs_dir(badgeholder_count: usize) -> String {
+        format!(
+            "{}/cycle_benchmarks/{}",
+            *CARGO_TARGET_DIR, badgeholder_count
+        )
+    }
+
+    fn stash_processed_inputs(badgeholder_count: usize) {
+        let dir = stashed_inputs_dir(badgeholder_count);
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in PROCESSED_FILES {
+            std::fs::copy(file, format!("{}/{}", dir, file)).unwrap();
+        }
+    }
+
+    fn restore_processed_inputs(badgeholder_count: usize) {
+        let dir = stashed_inputs_dir(badgeholder_count);
+        for file in PROCESSED_FILES {
+            std::fs::copy(format!("{}/{}", dir, file), file).unwrap();
+        }
+    }
+
     fn generate_sample_ballot_data(badgeholder_count: usize, ballot_count: usize) {
         // call the ballot generator binary to generate sample ballot data
         let command = format!(
@@ -116,19 +218,32 @@ mod benchmarking_tests {
         assert!(result.status.success());
     }
 
-    fn run_risc0_zk_vm(badgeholder_count: usize, ballot_count: usize, time_cmd: &str, aggr: bool) {
+    // Returns the total guest cycles of the run
+    fn run_risc0_zk_vm(
+        badgeholder_count: usize,
+        ballot_count: usize,
+        time_cmd: &str,
+        aggr: bool,
+        software_field: bool,
+    ) -> u64 {
+        // Build the guests without the bigint accelerator if asked to
+        let env = if software_field {
+            "RPGF_SOFTWARE_FIELD=1 "
+        } else {
+            ""
+        };
         // Wrap the risc0 binry run command in the gnu time command
         let command = if aggr {
             format!(
                 "
-                {} -v cargo run --release --bin op-rpgf -- --aggregate",
-                time_cmd
+                {}{} -v cargo run --release --bin op-rpgf -- --aggregate",
+                env, time_cmd
             )
         } else {
             format!(
                 "
-                {} -v cargo run --release --bin op-rpgf",
-                time_cmd
+                {}{} -v cargo run --release --bin op-rpgf",
+                env, time_cmd
             )
         };
         // Run the command using Bash, capturing both stdout and stderr
@@ -148,6 +263,7 @@ mod benchmarking_tests {
         // Use regex to extract the Proving time and Memory usage
         let proving_time_re = Regex::new(r"Proving time: (\d+\.\d+)s").unwrap();
         let memory_usage_re = Regex::new(r"Maximum resident set size \(kbytes\): (\d+)").unwrap();
+        let cycles_re = Regex::new(r"Total cycles for guest code execution: (\d+)").unwrap();
 
         let proving_time_r0 = proving_time_re
             .captures(&stdout)
@@ -159,6 +275,12 @@ mod benchmarking_tests {
             .and_then(|caps| caps.get(1))
             .map_or("".to_string(), |m| m.as_str().to_string() + "kb");
 
+        // Each single project proof prints its own cycles
+        let cycles: u64 = cycles_re
+            .captures_iter(&stdout)
+            .map(|caps| caps[1].parse::<u64>().unwrap())
+            .sum();
+
         // Read the benchmarks.json file
         let benchmarks_json = std::fs::read_to_string("./benchmarks.json").unwrap();
         let mut benchmarks_json: serde_json::Value =
@@ -177,7 +299,9 @@ mod benchmarking_tests {
             "badgeholder_count": badgeholder_count,
             "ballot_count": ballot_count,
             "proving_time": proving_time_r0,
-            "memory_usage": memory_usage_r0
+            "memory_usage": memory_usage_r0,
+            "cycles": cycles,
+            "field_arithmetic": if software_field { "software" } else { "bigint" }
         }));
 
         // Write to benchmarks.json file
@@ -186,6 +310,7 @@ mod benchmarking_tests {
             serde_json::to_string_pretty(&benchmarks_json).unwrap(),
         )
         .unwrap();
+        cycles
     }
 
     fn verify_in_browser() {
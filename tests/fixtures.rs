@@ -0,0 +1,37 @@
+use op_rpgf::journal::{verify_project_receipt, verify_projects_receipt};
+use risc0_zkvm::Receipt;
+use rpgf_ballots_methods::{PROJECTS_ID, PROJECT_ID};
+use std::fs;
+
+// browser-verify's tests check the receipts and image ids committed next to them, which go stale
+// whenever a guest or the journal layout changes. Regenerate them as browser-verify/README.md
+// describes, then drop the ignore
+
+fn image_id(path: &str) -> [u32; 8] {
+    bincode::deserialize(&fs::read(path).unwrap()).unwrap()
+}
+
+#[test]
+#[ignore = "browser-verify's receipts predate the journal header and need regenerating"]
+fn browser_verify_fixtures_match_the_guests() {
+    assert_eq!(
+        image_id("browser-verify/image_id"),
+        PROJECT_ID,
+        "browser-verify/image_id is stale"
+    );
+    assert_eq!(
+        image_id("browser-verify/image_id_aggr"),
+        PROJECTS_ID,
+        "browser-verify/image_id_aggr is stale"
+    );
+
+    let receipt: Receipt =
+        bincode::deserialize(&fs::read("browser-verify/receipt_aggr").unwrap()).unwrap();
+    verify_projects_receipt(&receipt, &PROJECTS_ID).expect("browser-verify/receipt_aggr is stale");
+
+    let receipts: Vec<Receipt> =
+        bincode::deserialize(&fs::read("browser-verify/receipts").unwrap()).unwrap();
+    for receipt in &receipts {
+        verify_project_receipt(receipt).expect("browser-verify/receipts is stale");
+    }
+}
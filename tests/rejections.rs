@@ -0,0 +1,144 @@
+use k256::ecdsa::SigningKey;
+use op_rpgf::processing::process_ballots_csv;
+use rpgf_ballots_core::signature::{hash_message, signer_address};
+use rpgf_ballots_core::{
+    validate_ballot, Allowlist, BallotChecks, GuestConfig, ProjectId, RejectionPolicy,
+    RejectionReason, SafeOwners,
+};
+use std::io::Write;
+
+const PROJECT: &str = "0x27296535734d2a0ed74c252553aec63c94a0a99f035b07b1b80e8a3fe0a830db";
+const SAFE: [u8; 20] = [0x5a; 20];
+
+fn key(byte: u8) -> SigningKey {
+    SigningKey::from_slice(&[byte; 32]).unwrap()
+}
+
+fn ballot(project_id: &str, amount: &str) -> String {
+    format!(
+        r#"[{{"projectId":"{}","amount":"{}"}}]"#,
+        project_id, amount
+    )
+}
+
+// a `address, signature, ballot json` record of `ballot` signed by `signer` with EIP-191
+fn record(address: [u8; 20], signer: &SigningKey, ballot: &str) -> String {
+    let (signature, recovery_id) = signer
+        .sign_prehash_recoverable(&hash_message(ballot.as_bytes()))
+        .unwrap();
+    let mut signature = signature.to_bytes().to_vec();
+    signature.push(recovery_id.to_byte() + 27);
+    format!(
+        "0x{},0x{},\"{}\"\n",
+        hex::encode(address),
+        hex::encode(signature),
+        ballot.replace('"', "\"\"")
+    )
+}
+
+// The guests must reject each record the host couldn't process for the reason it is invalid,
+// from what the host passes them
+#[test]
+fn guests_reject_skipped_records_for_their_reason() {
+    let signer = key(1);
+    let address = signer_address(signer.verifying_key());
+    let unknown_project = format!("0x{}", "11".repeat(32));
+    let records = [
+        (
+            record(address, &signer, r#"[{"projectId":"#),
+            RejectionReason::MalformedBallot,
+        ),
+        (
+            record(address, &key(2), &ballot(PROJECT, "1")),
+            RejectionReason::UnrecoveredSigner,
+        ),
+        (
+            record(address, &signer, &ballot(&unknown_project, "1")),
+            RejectionReason::UnknownProject,
+        ),
+        (
+            record(address, &signer, &ballot(PROJECT, "1.5")),
+            RejectionReason::InvalidAmount,
+        ),
+        (
+            record(SAFE, &signer, &ballot(PROJECT, "1")),
+            RejectionReason::InsufficientSignatures,
+        ),
+    ];
+    let mut csv = tempfile::NamedTempFile::new().unwrap();
+    for (record, _) in &records {
+        csv.write_all(record.as_bytes()).unwrap();
+    }
+
+    let mut owners = vec![address, signer_address(key(2).verifying_key())];
+    owners.sort_unstable();
+    let safe_owners = vec![SafeOwners {
+        safe: SAFE,
+        threshold: 2,
+        owners,
+    }];
+    let config = GuestConfig {
+        amount_scale: 0,
+        rejection_policy: RejectionPolicy::Skip,
+        ..GuestConfig::default()
+    };
+    let processed = process_ballots_csv(csv.path(), 0, &config, &safe_owners).unwrap();
+    assert_eq!(processed.rejected, vec![0, 1, 3, 4]);
+
+    let project_ids: Vec<ProjectId> = vec![PROJECT.parse().unwrap()];
+    let checks = BallotChecks::new(Some(&project_ids), &safe_owners, &config);
+    for (index, (_, reason)) in records.iter().enumerate() {
+        let (ballot, signature) = processed.inputs[index].clone();
+        let verifying_key = processed.verifying_keys.get(index).map(Vec::as_slice);
+        assert_eq!(
+            validate_ballot(ballot, &signature, verifying_key, &checks).map(|_| ()),
+            Err(*reason),
+            "record {}",
+            index
+        );
+    }
+}
+
+// Ballots of signers outside of the allowlist are left out of the published votes table, as the
+// guests leave them out of the tally
+#[test]
+fn ineligible_signers_are_left_out_of_the_votes_table() {
+    let (eligible, ineligible) = (key(1), key(2));
+    let mut csv = tempfile::NamedTempFile::new().unwrap();
+    for (signer, amount) in [(&eligible, "2"), (&ineligible, "3")] {
+        let address = signer_address(signer.verifying_key());
+        csv.write_all(record(address, signer, &ballot(PROJECT, amount)).as_bytes())
+            .unwrap();
+    }
+
+    let config = GuestConfig {
+        amount_scale: 0,
+        rejection_policy: RejectionPolicy::Skip,
+        eligibility: Some(Allowlist::new(vec![signer_address(
+            eligible.verifying_key(),
+        )])),
+        ..GuestConfig::default()
+    };
+    let processed = process_ballots_csv(csv.path(), 0, &config, &[]).unwrap();
+    assert_eq!(processed.rejected, vec![1]);
+    assert_eq!(processed.votes_table[PROJECT], vec![2]);
+
+    let checks = BallotChecks::new(None, &[], &config);
+    let (ballot, signature) = processed.inputs[1].clone();
+    assert_eq!(
+        validate_ballot(
+            ballot,
+            &signature,
+            Some(&processed.verifying_keys[1]),
+            &checks
+        )
+        .map(|_| ()),
+        Err(RejectionReason::NotEligible)
+    );
+
+    let abort = GuestConfig {
+        rejection_policy: RejectionPolicy::Abort,
+        ..config
+    };
+    assert!(process_ballots_csv(csv.path(), 0, &abort, &[]).is_err());
+}